@@ -13,8 +13,8 @@ pub fn get_params(cx: &CallContext) -> Result<WrappedObject> {
 
 pub(crate) fn parse_strategy(strat: String) -> FillNullStrategy {
     match strat.as_str() {
-        "backward" => FillNullStrategy::Backward,
-        "forward" => FillNullStrategy::Forward,
+        "backward" => FillNullStrategy::Backward(None),
+        "forward" => FillNullStrategy::Forward(None),
         "min" => FillNullStrategy::Min,
         "max" => FillNullStrategy::Max,
         "mean" => FillNullStrategy::Mean,
@@ -559,8 +559,6 @@ impl_no_arg_expr!(list);
 impl_no_arg_expr!(count);
 impl_no_arg_expr!(agg_groups);
 
-impl_no_arg_expr!(backward_fill);
-impl_no_arg_expr!(forward_fill);
 impl_no_arg_expr!(reverse);
 impl_no_arg_expr!(std);
 impl_no_arg_expr!(var);
@@ -884,3 +882,17 @@ pub fn when_then_then_otherwise(cx: CallContext) -> JsResult<JsExternal> {
 
     cx.env.create_external(whenthenthen.otherwise(expr), None)
 }
+
+#[js_function(1)]
+pub fn backward_fill(cx: CallContext) -> JsResult<JsExternal> {
+    let params = get_params(&cx)?;
+    let expr = params.get_external::<Expr>(&cx, "_expr")?;
+    expr.clone().backward_fill(None).try_into_js(&cx)
+}
+
+#[js_function(1)]
+pub fn forward_fill(cx: CallContext) -> JsResult<JsExternal> {
+    let params = get_params(&cx)?;
+    let expr = params.get_external::<Expr>(&cx, "_expr")?;
+    expr.clone().forward_fill(None).try_into_js(&cx)
+}
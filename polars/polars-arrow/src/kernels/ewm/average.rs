@@ -1,3 +1,4 @@
+use super::{decay, EWMOptions};
 use crate::trusted_len::{PushUnchecked, TrustedLen};
 use num::Float;
 use std::fmt::Debug;
@@ -26,14 +27,15 @@ where
     let mut ewma_old = first;
     let one_sub_alpha = T::one() - alpha;
 
-    let mut i = T::from(out.len()).unwrap();
+    // (1 - alpha)^i
+    let mut old_weight = T::one();
     for val in iter {
-        weight += one_sub_alpha.powf(i);
+        old_weight = old_weight * one_sub_alpha;
+        weight += old_weight;
         ewma_old = ewma_old * (one_sub_alpha) + val;
         // Safety:
         // we allocated vals.len()
         unsafe { out.push_unchecked(ewma_old / weight) };
-        i += T::one();
     }
 
     out
@@ -57,14 +59,15 @@ where
     let mut ewma_old = out[out.len() - 1];
     let one_sub_alpha = T::one() - alpha;
 
-    let mut i = T::one();
+    // (1 - alpha)^i
+    let mut old_weight = T::one();
     let mut prev = out[out.len() - 1];
     for opt_val in iter {
         prev = match opt_val {
             Some(val) => {
-                weight += one_sub_alpha.powf(i);
+                old_weight = old_weight * one_sub_alpha;
+                weight += old_weight;
                 ewma_old = ewma_old * (one_sub_alpha) + val;
-                i += T::one();
                 ewma_old / weight
             }
             None => prev,
@@ -156,6 +159,69 @@ where
     (leading_null_count, out)
 }
 
+/// Exponentially weighted mean with the semantics of pandas' `ewm(..).mean()`.
+///
+/// # Arguments
+///
+/// * `vals` - Iterator of optional values
+/// * `options` - `alpha`, `adjust`, `ignore_nulls` and `min_periods` are used
+/// * `deltas` - If given, the weights decay with `(1 - alpha)^deltas[i]` between the
+///   previous row and row `i` (e.g. time differences in half-lives), instead of once per row.
+///   With `deltas`, nulls always decay the weights by their distance.
+///
+/// Returns `None` until `min_periods` non-null values are seen. Null values get the
+/// current mean.
+pub fn ewm_mean<T, I>(vals: I, options: &EWMOptions, deltas: Option<&[T]>) -> Vec<Option<T>>
+where
+    T: Float + AddAssign,
+    I: IntoIterator<Item = Option<T>>,
+{
+    let alpha = T::from(options.alpha).unwrap();
+    let old_wt_factor = T::one() - alpha;
+    let new_wt = if options.adjust { T::one() } else { alpha };
+    let min_periods = std::cmp::max(options.min_periods, 1);
+
+    let mut old_wt = T::one();
+    let mut mean: Option<T> = None;
+    // decay that is not yet applied to `old_wt`
+    let mut pending_decay = T::zero();
+    let mut non_null_count = 0;
+
+    vals.into_iter()
+        .enumerate()
+        .map(|(i, opt_val)| {
+            let delta = deltas.map(|d| d[i]).unwrap_or_else(T::one);
+            match (opt_val, mean) {
+                (Some(val), None) => {
+                    mean = Some(val);
+                    non_null_count += 1;
+                }
+                (Some(val), Some(prev)) => {
+                    pending_decay += delta;
+                    old_wt = old_wt * decay(old_wt_factor, pending_decay);
+                    pending_decay = T::zero();
+                    mean = Some((old_wt * prev + new_wt * val) / (old_wt + new_wt));
+                    old_wt = if options.adjust {
+                        old_wt + new_wt
+                    } else {
+                        T::one()
+                    };
+                    non_null_count += 1;
+                }
+                (None, Some(_)) if !options.ignore_nulls || deltas.is_some() => {
+                    pending_decay += delta;
+                }
+                (None, _) => {}
+            }
+            if non_null_count >= min_periods {
+                mean
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 pub fn set_first_none_null<T, I>(iter: &mut I, out: &mut Vec<T>) -> usize
 where
     T: Float + AddAssign,
@@ -265,4 +331,42 @@ mod test {
         assert_eq!(cnt, 1);
         assert_eq!(out, expected);
     }
+
+    fn assert_close(out: &[Option<f64>], expected: &[Option<f64>]) {
+        assert_eq!(out.len(), expected.len());
+        for (a, b) in out.iter().zip(expected) {
+            match (a, b) {
+                (Some(a), Some(b)) => assert!((a - b).abs() < 1e-12, "{} != {}", a, b),
+                _ => assert_eq!(a, b),
+            }
+        }
+    }
+
+    #[test]
+    fn test_ewm_mean() {
+        let vals = [None, Some(1.0), Some(5.0), None, Some(2.0)];
+        let options = EWMOptions::default();
+
+        let out = ewm_mean(vals.iter().copied(), &options, None);
+        let expected = [
+            None,
+            Some(1.0),
+            Some(11.0 / 3.0),
+            Some(11.0 / 3.0),
+            Some(4.75 / 1.75),
+        ];
+        assert_close(&out, &expected);
+
+        // a null decays the previous values: (0.125 * 1 + 0.25 * 5 + 2) / (0.125 + 0.25 + 1)
+        let not_ignored = ewm_mean(vals.iter().copied(), &options.and_ignore_nulls(false), None);
+        assert_close(&not_ignored[4..], &[Some(3.375 / 1.375)]);
+
+        let out = ewm_mean(vals.iter().copied(), &options.and_min_periods(2), None);
+        assert_close(&out[1..3], &[None, Some(11.0 / 3.0)]);
+
+        // with deltas the null row still takes up a half life
+        let deltas = [0.0, 1.0, 1.0, 1.0, 1.0];
+        let out = ewm_mean(vals.iter().copied(), &options, Some(&deltas));
+        assert_close(&out, &not_ignored);
+    }
 }
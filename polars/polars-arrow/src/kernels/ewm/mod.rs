@@ -2,13 +2,31 @@ mod average;
 mod variance;
 
 pub use average::*;
+use num::Float;
 pub use variance::*;
 
+/// `base^steps`. Without deltas the weights decay one step per row, which doesn't need `powf`.
+#[inline]
+fn decay<T: Float>(base: T, steps: T) -> T {
+    if steps == T::one() {
+        base
+    } else {
+        base.powf(steps)
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct EWMOptions {
     pub alpha: f64,
     pub adjust: bool,
+    /// Minimal number of non-null observations before a value is produced.
     pub min_periods: usize,
+    /// Skip null values when computing the weights. If `false`, the weights are
+    /// based on the absolute positions, so nulls still decay the previous values (as
+    /// `ignore_na=False` in pandas).
+    pub ignore_nulls: bool,
+    /// Return the biased variance/covariance instead of the bias corrected one.
+    pub bias: bool,
 }
 
 impl Default for EWMOptions {
@@ -17,6 +35,8 @@ impl Default for EWMOptions {
             alpha: 0.5,
             adjust: true,
             min_periods: 1,
+            ignore_nulls: true,
+            bias: false,
         }
     }
 }
@@ -30,6 +50,14 @@ impl EWMOptions {
         self.adjust = adjust;
         self
     }
+    pub fn and_ignore_nulls(mut self, ignore_nulls: bool) -> Self {
+        self.ignore_nulls = ignore_nulls;
+        self
+    }
+    pub fn and_bias(mut self, bias: bool) -> Self {
+        self.bias = bias;
+        self
+    }
     pub fn and_span(mut self, span: usize) -> Self {
         assert!(span >= 1);
         self.alpha = 2.0 / (span as f64 + 1.0);
@@ -38,7 +66,7 @@ impl EWMOptions {
 
     pub fn and_half_life(mut self, half_life: f64) -> Self {
         assert!(half_life > 0.0);
-        self.alpha = 1.0 - ((-2.0f64).ln() / half_life).exp();
+        self.alpha = 1.0 - (-(2.0f64).ln() / half_life).exp();
         self
     }
 
@@ -48,3 +76,18 @@ impl EWMOptions {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ewm_options() {
+        let options = EWMOptions::default().and_half_life(1.0);
+        assert!((options.alpha - 0.5).abs() < 1e-12);
+        let options = EWMOptions::default().and_span(3);
+        assert!((options.alpha - 0.5).abs() < 1e-12);
+        let options = EWMOptions::default().and_com(1.0);
+        assert!((options.alpha - 0.5).abs() < 1e-12);
+    }
+}
@@ -1,49 +1,188 @@
+use super::{decay, EWMOptions};
+use crate::prelude::FromData;
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::MutableBitmap;
+use arrow::types::NativeType;
 use num::Float;
+use std::ops::AddAssign;
 
-// See: https://stats.stackexchange.com/a/111912/147321
+/// Running state of the exponentially weighted covariance, updated per observation.
+struct EwmCovState<T> {
+    alpha_complement: T,
+    new_wt: T,
+    adjust: bool,
+    means: Option<(T, T)>,
+    cov: T,
+    sum_wt: T,
+    sum_wt2: T,
+    old_wt: T,
+    count: usize,
+}
 
-pub fn ewm_std<T: Float>(x_vals: &[T], ewma_vals: &mut [T], alpha: T) {
-    if !ewma_vals.is_empty() {
-        let mut emwa_prev = ewma_vals[0];
-        let mut emvar_prev = T::zero();
-        ewma_vals[0] = T::zero();
+impl<T: Float + AddAssign> EwmCovState<T> {
+    fn new(options: &EWMOptions) -> Self {
+        let alpha = T::from(options.alpha).unwrap();
+        Self {
+            alpha_complement: T::one() - alpha,
+            new_wt: if options.adjust { T::one() } else { alpha },
+            adjust: options.adjust,
+            means: None,
+            cov: T::zero(),
+            sum_wt: T::one(),
+            sum_wt2: T::one(),
+            old_wt: T::one(),
+            count: 0,
+        }
+    }
 
-        let one_sub_alpha = T::one() - alpha;
-        let two = T::one() + T::one();
+    /// Add the observation `(x, y)`, `steps` decay steps after the previous observation.
+    #[inline]
+    fn push(&mut self, x: T, y: T, steps: T) {
+        self.count += 1;
+        let (mean_x, mean_y) = match self.means {
+            None => {
+                self.means = Some((x, y));
+                return;
+            }
+            Some(means) => means,
+        };
+        let decay = decay(self.alpha_complement, steps);
+        self.sum_wt = self.sum_wt * decay;
+        self.sum_wt2 = self.sum_wt2 * decay * decay;
+        self.old_wt = self.old_wt * decay;
 
-        let mut x_iter = x_vals.iter();
-        x_iter.next();
+        let old_wt = self.old_wt;
+        let new_wt = self.new_wt;
+        let wt_sum = old_wt + new_wt;
+        let new_mean_x = (old_wt * mean_x + new_wt * x) / wt_sum;
+        let new_mean_y = (old_wt * mean_y + new_wt * y) / wt_sum;
+        self.cov = (old_wt * (self.cov + (mean_x - new_mean_x) * (mean_y - new_mean_y))
+            + new_wt * (x - new_mean_x) * (y - new_mean_y))
+            / wt_sum;
+        self.means = Some((new_mean_x, new_mean_y));
 
-        for (xi, ewma_i) in x_iter.zip(ewma_vals[1..].iter_mut()) {
-            let delta_i = *xi - emwa_prev;
-            emwa_prev = *ewma_i;
+        self.sum_wt += new_wt;
+        self.sum_wt2 += new_wt * new_wt;
+        self.old_wt += new_wt;
+        if !self.adjust {
+            self.sum_wt = self.sum_wt / self.old_wt;
+            self.sum_wt2 = self.sum_wt2 / (self.old_wt * self.old_wt);
+            self.old_wt = T::one();
+        }
+    }
 
-            emvar_prev = one_sub_alpha * (emvar_prev + alpha * delta_i.powf(two));
-            *ewma_i = emvar_prev.sqrt();
+    /// The current covariance, `None` if it is not defined.
+    #[inline]
+    fn cov(&self, bias: bool) -> Option<T> {
+        if bias {
+            Some(self.cov)
+        } else {
+            let numerator = self.sum_wt * self.sum_wt;
+            let denominator = numerator - self.sum_wt2;
+            if denominator > T::zero() {
+                Some(numerator / denominator * self.cov)
+            } else {
+                None
+            }
         }
     }
 }
 
-pub fn ewm_var<T: Float>(x_vals: &[T], ewma_vals: &mut [T], alpha: T) {
-    if !ewma_vals.is_empty() {
-        let mut emwa_prev = ewma_vals[0];
-        let mut emvar_prev = T::zero();
-        ewma_vals[0] = T::zero();
+/// Exponentially weighted variance of values without nulls, with the semantics of
+/// pandas' `ewm(..).var()`. See [`ewm_cov`] for values with nulls.
+///
+/// The output is null until `min_periods` values are seen and where the bias corrected
+/// variance is not defined (e.g. at the first value).
+pub fn ewm_var<T>(x_vals: &[T], options: &EWMOptions) -> PrimitiveArray<T>
+where
+    T: NativeType + Float + AddAssign,
+{
+    let min_periods = std::cmp::max(options.min_periods, 1);
+    let mut state = EwmCovState::new(options);
+    let mut validity: Option<MutableBitmap> = None;
 
-        let one_sub_alpha = T::one() - alpha;
-        let two = T::one() + T::one();
+    let values = x_vals
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            state.push(x, x, T::one());
+            let var = if state.count < min_periods {
+                None
+            } else {
+                state.cov(options.bias)
+            };
+            match (var, &mut validity) {
+                (Some(var), Some(validity)) => {
+                    validity.push(true);
+                    var
+                }
+                (Some(var), None) => var,
+                (None, Some(validity)) => {
+                    validity.push(false);
+                    T::zero()
+                }
+                (None, None) => {
+                    let mut bitmap = MutableBitmap::with_capacity(x_vals.len());
+                    bitmap.extend_constant(i, true);
+                    bitmap.push(false);
+                    validity = Some(bitmap);
+                    T::zero()
+                }
+            }
+        })
+        .collect::<Vec<_>>();
 
-        let mut x_iter = x_vals.iter();
-        x_iter.next();
+    PrimitiveArray::from_data_default(values.into(), validity.map(|v| v.into()))
+}
 
-        for (xi, ewma_i) in x_iter.zip(ewma_vals[1..].iter_mut()) {
-            let delta_i = *xi - emwa_prev;
-            emwa_prev = *ewma_i;
+/// Exponentially weighted standard deviation of values without nulls.
+/// See [`ewm_var`].
+pub fn ewm_std<T>(x_vals: &[T], options: &EWMOptions) -> PrimitiveArray<T>
+where
+    T: NativeType + Float + AddAssign,
+{
+    let var = ewm_var(x_vals, options);
+    let values = var.values().iter().map(|v| v.sqrt()).collect::<Vec<_>>();
+    PrimitiveArray::from_data_default(values.into(), var.validity().cloned())
+}
 
-            emvar_prev = one_sub_alpha * (emvar_prev + alpha * delta_i.powf(two));
-            *ewma_i = emvar_prev;
-        }
-    }
+/// Exponentially weighted covariance with the semantics of pandas' `ewm(..).cov()`.
+/// The variance is the covariance of a series with itself.
+///
+/// A row only counts as an observation if both values are non-null. See
+/// [`ewm_mean`](super::ewm_mean) for the meaning of `deltas`.
+pub fn ewm_cov<T, I>(vals: I, options: &EWMOptions, deltas: Option<&[T]>) -> Vec<Option<T>>
+where
+    T: Float + AddAssign,
+    I: IntoIterator<Item = (Option<T>, Option<T>)>,
+{
+    let min_periods = std::cmp::max(options.min_periods, 1);
+    let mut state = EwmCovState::new(options);
+    // decay that is not yet applied to the weights
+    let mut pending_decay = T::zero();
+
+    vals.into_iter()
+        .enumerate()
+        .map(|(i, (opt_x, opt_y))| {
+            let delta = deltas.map(|d| d[i]).unwrap_or_else(T::one);
+            match (opt_x.zip(opt_y), state.means) {
+                (Some((x, y)), _) => {
+                    pending_decay += delta;
+                    state.push(x, y, pending_decay);
+                    pending_decay = T::zero();
+                }
+                (None, Some(_)) if !options.ignore_nulls || deltas.is_some() => {
+                    pending_decay += delta;
+                }
+                (None, _) => {}
+            }
+            if state.count < min_periods {
+                None
+            } else {
+                state.cov(options.bias)
+            }
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -51,28 +190,54 @@ mod test {
     use super::*;
 
     #[test]
-    fn test_emw_var() {
-        let x = [1.0, 5.0, 7.0, 1.0, 2.0, 1.0, 4.0];
-        let mut ewma = [
-            1.0,
-            3.6666666666666665,
-            5.571428571428571,
-            3.1333333333333333,
-            2.5483870967741935,
-            1.7619047619047619,
-            2.8897637795275593,
-        ];
+    fn test_ewm_var() {
+        let x = [1.0, 5.0, 7.0, 1.0];
+        let options = EWMOptions::default();
+        let with_nulls = ewm_cov(
+            x.iter().map(|&v| (Some(v), Some(v))),
+            &options.and_min_periods(2),
+            None,
+        );
+
+        // the no null kernel is the covariance of the values with themselves
+        let out = ewm_var(&x, &options.and_min_periods(2));
+        assert_eq!(out.len(), 4);
+        assert!(out.is_null(0));
+        for (i, expected) in with_nulls.iter().enumerate().skip(1) {
+            assert!((out.value(i) - expected.unwrap()).abs() < 1e-12);
+        }
+
+        let out = ewm_std(&x, &options.and_bias(true));
+        assert_eq!(out.validity(), None);
+        assert_eq!(out.value(0), 0.0);
+        assert!((out.value(1) - (32.0f64 / 9.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ewm_cov() {
+        let x = [Some(1.0), Some(5.0)];
+        let vals = x.iter().copied().zip(x.iter().copied());
+
+        let out = ewm_cov(vals.clone(), &EWMOptions::default(), None);
+        assert_eq!(out[0], None);
+        assert!((out[1].unwrap() - 8.0).abs() < 1e-12);
+
+        let out = ewm_cov(vals, &EWMOptions::default().and_bias(true), None);
+        assert_eq!(out[0], Some(0.0));
+        assert!((out[1].unwrap() - 32.0 / 9.0).abs() < 1e-12);
 
-        ewm_var(&x, &mut ewma, 0.5);
-        let expected = [
-            0.0,
-            4.0,
-            4.777777777777779,
-            7.613378684807256,
-            4.127800453514739,
-            2.6632758771215737,
-            2.583905512256932,
+        // a missing value in one of the series is not an observation
+        let vals = [
+            (Some(1.0), Some(2.0)),
+            (Some(3.0), None),
+            (Some(2.0), Some(4.0)),
         ];
-        assert_eq!(ewma, expected);
+        let out = ewm_cov(vals.iter().copied(), &EWMOptions::default(), None);
+        // two observations with weights 0.5 and 1 -> unbiased covariance
+        let expected = (0.5 * (1.0 - 5.0 / 3.0) * (2.0 - 10.0 / 3.0)
+            + (2.0 - 5.0 / 3.0) * (4.0 - 10.0 / 3.0))
+            / 1.5
+            * 2.25;
+        assert!((out[2].unwrap() - expected).abs() < 1e-12);
     }
 }
@@ -6,6 +6,7 @@ pub mod float;
 pub mod list;
 pub mod rolling;
 pub mod set;
+pub mod sketch;
 #[cfg(feature = "strings")]
 pub mod string;
 pub mod take;
@@ -0,0 +1,7 @@
+//! Mergeable sketches for approximate aggregations. Sketches of different partitions
+//! of the data can be merged and give the same estimate as a sketch of all the data.
+mod hyperloglog;
+mod tdigest;
+
+pub use hyperloglog::*;
+pub use tdigest::*;
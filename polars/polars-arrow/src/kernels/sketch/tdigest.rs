@@ -0,0 +1,250 @@
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+
+/// Bounds the number of centroids to roughly `COMPRESSION`.
+const COMPRESSION: f64 = 100.0;
+/// Number of values that are buffered before they are merged into the centroids.
+const BUFFER_SIZE: usize = 5 * COMPRESSION as usize;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Centroid {
+    pub mean: f64,
+    pub weight: f64,
+}
+
+/// Merging t-digest that estimates quantiles from a bounded number of centroids.
+///
+/// Centroids near the tails hold fewer values than centroids near the median, so extreme
+/// quantiles stay accurate. Small inputs are kept as single values and give exact results.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TDigest {
+    /// sorted by mean
+    centroids: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self {
+            centroids: vec![],
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+/// Scale function `k1` that limits the size of the centroids.
+fn scale(q: f64) -> f64 {
+    COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).clamp(-1.0, 1.0).asin()
+}
+
+/// Order the means of two centroids, NaN means are sorted to the end.
+fn cmp_means(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+fn sort_centroids(centroids: &mut [Centroid]) {
+    centroids.sort_by(|a, b| cmp_means(a.mean, b.mean));
+}
+
+/// Merge neighbouring centroids, the `centroids` should be sorted by mean.
+fn compress(centroids: Vec<Centroid>) -> Vec<Centroid> {
+    let total: f64 = centroids.iter().map(|c| c.weight).sum();
+    let mut iter = centroids.into_iter();
+    let mut current = match iter.next() {
+        Some(c) => c,
+        None => return vec![],
+    };
+
+    let mut out = Vec::new();
+    let mut weight_so_far = 0.0;
+    let mut k_lower = scale(0.0);
+    for c in iter {
+        let q = (weight_so_far + current.weight + c.weight) / total;
+        if scale(q) - k_lower <= 1.0 {
+            let weight = current.weight + c.weight;
+            current.mean += (c.mean - current.mean) * c.weight / weight;
+            current.weight = weight;
+        } else {
+            weight_so_far += current.weight;
+            k_lower = scale(weight_so_far / total);
+            out.push(current);
+            current = c;
+        }
+    }
+    out.push(current);
+    out
+}
+
+impl TDigest {
+    /// Create a digest of the values, NaN values are ignored.
+    ///
+    /// The values are buffered and merged into the centroids in batches, so only a bounded
+    /// number of centroids and buffered values are kept in memory.
+    pub fn from_values<I: IntoIterator<Item = f64>>(values: I) -> Self {
+        let mut digest = Self::default();
+        let mut buffer = Vec::with_capacity(BUFFER_SIZE);
+        for v in values.into_iter().filter(|v| !v.is_nan()) {
+            digest.min = digest.min.min(v);
+            digest.max = digest.max.max(v);
+            buffer.push(Centroid {
+                mean: v,
+                weight: 1.0,
+            });
+            if buffer.len() == BUFFER_SIZE {
+                digest.merge_buffer(&mut buffer);
+            }
+        }
+        digest.merge_buffer(&mut buffer);
+        digest
+    }
+
+    /// Merge the buffered centroids into the digest and clear the buffer.
+    fn merge_buffer(&mut self, buffer: &mut Vec<Centroid>) {
+        if buffer.is_empty() {
+            return;
+        }
+        sort_centroids(buffer);
+        // merge the two sorted runs
+        let mut merged = Vec::with_capacity(self.centroids.len() + buffer.len());
+        let mut left = std::mem::take(&mut self.centroids).into_iter().peekable();
+        let mut right = buffer.drain(..).peekable();
+        loop {
+            let take_left = match (left.peek(), right.peek()) {
+                (Some(l), Some(r)) => cmp_means(l.mean, r.mean) != Ordering::Greater,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            let next = if take_left { left.next() } else { right.next() };
+            merged.push(next.unwrap());
+        }
+        self.centroids = compress(merged);
+    }
+
+    /// Restore a digest from its parts, see [centroids](TDigest::centroids).
+    pub fn from_parts(mut centroids: Vec<Centroid>, min: f64, max: f64) -> Self {
+        sort_centroids(&mut centroids);
+        Self {
+            centroids: compress(centroids),
+            min,
+            max,
+        }
+    }
+
+    pub fn centroids(&self) -> &[Centroid] {
+        &self.centroids
+    }
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.centroids.is_empty()
+    }
+
+    /// Merge multiple digests into one.
+    pub fn merge<'a, I: IntoIterator<Item = &'a TDigest>>(digests: I) -> Self {
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        let mut centroids = vec![];
+        for digest in digests {
+            min = min.min(digest.min);
+            max = max.max(digest.max);
+            centroids.extend_from_slice(&digest.centroids);
+        }
+        Self::from_parts(centroids, min, max)
+    }
+
+    /// Estimate the `quantile` by interpolating between the centers of the centroids.
+    /// Returns `None` for an empty digest.
+    pub fn quantile(&self, quantile: f64) -> Option<f64> {
+        let first = self.centroids.first()?;
+        let last = self.centroids.last().unwrap();
+        let total: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let rank = quantile.clamp(0.0, 1.0) * total;
+
+        if rank <= first.weight / 2.0 {
+            return Some(self.min + (first.mean - self.min) * rank / (first.weight / 2.0).max(1.0));
+        }
+        if rank >= total - last.weight / 2.0 {
+            let to_end = total - rank;
+            return Some(self.max - (self.max - last.mean) * to_end / (last.weight / 2.0).max(1.0));
+        }
+
+        let mut center = first.weight / 2.0;
+        for pair in self.centroids.windows(2) {
+            let next_center = center + (pair[0].weight + pair[1].weight) / 2.0;
+            if rank <= next_center {
+                let frac = (rank - center) / (next_center - center);
+                return Some(pair[0].mean + (pair[1].mean - pair[0].mean) * frac);
+            }
+            center = next_center;
+        }
+        Some(last.mean)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tdigest() {
+        // small inputs are exact
+        let digest = TDigest::from_values((0..27).map(|v| v as f64));
+        assert_eq!(digest.quantile(0.5), Some(13.0));
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some(26.0));
+        assert_eq!(TDigest::default().quantile(0.5), None);
+
+        let left = TDigest::from_values((0..50_000).map(|v| v as f64));
+        let right = TDigest::from_values((50_000..100_000).map(|v| v as f64));
+        let digest = TDigest::merge([&left, &right]);
+        assert!(digest.centroids().len() < 200);
+        for q in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let estimate = digest.quantile(q).unwrap();
+            assert!((estimate - q * 100_000.0).abs() < 100_000.0 * 0.005);
+        }
+
+        // values are merged in batches, unsorted input gives the same bounded digest
+        let digest = TDigest::from_values((0..100_000).map(|v| ((v * 7919) % 100_000) as f64));
+        assert!(digest.centroids().len() < 200);
+        for q in [0.01, 0.5, 0.99] {
+            let estimate = digest.quantile(q).unwrap();
+            assert!((estimate - q * 100_000.0).abs() < 100_000.0 * 0.005);
+        }
+    }
+
+    #[test]
+    fn test_tdigest_nan() {
+        let digest = TDigest::from_values([f64::NAN, 1.0, f64::NAN, 3.0]);
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(1.0), Some(3.0));
+        assert!(TDigest::from_values([f64::NAN]).is_empty());
+
+        // a state with a NaN centroid doesn't panic
+        let centroids = vec![
+            Centroid {
+                mean: f64::NAN,
+                weight: 1.0,
+            },
+            Centroid {
+                mean: 1.0,
+                weight: 1.0,
+            },
+        ];
+        let digest = TDigest::from_parts(centroids, 1.0, 1.0);
+        assert_eq!(digest.centroids().len(), 2);
+        // and the NaN centroid is sorted to the end
+        assert_eq!(digest.centroids()[0].mean, 1.0);
+        assert!(digest.centroids()[1].mean.is_nan());
+    }
+}
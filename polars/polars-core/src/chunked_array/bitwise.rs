@@ -2,8 +2,56 @@ use super::*;
 use crate::utils::{align_chunks_binary, combine_validities, CustomIterTools};
 use arrow::bitmap::MutableBitmap;
 use arrow::compute;
+use num::PrimInt;
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
+/// Apply a binary bitwise kernel, broadcasting a length-1 operand.
+fn bitwise_binary<T, F>(lhs: &ChunkedArray<T>, rhs: &ChunkedArray<T>, op: F) -> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    F: Fn(T::Native, T::Native) -> T::Native + Copy,
+{
+    match (lhs.len(), rhs.len()) {
+        (_, 1) if lhs.len() != 1 => {
+            return match rhs.get(0) {
+                Some(r) => lhs.apply(|l| op(l, r)),
+                None => ChunkedArray::full_null(lhs.name(), lhs.len()),
+            };
+        }
+        (1, _) if rhs.len() != 1 => {
+            let mut out = match lhs.get(0) {
+                Some(l) => rhs.apply(|r| op(l, r)),
+                None => ChunkedArray::full_null(lhs.name(), rhs.len()),
+            };
+            out.rename(lhs.name());
+            return out;
+        }
+        _ => {}
+    }
+
+    let (l, r) = align_chunks_binary(lhs, rhs);
+    let chunks = l
+        .downcast_iter()
+        .zip(r.downcast_iter())
+        .map(|(l_arr, r_arr)| {
+            let l_vals = l_arr.values().as_slice();
+            let r_vals = r_arr.values().as_slice();
+            let valididity = combine_validities(l_arr.validity(), r_arr.validity());
+
+            let av = l_vals
+                .iter()
+                .zip(r_vals)
+                .map(|(l, r)| op(*l, *r))
+                .collect_trusted::<Vec<_>>();
+
+            let arr = PrimitiveArray::from_data(T::get_dtype().to_arrow(), av.into(), valididity);
+            Arc::new(arr) as ArrayRef
+        })
+        .collect::<Vec<_>>();
+
+    ChunkedArray::new_from_chunks(lhs.name(), chunks)
+}
+
 impl<T> BitAnd for &ChunkedArray<T>
 where
     T: PolarsIntegerType,
@@ -12,28 +60,7 @@ where
     type Output = ChunkedArray<T>;
 
     fn bitand(self, rhs: Self) -> Self::Output {
-        let (l, r) = align_chunks_binary(self, rhs);
-        let chunks = l
-            .downcast_iter()
-            .zip(r.downcast_iter())
-            .map(|(l_arr, r_arr)| {
-                let l_vals = l_arr.values().as_slice();
-                let r_vals = r_arr.values().as_slice();
-                let valididity = combine_validities(l_arr.validity(), r_arr.validity());
-
-                let av = l_vals
-                    .iter()
-                    .zip(r_vals)
-                    .map(|(l, r)| *l & *r)
-                    .collect_trusted::<Vec<_>>();
-
-                let arr =
-                    PrimitiveArray::from_data(T::get_dtype().to_arrow(), av.into(), valididity);
-                Arc::new(arr) as ArrayRef
-            })
-            .collect::<Vec<_>>();
-
-        ChunkedArray::new_from_chunks(self.name(), chunks)
+        bitwise_binary(self, rhs, |l, r| l & r)
     }
 }
 
@@ -45,28 +72,7 @@ where
     type Output = ChunkedArray<T>;
 
     fn bitor(self, rhs: Self) -> Self::Output {
-        let (l, r) = align_chunks_binary(self, rhs);
-        let chunks = l
-            .downcast_iter()
-            .zip(r.downcast_iter())
-            .map(|(l_arr, r_arr)| {
-                let l_vals = l_arr.values().as_slice();
-                let r_vals = r_arr.values().as_slice();
-                let valididity = combine_validities(l_arr.validity(), r_arr.validity());
-
-                let av = l_vals
-                    .iter()
-                    .zip(r_vals)
-                    .map(|(l, r)| *l | *r)
-                    .collect_trusted::<Vec<_>>();
-
-                let arr =
-                    PrimitiveArray::from_data(T::get_dtype().to_arrow(), av.into(), valididity);
-                Arc::new(arr) as ArrayRef
-            })
-            .collect::<Vec<_>>();
-
-        ChunkedArray::new_from_chunks(self.name(), chunks)
+        bitwise_binary(self, rhs, |l, r| l | r)
     }
 }
 
@@ -78,28 +84,109 @@ where
     type Output = ChunkedArray<T>;
 
     fn bitxor(self, rhs: Self) -> Self::Output {
-        let (l, r) = align_chunks_binary(self, rhs);
-        let chunks = l
-            .downcast_iter()
-            .zip(r.downcast_iter())
-            .map(|(l_arr, r_arr)| {
-                let l_vals = l_arr.values().as_slice();
-                let r_vals = r_arr.values().as_slice();
-                let valididity = combine_validities(l_arr.validity(), r_arr.validity());
+        bitwise_binary(self, rhs, |l, r| l ^ r)
+    }
+}
 
-                let av = l_vals
-                    .iter()
-                    .zip(r_vals)
-                    .map(|(l, r)| l.bitxor(*r))
-                    .collect_trusted::<Vec<_>>();
+impl<T> Not for &ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: Not<Output = T::Native>,
+{
+    type Output = ChunkedArray<T>;
 
-                let arr =
-                    PrimitiveArray::from_data(T::get_dtype().to_arrow(), av.into(), valididity);
-                Arc::new(arr) as ArrayRef
+    fn not(self) -> Self::Output {
+        self.apply(|v| !v)
+    }
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: PrimInt,
+{
+    const BITS: u32 = (std::mem::size_of::<T::Native>() * 8) as u32;
+
+    fn shift_by<F>(&self, n: &UInt32Chunked, f: F) -> Self
+    where
+        F: Fn(T::Native, u32) -> T::Native + Copy,
+    {
+        if n.len() == 1 && self.len() != 1 {
+            return match n.get(0) {
+                Some(n) => self.apply(|v| f(v, n)),
+                None => Self::full_null(self.name(), self.len()),
+            };
+        }
+        let mut out: Self = self
+            .into_iter()
+            .zip(n.into_iter())
+            .map(|(v, n)| match (v, n) {
+                (Some(v), Some(n)) => Some(f(v, n)),
+                _ => None,
             })
-            .collect::<Vec<_>>();
+            .collect();
+        out.rename(self.name());
+        out
+    }
 
-        ChunkedArray::new_from_chunks(self.name(), chunks)
+    /// Shift the bits of every value `n` positions to the left. Shifting by the bit width
+    /// or more yields zero. `n` may be a single value that is broadcasted.
+    pub fn shift_left(&self, n: &UInt32Chunked) -> Self {
+        self.shift_by(n, |v, n| {
+            if n >= Self::BITS {
+                T::Native::zero()
+            } else {
+                v << n as usize
+            }
+        })
+    }
+
+    /// Shift the bits of every value `n` positions to the right. Signed integers are
+    /// shifted arithmetically, so shifting by the bit width or more yields zero or `-1`.
+    /// `n` may be a single value that is broadcasted.
+    pub fn shift_right(&self, n: &UInt32Chunked) -> Self {
+        self.shift_by(n, |v, n| {
+            if n >= Self::BITS {
+                (v >> (Self::BITS - 1) as usize) >> 1
+            } else {
+                v >> n as usize
+            }
+        })
+    }
+
+    /// Count the number of set bits of every value.
+    pub fn count_ones(&self) -> UInt32Chunked {
+        self.apply_cast_numeric(|v| v.count_ones())
+    }
+
+    /// Count the number of unset bits of every value.
+    pub fn count_zeros(&self) -> UInt32Chunked {
+        self.apply_cast_numeric(|v| v.count_zeros())
+    }
+
+    /// Count the number of leading unset bits of every value.
+    pub fn leading_zeros(&self) -> UInt32Chunked {
+        self.apply_cast_numeric(|v| v.leading_zeros())
+    }
+
+    /// Count the number of trailing unset bits of every value.
+    pub fn trailing_zeros(&self) -> UInt32Chunked {
+        self.apply_cast_numeric(|v| v.trailing_zeros())
+    }
+
+    /// Check if bit `n` (counted from the least significant bit) is set. Bits outside
+    /// of the bit width are never set.
+    pub fn is_bit_set(&self, n: u32) -> BooleanChunked {
+        let mut out: BooleanChunked = if n >= Self::BITS {
+            self.into_iter().map(|opt_v| opt_v.map(|_| false)).collect()
+        } else {
+            let mask = T::Native::one() << n as usize;
+            self.into_iter()
+                .map(|opt_v| opt_v.map(|v| v & mask != T::Native::zero()))
+                .collect()
+        };
+        out.rename(self.name());
+        out
     }
 }
 
@@ -137,8 +137,12 @@ impl CategoricalChunkedBuilder {
     where
         I: IntoIterator<Item = Option<&'a str>>,
     {
-        if use_string_cache() {
+        // the builder decides, as the string cache may be turned on or off by another thread
+        // after the builder was created
+        if let RevMappingBuilder::Global(_, _, uuid) = &mut self.reverse_mapping {
             let mut cache = crate::STRING_CACHE.lock_map();
+            // the cache may have been reset since the builder was created
+            *uuid = cache.uuid;
 
             for opt_s in i {
                 match opt_s {
@@ -274,4 +278,56 @@ mod test {
             assert_eq!(s.str_value(2), "\"world\"");
         }
     }
+
+    #[test]
+    fn test_string_cache_guard() {
+        use crate::{toggle_string_cache, use_string_cache, StringCacheGuard};
+        let _lock = crate::SINGLE_LOCK.lock();
+        toggle_string_cache(false);
+        assert!(!use_string_cache());
+
+        let outer = StringCacheGuard::new();
+        let s1 = Series::new("a", &["a", "b"])
+            .cast(&DataType::Categorical)
+            .unwrap();
+        {
+            let _inner = StringCacheGuard::new();
+            // toggling off doesn't turn off the cache of the guards
+            toggle_string_cache(false);
+            assert!(use_string_cache());
+        }
+        assert!(use_string_cache());
+        let s2 = Series::new("b", &["b", "c"])
+            .cast(&DataType::Categorical)
+            .unwrap();
+        let (l, r) = (s1.categorical().unwrap(), s2.categorical().unwrap());
+        assert!(l
+            .get_categorical_map()
+            .unwrap()
+            .same_src(r.get_categorical_map().unwrap()));
+
+        drop(outer);
+        assert!(!use_string_cache());
+    }
+
+    #[test]
+    fn test_reset_string_cache_in_use() -> Result<()> {
+        use crate::{use_string_cache, StringCacheGuard, STRING_CACHE};
+        let _lock = SINGLE_LOCK.lock();
+        toggle_string_cache(false);
+
+        let _guard = StringCacheGuard::new();
+        let s1 = Series::new("a", &["a", "b"]).cast(&DataType::Categorical)?;
+        assert_eq!(STRING_CACHE.lock_map().map.len(), 2);
+
+        // the cache is freed, but stays in use
+        reset_string_cache();
+        assert!(STRING_CACHE.lock_map().map.is_empty());
+        assert!(use_string_cache());
+
+        // categoricals from before the reset are re-mapped
+        let s2 = Series::new("b", &["a", "c"]).cast(&DataType::Categorical)?;
+        assert_eq!(Vec::from(&s1.equal(&s2)), &[Some(true), Some(false)]);
+        Ok(())
+    }
 }
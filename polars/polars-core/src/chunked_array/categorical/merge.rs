@@ -1,7 +1,35 @@
 use super::*;
+use crate::datatypes::PlHashMap;
 use arrow::bitmap::MutableBitmap;
 use std::sync::Arc;
 
+impl RevMapping {
+    /// Iterate over the categories and their string values.
+    fn categories(&self) -> Box<dyn Iterator<Item = (u32, &str)> + '_> {
+        match self {
+            RevMapping::Global(map, slots, _) => Box::new(map.iter().map(move |(cat, idx)| {
+                // Safety
+                // within bounds
+                (*cat, unsafe { slots.value_unchecked(*idx as usize) })
+            })),
+            RevMapping::Local(slots) => Box::new(
+                (0..slots.len())
+                    // Safety
+                    // within bounds
+                    .map(move |idx| (idx as u32, unsafe { slots.value_unchecked(idx) })),
+            ),
+        }
+    }
+}
+
+/// Check if the categories of `l` and `r` can be compared without re-mapping.
+fn same_categories(l: &CategoricalChunked, r: &CategoricalChunked) -> bool {
+    match (l.categorical_map.as_ref(), r.categorical_map.as_ref()) {
+        (Some(l), Some(r)) => l.same_src(r) || Arc::ptr_eq(l, r),
+        _ => true,
+    }
+}
+
 impl CategoricalChunked {
     pub(crate) fn merge_categorical_map(&self, other: &Self) -> Arc<RevMapping> {
         match (
@@ -63,4 +91,59 @@ impl CategoricalChunked {
             }
         }
     }
+
+    /// Replace the categories with `translation[category]`. Null slots map to `0`.
+    fn remap(&self, translation: &PlHashMap<u32, u32>, rev_map: Arc<RevMapping>) -> Self {
+        let codes: UInt32Chunked =
+            (**self).apply(|cat| translation.get(&cat).copied().unwrap_or(0));
+        let mut out: CategoricalChunked = codes.into();
+        out.set_categorical_map(rev_map);
+        out
+    }
+
+    /// Encode `self` and `other` under a single (local) rev map, so that their categories
+    /// can be compared, joined or appended.
+    ///
+    /// This is a no-op if both are created under the same global string cache.
+    pub(crate) fn remap_to_common(&self, other: &Self) -> (Self, Self) {
+        if same_categories(self, other) {
+            return (self.clone(), other.clone());
+        }
+        let l_map = self.categorical_map.as_ref().unwrap();
+        let r_map = other.categorical_map.as_ref().unwrap();
+
+        let mut slots = MutableUtf8Array::<i64>::with_capacity(l_map.len() + r_map.len());
+        let mut lookup = PlHashMap::with_capacity(l_map.len() + r_map.len());
+        let mut translate = |rev_map: &RevMapping| -> PlHashMap<u32, u32> {
+            rev_map
+                .categories()
+                .map(|(cat, value)| {
+                    let new_cat = *lookup.entry(value.to_string()).or_insert_with(|| {
+                        slots.push(Some(value));
+                        slots.len() as u32 - 1
+                    });
+                    (cat, new_cat)
+                })
+                .collect()
+        };
+        let l_translation = translate(l_map.as_ref());
+        let r_translation = translate(r_map.as_ref());
+
+        let rev_map = Arc::new(RevMapping::Local(slots.into()));
+        (
+            self.remap(&l_translation, rev_map.clone()),
+            other.remap(&r_translation, rev_map),
+        )
+    }
+}
+
+/// Encode two categorical Series under a single rev map. Other Series are returned as is.
+pub(crate) fn remap_categoricals(l: &Series, r: &Series) -> (Series, Series) {
+    match (l.categorical(), r.categorical()) {
+        (Ok(l_ca), Ok(r_ca)) if !same_categories(l_ca, r_ca) => {
+            let (l_ca, r_ca) = l_ca.remap_to_common(r_ca);
+            (l_ca.into_series(), r_ca.into_series())
+        }
+        _ => (l.clone(), r.clone()),
+    }
 }
@@ -5,6 +5,7 @@ mod builder;
 mod merge;
 
 pub use builder::*;
+pub(crate) use merge::remap_categoricals;
 use std::ops::{Deref, DerefMut};
 
 impl From<&CategoricalChunked> for DictionaryArray<u32> {
@@ -195,6 +196,46 @@ mod test {
         assert_eq!(appended.str_value(5), "\"y\"");
     }
 
+    #[test]
+    fn test_remap_local_categoricals() -> Result<()> {
+        let _lock = SINGLE_LOCK.lock();
+        toggle_string_cache(false);
+
+        let a = Series::new("a", vec![Some("a"), Some("b"), None, Some("c")])
+            .cast(&DataType::Categorical)?;
+        let mut b = Series::new("b", vec!["c", "b", "x", "a"]).cast(&DataType::Categorical)?;
+
+        // the categories are compared, not the codes
+        assert_eq!(
+            Vec::from(&a.slice(0, 2).equal(&b.slice(0, 2))),
+            &[Some(false), Some(true)]
+        );
+        let c = Series::new("c", vec!["b", "b"]).cast(&DataType::Categorical)?;
+        assert_eq!(
+            Vec::from(&c.equal(&a.slice(0, 2))),
+            &[Some(false), Some(true)]
+        );
+
+        let mut df_a = DataFrame::new(vec![a])?;
+        let df_b = DataFrame::new(vec![b.rename("a").clone()])?;
+        df_a.vstack_mut(&df_b)?;
+        let out = df_a.column("a")?.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[
+                Some("a"),
+                Some("b"),
+                None,
+                Some("c"),
+                Some("c"),
+                Some("b"),
+                Some("x"),
+                Some("a")
+            ]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_fast_unique() {
         let s = Series::new("1", vec!["a", "b", "c"])
@@ -6,9 +6,12 @@ use crate::{datatypes::PolarsNumericType, prelude::*, utils::CustomIterTools};
 use arrow::compute;
 use arrow::types::simd::Simd;
 use num::{NumCast, ToPrimitive};
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 use std::ops::Add;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum QuantileInterpolOptions {
     Nearest,
     Lower,
@@ -123,95 +126,98 @@ where
         quantile: f64,
         interpol: QuantileInterpolOptions,
     ) -> Result<Option<T::Native>> {
-        if !(0.0..=1.0).contains(&quantile) {
+        Ok(self.quantiles(&[quantile], interpol)?[0])
+    }
+
+    fn quantiles(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Vec<Option<T::Native>>> {
+        if quantiles.iter().any(|q| !(0.0..=1.0).contains(q)) {
             return Err(PolarsError::ValueError(
                 "quantile should be between 0.0 and 1.0".into(),
             ));
         }
 
-        let null_count = self.null_count();
-        let length = self.len();
-
-        if null_count == length {
-            return Ok(None);
+        if self.null_count() == self.len() {
+            return Ok(vec![None; quantiles.len()]);
         }
+        // sort once for all quantiles
+        let sorted = ChunkSort::sort(self, false);
+        Ok(quantiles
+            .iter()
+            .map(|q| quantile_sorted(&sorted, self.null_count(), *q, interpol))
+            .collect())
+    }
+}
 
-        let mut idx = match interpol {
-            QuantileInterpolOptions::Nearest => {
-                (((length - null_count) as f64) * quantile + null_count as f64) as i64
-            }
-            QuantileInterpolOptions::Lower
-            | QuantileInterpolOptions::Midpoint
-            | QuantileInterpolOptions::Linear => {
-                (((length - null_count) as f64 - 1.0) * quantile + null_count as f64) as i64
-            }
-            QuantileInterpolOptions::Higher => {
-                (((length - null_count) as f64 - 1.0) * quantile + null_count as f64).ceil() as i64
-            }
-        };
+/// The `quantile` of the `sorted` values, of which the first `null_count` values are null.
+fn quantile_sorted<T>(
+    sorted: &ChunkedArray<T>,
+    null_count: usize,
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+) -> Option<T::Native>
+where
+    T: PolarsNumericType,
+{
+    let length = sorted.len();
 
-        if idx >= length as i64 {
-            idx = (length - 1) as i64;
-        } else if idx <= 0i64 {
-            idx = 0;
+    let mut idx = match interpol {
+        QuantileInterpolOptions::Nearest => {
+            (((length - null_count) as f64) * quantile + null_count as f64) as i64
+        }
+        QuantileInterpolOptions::Lower
+        | QuantileInterpolOptions::Midpoint
+        | QuantileInterpolOptions::Linear => {
+            (((length - null_count) as f64 - 1.0) * quantile + null_count as f64) as i64
         }
+        QuantileInterpolOptions::Higher => {
+            (((length - null_count) as f64 - 1.0) * quantile + null_count as f64).ceil() as i64
+        }
+    };
 
-        let opt = match interpol {
-            QuantileInterpolOptions::Midpoint => {
-                let top_idx = (((length - null_count) as f64 - 1.0) * quantile + null_count as f64)
-                    .ceil() as i64;
-                if top_idx == idx {
-                    ChunkSort::sort(self, false)
-                        .slice(idx, 1)
-                        .into_iter()
-                        .next()
-                        .flatten()
-                } else {
-                    let bounds: Vec<Option<T::Native>> = ChunkSort::sort(self, false)
-                        .slice(idx, 2)
-                        .into_iter()
-                        .collect();
+    if idx >= length as i64 {
+        idx = (length - 1) as i64;
+    } else if idx <= 0i64 {
+        idx = 0;
+    }
+
+    match interpol {
+        QuantileInterpolOptions::Midpoint => {
+            let top_idx =
+                (((length - null_count) as f64 - 1.0) * quantile + null_count as f64).ceil() as i64;
+            if top_idx == idx {
+                sorted.slice(idx, 1).into_iter().next().flatten()
+            } else {
+                let bounds: Vec<Option<T::Native>> = sorted.slice(idx, 2).into_iter().collect();
 
-                    let length: T::Native = NumCast::from(bounds.len()).unwrap();
+                let length: T::Native = NumCast::from(bounds.len()).unwrap();
 
-                    Some((bounds[0].unwrap() + bounds[1].unwrap()) / length)
-                }
+                Some((bounds[0].unwrap() + bounds[1].unwrap()) / length)
             }
-            QuantileInterpolOptions::Linear => {
-                let float_idx = ((length - null_count) as f64 - 1.0) * quantile + null_count as f64;
-                let top_idx = f64::ceil(float_idx) as i64;
-
-                if top_idx == idx {
-                    ChunkSort::sort(self, false)
-                        .slice(idx, 1)
-                        .into_iter()
-                        .next()
-                        .flatten()
+        }
+        QuantileInterpolOptions::Linear => {
+            let float_idx = ((length - null_count) as f64 - 1.0) * quantile + null_count as f64;
+            let top_idx = f64::ceil(float_idx) as i64;
+
+            if top_idx == idx {
+                sorted.slice(idx, 1).into_iter().next().flatten()
+            } else {
+                let bounds: Vec<Option<T::Native>> = sorted.slice(idx, 2).into_iter().collect();
+
+                if bounds[0] == bounds[1] {
+                    Some(bounds[0].unwrap())
                 } else {
-                    let bounds: Vec<Option<T::Native>> = ChunkSort::sort(self, false)
-                        .slice(idx, 2)
-                        .into_iter()
-                        .collect();
-
-                    if bounds[0] == bounds[1] {
-                        Some(bounds[0].unwrap())
-                    } else {
-                        let proportion: T::Native = NumCast::from(float_idx - idx as f64).unwrap();
-                        Some(
-                            proportion * (bounds[1].unwrap() - bounds[0].unwrap())
-                                + bounds[0].unwrap(),
-                        )
-                    }
+                    let proportion: T::Native = NumCast::from(float_idx - idx as f64).unwrap();
+                    Some(
+                        proportion * (bounds[1].unwrap() - bounds[0].unwrap()) + bounds[0].unwrap(),
+                    )
                 }
             }
-            _ => ChunkSort::sort(self, false)
-                .slice(idx, 1)
-                .into_iter()
-                .next()
-                .flatten(),
-        };
-
-        Ok(opt)
+        }
+        _ => sorted.slice(idx, 1).into_iter().next().flatten(),
     }
 }
 
@@ -1102,4 +1108,33 @@ mod test {
                 < 0.0001
         );
     }
+
+    #[test]
+    fn test_quantiles() {
+        let ca = Float64Chunked::new_from_opt_slice(
+            "",
+            &[Some(4.0), None, Some(1.0), Some(3.0), Some(2.0)],
+        );
+        for interpol in [
+            QuantileInterpolOptions::Nearest,
+            QuantileInterpolOptions::Midpoint,
+            QuantileInterpolOptions::Linear,
+        ] {
+            let quantiles = [0.0, 0.3, 0.5, 1.0];
+            let expected = quantiles
+                .iter()
+                .map(|q| ca.quantile(*q, interpol).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(ca.quantiles(&quantiles, interpol).unwrap(), expected);
+        }
+        assert!(ca
+            .quantiles(&[0.5, 1.5], QuantileInterpolOptions::Linear)
+            .is_err());
+        let ca = Int32Chunked::new_from_opt_slice("", &[None, None]);
+        assert_eq!(
+            ca.quantiles(&[0.1, 0.9], QuantileInterpolOptions::Linear)
+                .unwrap(),
+            &[None, None]
+        );
+    }
 }
@@ -48,21 +48,26 @@ impl<T: PolarsObject> ObjectChunked<T> {
 }
 #[cfg(feature = "dtype-categorical")]
 impl CategoricalChunked {
+    /// Append in place. If `other` is not created under the same global string cache, both
+    /// are re-encoded under a common rev map.
     pub fn append(&mut self, other: &Self) {
+        let mut remapped = None;
         if let (Some(rev_map_l), Some(rev_map_r)) = (
             self.categorical_map.as_ref(),
             other.categorical_map.as_ref(),
         ) {
-            // first assertion checks if the global string cache is equal,
+            // first check if the global string cache is equal,
             // the second checks if we append a slice from this array to self
-            if !rev_map_l.same_src(rev_map_r) && !Arc::ptr_eq(rev_map_l, rev_map_r) {
-                panic!("Appending categorical data can only be done if they are made under the same global string cache. \
-                Consider using a global string cache.")
+            if rev_map_l.same_src(rev_map_r) {
+                let new_rev_map = self.merge_categorical_map(other);
+                self.categorical_map = Some(new_rev_map);
+            } else if !Arc::ptr_eq(rev_map_l, rev_map_r) {
+                let (l, r) = self.remap_to_common(other);
+                *self = l;
+                remapped = Some(r);
             }
-
-            let new_rev_map = self.merge_categorical_map(other);
-            self.categorical_map = Some(new_rev_map);
         }
+        let other = remapped.as_ref().unwrap_or(other);
 
         let len = self.len();
         new_chunks(&mut self.chunks, &other.chunks, len);
@@ -5,71 +5,62 @@ use num::{Bounded, NumCast, One, Zero};
 use polars_arrow::kernels::set::set_at_nulls;
 use polars_arrow::utils::CustomIterTools;
 use std::ops::Add;
+#[cfg(feature = "dtype-categorical")]
+use std::ops::Deref;
 
-fn fill_forward<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
+/// Replace every null with the last non-null value seen, filling at most `limit`
+/// consecutive nulls.
+fn fill_forward_iter<I, V>(iter: I, limit: Option<usize>) -> impl Iterator<Item = Option<V>>
 where
-    T: PolarsNumericType,
+    I: Iterator<Item = Option<V>>,
+    V: Clone,
 {
-    ca.into_iter()
-        .scan(None, |previous, opt_v| match opt_v {
+    let limit = limit.unwrap_or(usize::MAX);
+    iter.scan(
+        (None, 0usize),
+        move |(previous, count), opt_v| match opt_v {
             Some(value) => {
-                *previous = Some(value);
+                *previous = Some(value.clone());
+                *count = 0;
                 Some(Some(value))
             }
-            None => Some(*previous),
-        })
-        .trust_my_length(ca.len())
-        .collect_trusted()
+            None if *count < limit => {
+                *count += 1;
+                Some(previous.clone())
+            }
+            None => Some(None),
+        },
+    )
 }
 
-macro_rules! impl_fill_forward {
-    ($ca:ident) => {{
-        $ca.into_iter()
-            .scan(None, |previous, opt_v| match opt_v {
-                Some(value) => {
-                    *previous = Some(value);
-                    Some(Some(value))
-                }
-                None => Some(*previous),
-            })
-            .trust_my_length($ca.len())
-            .collect_trusted()
-    }};
+fn fill_forward<T>(ca: &ChunkedArray<T>, limit: Option<usize>) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    fill_forward_iter(ca.into_iter(), limit)
+        .trust_my_length(ca.len())
+        .collect_trusted()
 }
 
-fn fill_backward<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
+fn fill_backward<T>(ca: &ChunkedArray<T>, limit: Option<usize>) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
 {
-    ca.into_iter()
-        .rev()
-        .scan(None, |previous, opt_v| match opt_v {
-            Some(value) => {
-                *previous = Some(value);
-                Some(Some(value))
-            }
-            None => Some(*previous),
-        })
+    fill_forward_iter(ca.into_iter().rev(), limit)
         .trust_my_length(ca.len())
         .collect_reversed()
 }
 
-macro_rules! impl_fill_backward {
-    ($ca:ident, $ChunkedArray:ty) => {{
-        let ca: $ChunkedArray = $ca
-            .into_iter()
-            .rev()
-            .scan(None, |previous, opt_v| match opt_v {
-                Some(value) => {
-                    *previous = Some(value);
-                    Some(Some(value))
-                }
-                None => Some(*previous),
-            })
-            .trust_my_length($ca.len())
-            .collect_trusted();
-        ca.into_iter().rev().collect_trusted()
-    }};
+/// Backward fill for arrays that cannot be collected in reverse.
+fn fill_backward_collect<I, V, Out>(iter: I, limit: Option<usize>) -> Out
+where
+    I: DoubleEndedIterator<Item = Option<V>>,
+    V: Clone,
+    Out: FromIterator<Option<V>>,
+{
+    let mut values: Vec<_> = fill_forward_iter(iter.rev(), limit).collect();
+    values.reverse();
+    values.into_iter().collect()
 }
 
 impl<T> ChunkFillNull for ChunkedArray<T>
@@ -85,8 +76,8 @@ where
             return Ok(self.clone());
         }
         let mut ca = match strategy {
-            FillNullStrategy::Forward => fill_forward(self),
-            FillNullStrategy::Backward => fill_backward(self),
+            FillNullStrategy::Forward(limit) => fill_forward(self, limit),
+            FillNullStrategy::Backward(limit) => fill_backward(self, limit),
             FillNullStrategy::Min => {
                 self.fill_null_with_values(self.min().ok_or_else(|| {
                     PolarsError::ComputeError("Could not determine fill value".into())
@@ -130,14 +121,15 @@ impl ChunkFillNull for BooleanChunked {
             return Ok(self.clone());
         }
         match strategy {
-            FillNullStrategy::Forward => {
-                let mut out: Self = impl_fill_forward!(self);
+            FillNullStrategy::Forward(limit) => {
+                let mut out: Self = fill_forward_iter(self.into_iter(), limit)
+                    .trust_my_length(self.len())
+                    .collect_trusted();
                 out.rename(self.name());
                 Ok(out)
             }
-            FillNullStrategy::Backward => {
-                // TODO: still a double scan. impl collect_reversed for boolean
-                let mut out: Self = impl_fill_backward!(self, BooleanChunked);
+            FillNullStrategy::Backward(limit) => {
+                let mut out: Self = fill_backward_collect(self.into_iter(), limit);
                 out.rename(self.name());
                 Ok(out)
             }
@@ -175,13 +167,15 @@ impl ChunkFillNull for Utf8Chunked {
             return Ok(self.clone());
         }
         match strategy {
-            FillNullStrategy::Forward => {
-                let mut out: Self = impl_fill_forward!(self);
+            FillNullStrategy::Forward(limit) => {
+                let mut out: Self = fill_forward_iter(self.into_iter(), limit)
+                    .trust_my_length(self.len())
+                    .collect_trusted();
                 out.rename(self.name());
                 Ok(out)
             }
-            FillNullStrategy::Backward => {
-                let mut out: Self = impl_fill_backward!(self, Utf8Chunked);
+            FillNullStrategy::Backward(limit) => {
+                let mut out: Self = fill_backward_collect(self.into_iter(), limit);
                 out.rename(self.name());
                 Ok(out)
             }
@@ -199,19 +193,39 @@ impl ChunkFillNullValue<&str> for Utf8Chunked {
 }
 
 impl ChunkFillNull for ListChunked {
-    fn fill_null(&self, _strategy: FillNullStrategy) -> Result<Self> {
-        Err(PolarsError::InvalidOperation(
-            "fill_null not supported for List type".into(),
-        ))
+    fn fill_null(&self, strategy: FillNullStrategy) -> Result<Self> {
+        // nothing to fill
+        if !self.has_validity() || self.null_count() == self.len() {
+            return Ok(self.clone());
+        }
+        let mut out: Self = match strategy {
+            FillNullStrategy::Forward(limit) => fill_forward_iter(self.into_iter(), limit)
+                .trust_my_length(self.len())
+                .collect_trusted(),
+            FillNullStrategy::Backward(limit) => fill_backward_collect(self.into_iter(), limit),
+            strat => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("Strategy {:?} not supported for List type", strat).into(),
+                ))
+            }
+        };
+        out.rename(self.name());
+        Ok(out)
     }
 }
 
 #[cfg(feature = "dtype-categorical")]
 impl ChunkFillNull for CategoricalChunked {
-    fn fill_null(&self, _strategy: FillNullStrategy) -> Result<Self> {
-        Err(PolarsError::InvalidOperation(
-            "fill_null not supported for Categorical type".into(),
-        ))
+    fn fill_null(&self, strategy: FillNullStrategy) -> Result<Self> {
+        match strategy {
+            FillNullStrategy::Forward(_) | FillNullStrategy::Backward(_) => {
+                let ca: CategoricalChunked = self.deref().fill_null(strategy)?.into();
+                Ok(ca.set_state(self))
+            }
+            strat => Err(PolarsError::InvalidOperation(
+                format!("Strategy {:?} not supported for Categorical type", strat).into(),
+            )),
+        }
     }
 }
 
@@ -223,11 +237,24 @@ impl ChunkFillNullValue<&Series> for ListChunked {
     }
 }
 #[cfg(feature = "object")]
-impl<T> ChunkFillNull for ObjectChunked<T> {
-    fn fill_null(&self, _strategy: FillNullStrategy) -> Result<Self> {
-        Err(PolarsError::InvalidOperation(
-            "fill_null not supported for Object type".into(),
-        ))
+impl<T: PolarsObject> ChunkFillNull for ObjectChunked<T> {
+    fn fill_null(&self, strategy: FillNullStrategy) -> Result<Self> {
+        // nothing to fill
+        if !self.has_validity() {
+            return Ok(self.clone());
+        }
+        let iter = self.into_iter().map(|opt_v| opt_v.cloned());
+        let mut out: Self = match strategy {
+            FillNullStrategy::Forward(limit) => fill_forward_iter(iter, limit).collect(),
+            FillNullStrategy::Backward(limit) => fill_backward_collect(iter, limit),
+            strat => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("Strategy {:?} not supported for Object type", strat).into(),
+                ))
+            }
+        };
+        out.rename(self.name());
+        Ok(out)
     }
 }
 
@@ -247,14 +274,14 @@ mod test {
     #[test]
     fn test_fill_null() {
         let ca = Int32Chunked::new("a", &[None, Some(2), Some(3), None, Some(4), None]);
-        let filled = ca.fill_null(FillNullStrategy::Forward).unwrap();
+        let filled = ca.fill_null(FillNullStrategy::Forward(None)).unwrap();
         assert_eq!(filled.name(), "a");
 
         assert_eq!(
             Vec::from(&filled),
             &[None, Some(2), Some(3), Some(3), Some(4), Some(4)]
         );
-        let filled = ca.fill_null(FillNullStrategy::Backward).unwrap();
+        let filled = ca.fill_null(FillNullStrategy::Backward(None)).unwrap();
         assert_eq!(filled.name(), "a");
         assert_eq!(
             Vec::from(&filled),
@@ -279,11 +306,60 @@ mod test {
             &[Some(3), Some(2), Some(3), Some(3), Some(4), Some(3)]
         );
         let ca = Int32Chunked::new("a", &[None, None, None, None, Some(4), None]);
-        let filled = ca.fill_null(FillNullStrategy::Backward).unwrap();
+        let filled = ca.fill_null(FillNullStrategy::Backward(None)).unwrap();
         assert_eq!(filled.name(), "a");
         assert_eq!(
             Vec::from(&filled),
             &[Some(4), Some(4), Some(4), Some(4), Some(4), None]
         );
     }
+
+    #[test]
+    fn test_fill_null_limit() -> Result<()> {
+        let ca = Int32Chunked::new("a", &[Some(1), None, None, None, Some(5), None]);
+        let filled = ca.fill_null(FillNullStrategy::Forward(Some(2)))?;
+        assert_eq!(
+            Vec::from(&filled),
+            &[Some(1), Some(1), Some(1), None, Some(5), Some(5)]
+        );
+        let filled = ca.fill_null(FillNullStrategy::Backward(Some(1)))?;
+        assert_eq!(
+            Vec::from(&filled),
+            &[Some(1), None, None, Some(5), Some(5), None]
+        );
+
+        let ca = Utf8Chunked::new("a", &[Some("a"), None, None, Some("b")]);
+        let filled = ca.fill_null(FillNullStrategy::Backward(None))?;
+        assert_eq!(
+            Vec::from(&filled),
+            &[Some("a"), Some("b"), Some("b"), Some("b")]
+        );
+        assert_eq!(filled.name(), "a");
+
+        let ca: ListChunked = vec![Some(Series::new("", &[1, 2])), None, None]
+            .into_iter()
+            .collect();
+        let filled = ca.fill_null(FillNullStrategy::Forward(Some(1)))?;
+        assert_eq!(filled.null_count(), 1);
+        assert!(filled
+            .get(1)
+            .unwrap()
+            .series_equal(&Series::new("", &[1, 2])));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_fill_null_categorical() -> Result<()> {
+        let s =
+            Series::new("a", &[Some("x"), None, Some("y"), None]).cast(&DataType::Categorical)?;
+        let filled = s.fill_null(FillNullStrategy::Forward(None))?;
+        assert_eq!(filled.dtype(), &DataType::Categorical);
+        let filled = filled.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(filled.utf8()?),
+            &[Some("x"), Some("x"), Some("y"), Some("y")]
+        );
+        Ok(())
+    }
 }
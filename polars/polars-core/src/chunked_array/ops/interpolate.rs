@@ -1,8 +1,65 @@
 use crate::prelude::*;
 use arrow::bitmap::MutableBitmap;
-use num::{FromPrimitive, Zero};
+use num::{FromPrimitive, NumCast, ToPrimitive, Zero};
 use std::ops::{Add, Div, Mul, Sub};
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InterpolationMethod {
+    /// Straight line between the surrounding non-null values.
+    Linear,
+    /// Take the value of the nearest non-null value. Ties take the previous value.
+    Nearest,
+    /// Take the value of the previous non-null value.
+    Previous,
+    /// Take the value of the next non-null value.
+    Next,
+    /// Natural cubic spline through all non-null values.
+    CubicSpline,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InterpolateOptions {
+    pub method: InterpolationMethod,
+    /// Maximum number of consecutive nulls to fill. Nulls of a longer gap
+    /// beyond this limit remain null.
+    pub limit: Option<usize>,
+}
+
+impl Default for InterpolateOptions {
+    fn default() -> Self {
+        Self {
+            method: InterpolationMethod::Linear,
+            limit: None,
+        }
+    }
+}
+
+/// Second derivatives of the natural cubic spline through `(x, y)`.
+fn natural_spline_derivatives(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let mut m = vec![0.0; n];
+    if n < 3 {
+        return m;
+    }
+    // tridiagonal system for the inner points, solved with the Thomas algorithm
+    let mut c_prime = vec![0.0; n];
+    let mut d_prime = vec![0.0; n];
+    for i in 1..n - 1 {
+        let h_prev = x[i] - x[i - 1];
+        let h = x[i + 1] - x[i];
+        let a = h_prev;
+        let b = 2.0 * (h_prev + h);
+        let d = 6.0 * ((y[i + 1] - y[i]) / h - (y[i] - y[i - 1]) / h_prev);
+        let denom = b - a * c_prime[i - 1];
+        c_prime[i] = h / denom;
+        d_prime[i] = (d - a * d_prime[i - 1]) / denom;
+    }
+    for i in (1..n - 1).rev() {
+        m[i] = d_prime[i] - c_prime[i] * m[i + 1];
+    }
+    m
+}
+
 fn linear_itp<T>(low: T, step: T, diff: T, steps_n: T) -> T
 where
     T: Sub<Output = T> + Mul<Output = T> + Add<Output = T> + Div<Output = T>,
@@ -116,6 +173,100 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    pub(crate) fn interpolate_impl(
+        &self,
+        by: Option<&[f64]>,
+        options: InterpolateOptions,
+    ) -> Result<Self> {
+        if !self.has_validity() {
+            return Ok(self.clone());
+        }
+        let position = |i: usize| by.map(|by| by[i]).unwrap_or(i as f64);
+        let to_f64 = |v: T::Native| v.to_f64().unwrap();
+
+        let mut values: Vec<Option<T::Native>> = self.into_iter().collect();
+        let known: Vec<usize> = values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|_| i))
+            .collect();
+
+        let spline = if options.method == InterpolationMethod::CubicSpline {
+            let x: Vec<f64> = known.iter().map(|&i| position(i)).collect();
+            if x.windows(2).any(|w| w[0] >= w[1]) {
+                return Err(PolarsError::ComputeError(
+                    "cubic spline interpolation requires strictly increasing 'by' values at the non-null entries".into(),
+                ));
+            }
+            let y: Vec<f64> = known.iter().map(|&i| to_f64(values[i].unwrap())).collect();
+            let m = natural_spline_derivatives(&x, &y);
+            Some((x, y, m))
+        } else {
+            None
+        };
+
+        for (k, window) in known.windows(2).enumerate() {
+            let (low_idx, high_idx) = (window[0], window[1]);
+            let low = values[low_idx].unwrap();
+            let high = values[high_idx].unwrap();
+            let (x_low, x_high) = (position(low_idx), position(high_idx));
+
+            let mut gap_end = high_idx;
+            if let Some(limit) = options.limit {
+                gap_end = std::cmp::min(gap_end, low_idx + 1 + limit);
+            }
+            for (i, value) in values
+                .iter_mut()
+                .enumerate()
+                .take(gap_end)
+                .skip(low_idx + 1)
+            {
+                let x = position(i);
+                let interpolated = match options.method {
+                    InterpolationMethod::Previous => low,
+                    InterpolationMethod::Next => high,
+                    InterpolationMethod::Nearest => {
+                        if x_high - x < x - x_low {
+                            high
+                        } else {
+                            low
+                        }
+                    }
+                    InterpolationMethod::Linear => {
+                        let (y_low, y_high) = (to_f64(low), to_f64(high));
+                        let width = x_high - x_low;
+                        let v = if width == 0.0 {
+                            y_low
+                        } else {
+                            y_low + (y_high - y_low) * (x - x_low) / width
+                        };
+                        NumCast::from(v).unwrap_or(low)
+                    }
+                    InterpolationMethod::CubicSpline => {
+                        let (xs, ys, m) = spline.as_ref().unwrap();
+                        let h = xs[k + 1] - xs[k];
+                        let a = (xs[k + 1] - x) / h;
+                        let b = (x - xs[k]) / h;
+                        let v = a * ys[k]
+                            + b * ys[k + 1]
+                            + ((a * a * a - a) * m[k] + (b * b * b - b) * m[k + 1]) * h * h / 6.0;
+                        NumCast::from(v).unwrap_or(low)
+                    }
+                };
+                *value = Some(interpolated);
+            }
+        }
+
+        let mut out: Self = values.into_iter().collect();
+        out.rename(self.name());
+        Ok(out)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -143,4 +294,46 @@ mod test {
             &[None, Some(1), Some(2), Some(3), Some(4), Some(5), None]
         );
     }
+
+    #[test]
+    fn test_interpolate_methods() -> Result<()> {
+        let ca = Float64Chunked::new("", &[None, Some(1.0), None, None, Some(4.0), None]);
+        let interpolate = |method, limit| {
+            ca.interpolate_impl(None, InterpolateOptions { method, limit })
+                .map(|out| Vec::from(&out))
+        };
+        assert_eq!(
+            interpolate(InterpolationMethod::Nearest, None)?,
+            &[None, Some(1.0), Some(1.0), Some(4.0), Some(4.0), None]
+        );
+        assert_eq!(
+            interpolate(InterpolationMethod::Previous, None)?,
+            &[None, Some(1.0), Some(1.0), Some(1.0), Some(4.0), None]
+        );
+        assert_eq!(
+            interpolate(InterpolationMethod::Next, None)?,
+            &[None, Some(1.0), Some(4.0), Some(4.0), Some(4.0), None]
+        );
+        assert_eq!(
+            interpolate(InterpolationMethod::Linear, Some(1))?,
+            &[None, Some(1.0), Some(2.0), None, Some(4.0), None]
+        );
+
+        // weighted by the positions in `by`
+        let ca = Float64Chunked::new("", &[Some(0.0), None, Some(8.0)]);
+        let out = ca.interpolate_impl(Some(&[0.0, 1.0, 4.0]), Default::default())?;
+        assert_eq!(Vec::from(&out), &[Some(0.0), Some(2.0), Some(8.0)]);
+
+        let ca = Float64Chunked::new("", &[Some(0.0), None, Some(1.0), None, Some(0.0)]);
+        let options = InterpolateOptions {
+            method: InterpolationMethod::CubicSpline,
+            limit: None,
+        };
+        let out = ca.interpolate_impl(None, options)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(0.0), Some(0.6875), Some(1.0), Some(0.6875), Some(0.0)]
+        );
+        Ok(())
+    }
 }
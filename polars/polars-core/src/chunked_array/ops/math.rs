@@ -0,0 +1,119 @@
+use crate::prelude::*;
+use num::{Float, NumCast, One, Zero};
+
+macro_rules! impl_float_unary {
+    ($($(#[$doc:meta])* $name:ident => $method:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(&self) -> Self {
+                self.apply(|v| v.$method())
+            }
+        )*
+    };
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    /// Compute the logarithm to the given `base`.
+    pub fn log(&self, base: f64) -> Self {
+        let base = <T::Native as NumCast>::from(base).unwrap();
+        self.apply(|v| v.log(base))
+    }
+
+    impl_float_unary! {
+        /// Compute the logarithm to base 10.
+        log10 => log10,
+        /// Compute `ln(1 + x)`, more accurate than `log` for values close to zero.
+        log1p => ln_1p,
+        /// Compute `e^x`.
+        exp => exp,
+        /// Compute the square root.
+        sqrt => sqrt,
+        /// Compute the cube root.
+        cbrt => cbrt,
+        /// Compute the sine (in radians).
+        sin => sin,
+        /// Compute the cosine (in radians).
+        cos => cos,
+        /// Compute the tangent (in radians).
+        tan => tan,
+        /// Compute the inverse sine, in radians.
+        arcsin => asin,
+        /// Compute the inverse cosine, in radians.
+        arccos => acos,
+        /// Compute the inverse tangent, in radians.
+        arctan => atan,
+        /// Compute the hyperbolic sine.
+        sinh => sinh,
+        /// Compute the hyperbolic cosine.
+        cosh => cosh,
+        /// Compute the hyperbolic tangent.
+        tanh => tanh,
+        /// Compute the inverse hyperbolic sine.
+        arcsinh => asinh,
+        /// Compute the inverse hyperbolic cosine.
+        arccosh => acosh,
+        /// Compute the inverse hyperbolic tangent.
+        arctanh => atanh,
+    }
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Replace the values by `-1`, `0` or `1` depending on their sign. `NaN` stays `NaN`.
+    pub fn sign(&self) -> Self {
+        self.apply(|v| {
+            if v > T::Native::zero() {
+                T::Native::one()
+            } else if v < T::Native::zero() {
+                T::Native::zero() - T::Native::one()
+            } else {
+                v
+            }
+        })
+    }
+
+    /// Limit the values to the range `[min, max]`.
+    pub fn clip(&self, min: T::Native, max: T::Native) -> Self {
+        self.apply(|v| {
+            if v < min {
+                min
+            } else if v > max {
+                max
+            } else {
+                v
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_math_kernels() {
+        let ca = Float64Chunked::new("a", &[Some(1.0), None, Some(100.0)]);
+        assert_eq!(Vec::from(&ca.log10()), &[Some(0.0), None, Some(2.0)]);
+        let log = ca.log(10.0);
+        assert!((log.get(2).unwrap() - 2.0).abs() < 1e-12);
+        assert_eq!(log.get(1), None);
+        assert_eq!(Vec::from(&ca.sqrt()), &[Some(1.0), None, Some(10.0)]);
+        assert_eq!(
+            Vec::from(&ca.clip(2.0, 50.0)),
+            &[Some(2.0), None, Some(50.0)]
+        );
+
+        let ca = Int32Chunked::new("a", &[Some(-3), Some(0), None, Some(7)]);
+        assert_eq!(Vec::from(&ca.sign()), &[Some(-1), Some(0), None, Some(1)]);
+        assert_eq!(
+            Vec::from(&ca.clip(-1, 5)),
+            &[Some(-1), Some(0), None, Some(5)]
+        );
+    }
+}
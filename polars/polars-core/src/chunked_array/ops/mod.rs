@@ -2,6 +2,8 @@
 use std::marker::Sized;
 
 use arrow::array::ArrayRef;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 
 pub use self::take::*;
 #[cfg(feature = "object")]
@@ -30,10 +32,12 @@ mod fill_null;
 mod filter;
 pub mod full;
 #[cfg(feature = "interpolate")]
-mod interpolate;
+pub(crate) mod interpolate;
 #[cfg(feature = "is_in")]
 mod is_in;
 mod len;
+#[cfg(feature = "math")]
+mod math;
 mod peaks;
 #[cfg(feature = "repeat_by")]
 mod repeat_by;
@@ -389,6 +393,19 @@ pub trait ChunkAgg<T> {
     fn quantile(&self, _quantile: f64, _interpol: QuantileInterpolOptions) -> Result<Option<T>> {
         Ok(None)
     }
+
+    /// Aggregate the given quantiles of the ChunkedArray. The values are only sorted once.
+    /// Returns `None` for every quantile if the array is empty or only contains null values.
+    fn quantiles(
+        &self,
+        quantiles: &[f64],
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Vec<Option<T>>> {
+        quantiles
+            .iter()
+            .map(|q| self.quantile(*q, interpol))
+            .collect()
+    }
 }
 
 /// Variance and standard deviation aggregation.
@@ -498,6 +515,7 @@ pub trait ToDummies<T>: ChunkUnique<T> {
 }
 
 #[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct SortOptions {
     pub descending: bool,
     pub nulls_last: bool,
@@ -522,12 +540,13 @@ pub trait ChunkSort<T> {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum FillNullStrategy {
-    /// previous value in array
-    Backward,
-    /// next value in array
-    Forward,
+    /// next value in array, filling at most `limit` consecutive nulls
+    Backward(Option<usize>),
+    /// previous value in array, filling at most `limit` consecutive nulls
+    Forward(Option<usize>),
     /// mean value of array
     Mean,
     /// minimal value in array
@@ -547,8 +566,8 @@ pub enum FillNullStrategy {
 /// Replace None values with various strategies
 pub trait ChunkFillNull {
     /// Replace None values with one of the following strategies:
-    /// * Forward fill (replace None with the previous value)
-    /// * Backward fill (replace None with the next value)
+    /// * Forward fill (replace None with the previous value, optionally up to a limit)
+    /// * Backward fill (replace None with the next value, optionally up to a limit)
     /// * Mean fill (replace None with the mean of the whole array)
     /// * Min fill (replace None with the minimum of the whole array)
     /// * Max fill (replace None with the maximum of the whole array)
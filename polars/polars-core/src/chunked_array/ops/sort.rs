@@ -447,6 +447,44 @@ fn ordering_other_columns<'a>(
     Ordering::Equal
 }
 
+/// Get the indices of the first `k` rows in the sorted order of the `by` columns.
+///
+/// This is a partial sort: the `k` smallest rows are selected in linear time and only
+/// those are sorted. Ties are broken by row index, so the result is equal to the first
+/// `k` indices of a stable sort. Like `argsort`, nulls come first, or last if reversed.
+pub(crate) fn arg_top_k(by: &[Series], reverse: &[bool], k: usize) -> UInt32Chunked {
+    let len = by[0].len();
+    let k = std::cmp::min(k, len);
+    let by = by
+        .iter()
+        .map(|s| match s.dtype() {
+            // categoricals are sorted lexically
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical => s.cast(&DataType::Utf8).unwrap(),
+            _ => s.to_physical_repr(),
+        })
+        .collect::<Vec<_>>();
+    let compare_inner: Vec<_> = by.iter().map(|s| s.into_partial_ord_inner()).collect();
+
+    let cmp = |a: &u32, b: &u32| {
+        ordering_other_columns(&compare_inner, reverse, *a as usize, *b as usize)
+            .then_with(|| a.cmp(b))
+    };
+    let mut idx = (0..len as u32).collect::<Vec<_>>();
+    if k == 0 {
+        idx.clear();
+    } else if k < len {
+        idx.select_nth_unstable_by(k - 1, cmp);
+        idx.truncate(k);
+    }
+    idx.sort_unstable_by(cmp);
+
+    let ca: NoNull<UInt32Chunked> = idx.into_iter().collect_trusted();
+    let mut ca = ca.into_inner();
+    ca.rename(by[0].name());
+    ca
+}
+
 impl ChunkSort<Utf8Type> for Utf8Chunked {
     fn sort_with(&self, options: SortOptions) -> ChunkedArray<Utf8Type> {
         sort_with_fast_path!(self, options);
@@ -910,6 +948,27 @@ mod test {
         assert_eq!(Vec::from(&out), expected);
     }
 
+    #[test]
+    fn test_arg_top_k() -> Result<()> {
+        let a = Series::new("a", &[3, 5, 1, 3, 2, 4]);
+        for reverse in [false, true] {
+            let expected = Vec::from(&a.argsort(reverse));
+            for k in 0..8 {
+                let out = super::arg_top_k(&[a.clone()], &[reverse], k);
+                assert_eq!(Vec::from(&out), &expected[..std::cmp::min(k, 6)]);
+            }
+        }
+
+        // multiple keys with nulls
+        let a = Series::new("a", &[Some(3), None, Some(1), Some(3), Some(2), None]);
+        let b = Series::new("b", &["a", "b", "c", "d", "e", "f"]);
+        let out = super::arg_top_k(&[a.clone(), b.clone()], &[true, true], 4);
+        assert_eq!(Vec::from(&out), &[Some(3), Some(0), Some(4), Some(2)]);
+        let out = super::arg_top_k(&[a, b], &[false, true], 3);
+        assert_eq!(Vec::from(&out), &[Some(5), Some(1), Some(2)]);
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_sort_categorical() {
@@ -556,6 +556,7 @@ impl PartialEq<ArrowDataType> for DataType {
 
 /// Characterizes the name and the [`DataType`] of a column.
 #[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Field {
     name: String,
     data_type: DataType,
@@ -654,6 +655,7 @@ impl Field {
 }
 
 #[derive(Clone, Debug, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Schema {
     fields: Vec<Field>,
 }
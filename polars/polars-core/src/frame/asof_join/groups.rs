@@ -7,20 +7,40 @@ use rayon::prelude::*;
 
 use crate::frame::hash_join::{get_hash_tbl_threaded_join_partitioned, multiple_keys as mk};
 
-fn find_latest_leq<T>(left_val: T, right_asof: &[T], subset_idx: &[u32]) -> Option<u32>
+fn find_asof_in_group<T>(
+    left_val: T,
+    right_asof: &[T],
+    subset_idx: &[u32],
+    options: &AsOfOptions,
+    tolerance: Option<T>,
+) -> Option<u32>
 where
-    T: Copy + PartialOrd,
+    T: NumericNative,
 {
-    subset_idx
-        .iter()
-        .rev()
-        .find(|&&i| {
-            debug_assert!((i as usize) < right_asof.len());
-            // Safety:
-            // idx are in bounds
-            unsafe { *right_asof.get_unchecked(i as usize) <= left_val }
-        })
-        .copied()
+    // Safety:
+    // idx are in bounds
+    let right_at = |i: usize| unsafe {
+        let idx = *subset_idx.get_unchecked(i) as usize;
+        debug_assert!(idx < right_asof.len());
+        *right_asof.get_unchecked(idx)
+    };
+    // the subset indexes are in row order, so the right keys in the group are sorted.
+    let lt = subset_idx
+        .partition_point(|&i| unsafe { *right_asof.get_unchecked(i as usize) } < left_val);
+    let le = subset_idx
+        .partition_point(|&i| unsafe { *right_asof.get_unchecked(i as usize) } <= left_val);
+
+    asof_select(
+        left_val,
+        right_at,
+        subset_idx.len(),
+        lt,
+        le,
+        options.strategy,
+        tolerance,
+        options.allow_exact_matches,
+    )
+    .map(|i| subset_idx[i])
 }
 
 // TODO! add faster implementation that has a single groupby key
@@ -29,10 +49,13 @@ fn asof_join_by<T>(
     b: &DataFrame,
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
-) -> Vec<Option<u32>>
+    options: &AsOfOptions,
+) -> Result<Vec<Option<u32>>>
 where
     T: PolarsNumericType,
 {
+    let tolerance = tolerance_to_native::<T::Native>(&options.tolerance)?;
+
     let left_asof = left_asof.rechunk();
     let left_asof = left_asof.cont_slice().unwrap();
 
@@ -55,7 +78,7 @@ where
 
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
-    POOL.install(|| {
+    let out = POOL.install(|| {
         probe_hashes
             .into_par_iter()
             .zip(offsets)
@@ -88,9 +111,9 @@ where
 
                         match entry {
                             // left and right matches
-                            Some((_, indexes_b)) => {
-                                results.push(find_latest_leq(left_val, right_asof, indexes_b))
-                            }
+                            Some((_, indexes_b)) => results.push(find_asof_in_group(
+                                left_val, right_asof, indexes_b, options, tolerance,
+                            )),
                             // only left values, right = null
                             None => results.push(None),
                         }
@@ -102,7 +125,8 @@ where
             })
             .flatten()
             .collect()
-    })
+    });
+    Ok(out)
 }
 
 impl DataFrame {
@@ -117,6 +141,7 @@ impl DataFrame {
         right_on: &str,
         left_by: S,
         right_by: S,
+        options: &AsOfOptions,
     ) -> Result<DataFrame>
     where
         S: Selection<'a, J>,
@@ -124,6 +149,7 @@ impl DataFrame {
         let left_asof = self.column(left_on)?;
         let right_asof = other.column(right_on)?;
         let right_asof_name = right_asof.name();
+        let options = options.resolve_tolerance(left_asof.dtype())?;
 
         let left_by = self.select(left_by)?;
         let right_by = other.select(right_by)?;
@@ -134,13 +160,13 @@ impl DataFrame {
             let left_asof = left_asof.i64().unwrap();
             let right_asof = right_asof.i64().unwrap();
 
-            asof_join_by(&left_by, &right_by, left_asof, right_asof)
+            asof_join_by(&left_by, &right_by, left_asof, right_asof, &options)?
         } else {
             let left_asof = left_asof.cast(&DataType::Int32)?;
             let right_asof = right_asof.cast(&DataType::Int32)?;
             let left_asof = left_asof.i32().unwrap();
             let right_asof = right_asof.i32().unwrap();
-            asof_join_by(&left_by, &right_by, left_asof, right_asof)
+            asof_join_by(&left_by, &right_by, left_asof, right_asof, &options)?
         };
 
         let mut drop_these = right_by.get_column_names();
@@ -190,7 +216,7 @@ mod test {
             "right_vals" => [1, 2, 3, 4]
         ]?;
 
-        let out = a.join_asof_by(&b, "a", "a", "b", "b")?;
+        let out = a.join_asof_by(&b, "a", "a", "b", "b", &AsOfOptions::default())?;
         assert_eq!(out.get_column_names(), &["a", "b", "right_vals"]);
         let out = out.column("right_vals").unwrap();
         let out = out.i32().unwrap();
@@ -223,7 +249,14 @@ mod test {
 
                ]?;
 
-        let out = trades.join_asof_by(&quotes, "time", "time", "ticker", "ticker")?;
+        let out = trades.join_asof_by(
+            &quotes,
+            "time",
+            "time",
+            "ticker",
+            "ticker",
+            &AsOfOptions::default(),
+        )?;
         let a = out.column("bid_right").unwrap();
         let a = a.f64().unwrap();
 
@@ -232,6 +265,36 @@ mod test {
             &[Some(51.95), Some(51.97), Some(720.5), Some(720.5), None]
         );
 
+        let options = AsOfOptions {
+            strategy: AsofStrategy::Forward,
+            ..Default::default()
+        };
+        let out = trades.join_asof_by(&quotes, "time", "time", "ticker", "ticker", &options)?;
+        let a = out.column("bid_right").unwrap();
+        let a = a.f64().unwrap();
+        assert_eq!(
+            Vec::from(a),
+            &[
+                Some(51.95),
+                Some(51.99),
+                Some(720.5),
+                Some(720.5),
+                Some(97.99)
+            ]
+        );
+
+        let options = AsOfOptions {
+            tolerance: Some(AnyValue::Int64(2)),
+            ..Default::default()
+        };
+        let out = trades.join_asof_by(&quotes, "time", "time", "ticker", "ticker", &options)?;
+        let a = out.column("bid_right").unwrap();
+        let a = a.f64().unwrap();
+        assert_eq!(
+            Vec::from(a),
+            &[Some(51.95), None, Some(720.5), Some(720.5), None]
+        );
+
         Ok(())
     }
 }
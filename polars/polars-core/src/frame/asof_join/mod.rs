@@ -1,20 +1,285 @@
 mod groups;
 
 use crate::prelude::*;
-use crate::utils::CustomIterTools;
-use num::Bounded;
-use polars_arrow::trusted_len::PushUnchecked;
+use num::NumCast;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
+/// Direction in which an asof join searches for the matching key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum AsofStrategy {
+    /// selects the last row in the right DataFrame whose `on` key is less than or equal to the left's key
+    Backward,
+    /// selects the first row in the right DataFrame whose `on` key is greater than or equal to the left's key.
+    Forward,
+    /// selects the row in the right DataFrame whose `on` key is nearest to the left's key.
+    Nearest,
+}
+
+impl Default for AsofStrategy {
+    fn default() -> Self {
+        AsofStrategy::Backward
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct AsOfOptions {
+    pub strategy: AsofStrategy,
+    /// A maximum distance between the keys. Expressed in the physical
+    /// unit of the `on` key, e.g. milliseconds for `Datetime`.
+    #[cfg_attr(feature = "serde-lazy", serde(with = "crate::serde::scalar"))]
+    pub tolerance: Option<AnyValue<'static>>,
+    /// A maximum distance between the keys expressed as a duration string
+    /// like `"2m"` or `"1d12h"`. Only valid for `Date` and `Datetime` keys
+    /// and takes precedence over `tolerance`.
+    pub tolerance_str: Option<String>,
+    /// Allow matching with equal keys. If `false` only keys that are strictly
+    /// smaller (backward) or larger (forward) are matched.
+    pub allow_exact_matches: bool,
+}
+
+impl Default for AsOfOptions {
+    fn default() -> Self {
+        AsOfOptions {
+            strategy: AsofStrategy::Backward,
+            tolerance: None,
+            tolerance_str: None,
+            allow_exact_matches: true,
+        }
+    }
+}
+
+/// Check that `tolerance` is a duration string of fixed length units, like `"1h30m"`.
+/// Parsing an invalid string would panic.
+#[cfg(feature = "temporal")]
+fn validate_tolerance_str(tolerance: &str) -> Result<()> {
+    let err = || {
+        Err(PolarsError::ComputeError(
+            format!(
+                "invalid tolerance string '{}'; expected a positive duration like '1h30m' \
+                with the units ns, us, ms, s, m, h, d or w",
+                tolerance
+            )
+            .into(),
+        ))
+    };
+    if tolerance.is_empty() {
+        return err();
+    }
+    let mut rest = tolerance;
+    while !rest.is_empty() {
+        let n_digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or_else(|| rest.len());
+        let (digits, after_digits) = rest.split_at(n_digits);
+        let n_unit = after_digits
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or_else(|| after_digits.len());
+        let (unit, after_unit) = after_digits.split_at(n_unit);
+
+        if digits.parse::<i64>().is_err()
+            || !matches!(unit, "ns" | "us" | "ms" | "s" | "m" | "h" | "d" | "w")
+        {
+            return err();
+        }
+        rest = after_unit;
+    }
+    Ok(())
+}
+
+impl AsOfOptions {
+    /// Convert `tolerance_str` into a numeric `tolerance` in the physical unit of `dtype`.
+    pub(crate) fn resolve_tolerance(&self, dtype: &DataType) -> Result<Cow<AsOfOptions>> {
+        match &self.tolerance_str {
+            None => Ok(Cow::Borrowed(self)),
+            #[cfg(feature = "temporal")]
+            Some(tolerance) => {
+                validate_tolerance_str(tolerance)?;
+                let nanoseconds = polars_time::Duration::parse(tolerance).duration();
+                let tolerance = match dtype {
+                    DataType::Datetime => AnyValue::Int64(nanoseconds / 1_000_000),
+                    DataType::Date => AnyValue::Int32((nanoseconds / 86_400_000_000_000) as i32),
+                    dt => {
+                        return Err(PolarsError::ComputeError(
+                            format!(
+                            "a tolerance given as string is not supported for keys of dtype {:?}",
+                            dt
+                        )
+                            .into(),
+                        ))
+                    }
+                };
+                let mut options = self.clone();
+                options.tolerance = Some(tolerance);
+                options.tolerance_str = None;
+                Ok(Cow::Owned(options))
+            }
+            #[cfg(not(feature = "temporal"))]
+            Some(_) => Err(PolarsError::InvalidOperation(
+                "activate the 'temporal' feature to use a tolerance string".into(),
+            )),
+        }
+    }
+}
+
+pub(crate) fn tolerance_to_native<T: NumCast>(tolerance: &Option<AnyValue>) -> Result<Option<T>> {
+    let tolerance = match tolerance {
+        None | Some(AnyValue::Null) => return Ok(None),
+        Some(av) => av,
+    };
+    let out: Option<T> = match tolerance {
+        AnyValue::UInt8(v) => NumCast::from(*v),
+        AnyValue::UInt16(v) => NumCast::from(*v),
+        AnyValue::UInt32(v) => NumCast::from(*v),
+        AnyValue::UInt64(v) => NumCast::from(*v),
+        AnyValue::Int8(v) => NumCast::from(*v),
+        AnyValue::Int16(v) => NumCast::from(*v),
+        AnyValue::Int32(v) => NumCast::from(*v),
+        AnyValue::Int64(v) => NumCast::from(*v),
+        AnyValue::Float32(v) => NumCast::from(*v),
+        AnyValue::Float64(v) => NumCast::from(*v),
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(v) => NumCast::from(*v),
+        #[cfg(feature = "dtype-datetime")]
+        AnyValue::Datetime(v) => NumCast::from(*v),
+        _ => None,
+    };
+    match out {
+        Some(v) => Ok(Some(v)),
+        None => Err(PolarsError::ComputeError(
+            format!(
+                "could not use {:?} as tolerance of the asof join",
+                tolerance
+            )
+            .into(),
+        )),
+    }
+}
+
+/// Select the matching right index given the number of right keys that are smaller (`lt`)
+/// and the number of right keys that are smaller or equal (`le`) than the left key.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn asof_select<T, I>(
+    left_val: T,
+    right_at: I,
+    n_right: usize,
+    lt: usize,
+    le: usize,
+    strategy: AsofStrategy,
+    tolerance: Option<T>,
+    allow_exact_matches: bool,
+) -> Option<usize>
+where
+    T: NumericNative,
+    I: Fn(usize) -> T,
+{
+    let backward = || {
+        let n_smaller = if allow_exact_matches { le } else { lt };
+        if n_smaller == 0 {
+            None
+        } else {
+            let idx = n_smaller - 1;
+            Some((idx, left_val - right_at(idx)))
+        }
+    };
+    let forward = || {
+        let idx = if allow_exact_matches { lt } else { le };
+        if idx == n_right {
+            None
+        } else {
+            Some((idx, right_at(idx) - left_val))
+        }
+    };
+
+    let candidate = match strategy {
+        AsofStrategy::Backward => backward(),
+        AsofStrategy::Forward => forward(),
+        AsofStrategy::Nearest => match (backward(), forward()) {
+            (Some(b), Some(f)) => {
+                if f.1 < b.1 {
+                    Some(f)
+                } else {
+                    Some(b)
+                }
+            }
+            (b, None) => b,
+            (None, f) => f,
+        },
+    };
+    match (candidate, tolerance) {
+        (Some((idx, distance)), Some(tolerance)) => {
+            if distance <= tolerance {
+                Some(idx)
+            } else {
+                None
+            }
+        }
+        (candidate, None) => candidate.map(|(idx, _)| idx),
+        (None, _) => None,
+    }
+}
+
+fn join_asof_slices<T: NumericNative>(
+    left: &[T],
+    right: &[T],
+    options: &AsOfOptions,
+    tolerance: Option<T>,
+) -> Result<Vec<Option<u32>>> {
+    let mut out = Vec::with_capacity(left.len());
+    // number of right values smaller than the current left value
+    let mut lt = 0;
+    // number of right values smaller than or equal to the current left value
+    let mut le = 0;
+    let mut previous_left = None;
+
+    for &left_val in left {
+        if let Some(previous) = previous_left {
+            if left_val < previous {
+                return Err(PolarsError::ComputeError(
+                    "left key of asof join must be sorted".into(),
+                ));
+            }
+        }
+        previous_left = Some(left_val);
+
+        while lt < right.len() && right[lt] < left_val {
+            lt += 1;
+        }
+        le = std::cmp::max(le, lt);
+        while le < right.len() && right[le] <= left_val {
+            le += 1;
+        }
+        out.push(
+            asof_select(
+                left_val,
+                |idx| right[idx],
+                right.len(),
+                lt,
+                le,
+                options.strategy,
+                tolerance,
+                options.allow_exact_matches,
+            )
+            .map(|idx| idx as u32),
+        );
+    }
+    Ok(out)
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
-    T::Native: Bounded + PartialOrd,
 {
-    pub(crate) fn join_asof(&self, other: &Series) -> Result<Vec<Option<u32>>> {
+    pub(crate) fn join_asof(
+        &self,
+        other: &Series,
+        options: &AsOfOptions,
+    ) -> Result<Vec<Option<u32>>> {
         let other = self.unpack_series_matching_type(other)?;
-        let mut rhs_iter = other.into_iter().peekable();
-        let mut tuples = Vec::with_capacity(self.len());
         if self.null_count() > 0 {
             return Err(PolarsError::ComputeError(
                 "keys of asof join should not have null values".into(),
@@ -23,88 +288,54 @@ where
         if !(other.is_sorted_reverse() | other.is_sorted()) {
             eprintln!("right key of asof join is not explicitly sorted, this may lead to unexpected results");
         }
+        let tolerance = tolerance_to_native::<T::Native>(&options.tolerance)?;
 
-        let mut rhs_idx = 0;
-
-        let mut previous_lhs_val: T::Native = Bounded::min_value();
-
-        let mut sorted = true;
-
-        for arr in self.downcast_iter() {
-            for &lhs_val in arr.values().as_slice() {
-                if lhs_val < previous_lhs_val {
-                    sorted = false;
-                }
-                if lhs_val == previous_lhs_val {
-                    tuples.push(Some(rhs_idx - 1));
-                    continue;
-                }
-                previous_lhs_val = lhs_val;
-
-                loop {
-                    match rhs_iter.next() {
-                        Some(Some(rhs_val)) => {
-                            if rhs_val > lhs_val {
-                                if previous_lhs_val <= lhs_val {
-                                    if rhs_idx == 0 {
-                                        tuples.push(None);
-                                    } else {
-                                        tuples.push(Some(rhs_idx - 1));
-                                    }
-                                }
-
-                                rhs_idx += 1;
-
-                                // breaks from the loop, not the right iter
-                                break;
-                            }
-                            rhs_idx += 1;
-                        }
-                        Some(None) => {
-                            rhs_idx += 1;
-                        }
-                        // exhausted rhs
-                        None => {
-                            let remaining = self.len() - tuples.len();
-
-                            // all remaining values in the rhs are smaller
-                            // so we join with the last: the biggest
-                            let item = tuples.last().map(|_| rhs_idx - 1);
-
-                            let iter = std::iter::repeat(item)
-                                .take(remaining)
-                                .trust_my_length(remaining);
-                            tuples.extend_trusted_len(iter);
-
-                            return Ok(tuples);
-                        }
-                    }
-                }
-            }
-        }
+        let left = self.rechunk();
+        let left = left.cont_slice().unwrap();
 
-        if !sorted {
-            return Err(PolarsError::ComputeError(
-                "left key of asof join must be sorted".into(),
-            ));
+        if other.null_count() == 0 {
+            let right = other.rechunk();
+            let right = right.cont_slice().unwrap();
+            join_asof_slices(left, right, options, tolerance)
+        } else {
+            // null keys in the right table never match, so we search the valid values
+            // and map back to the original row indexes.
+            let (right_idx, right): (Vec<u32>, Vec<T::Native>) = other
+                .into_iter()
+                .enumerate()
+                .filter_map(|(idx, opt_v)| opt_v.map(|v| (idx as u32, v)))
+                .unzip();
+            let out = join_asof_slices(left, &right, options, tolerance)?;
+            Ok(out
+                .into_iter()
+                .map(|opt_idx| opt_idx.map(|idx| right_idx[idx as usize]))
+                .collect())
         }
-
-        Ok(tuples)
     }
 }
 
 impl DataFrame {
     /// This is similar to a left-join except that we match on nearest key rather than equal keys.
-    /// The keys must be sorted to perform an asof join
+    /// The keys must be sorted to perform an asof join.
+    ///
+    /// The search direction, a maximum distance between the keys and whether exact matches
+    /// are allowed are set with [`AsOfOptions`].
     #[cfg_attr(docsrs, doc(cfg(feature = "asof_join")))]
-    pub fn join_asof(&self, other: &DataFrame, left_on: &str, right_on: &str) -> Result<DataFrame> {
+    pub fn join_asof(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        options: &AsOfOptions,
+    ) -> Result<DataFrame> {
         let left_key = self.column(left_on)?;
         let right_key = other.column(right_on)?;
+        let options = options.resolve_tolerance(left_key.dtype())?;
 
-        let take_idx = left_key.join_asof(right_key)?;
+        let take_idx = left_key.join_asof(right_key, &options)?;
 
-        // take_idx are sorted so this is a bound check for all
-        if let Some(Some(idx)) = take_idx.last() {
+        // bound check for all
+        if let Some(idx) = take_idx.iter().flatten().max() {
             assert!((*idx as usize) < other.height())
         }
 
@@ -146,7 +377,7 @@ mod test {
             "right_val" => [1, 2, 3, 6, 7]
         ]?;
 
-        let out = left.join_asof(&right, "a", "b")?;
+        let out = left.join_asof(&right, "a", "b", &AsOfOptions::default())?;
         let expected = df![
             "a" => [1, 5, 10],
             "left_val" => ["a", "b", "c"],
@@ -164,7 +395,7 @@ mod test {
             "b" => [1, 2, 3],
             "right_val" => [1, 2, 3]
         ]?;
-        let out = left.join_asof(&right, "a", "b")?;
+        let out = left.join_asof(&right, "a", "b", &AsOfOptions::default())?;
         let expected = df![
             "a" => [2, 5, 10, 12],
             "left_val" => ["a", "b", "c", "d"],
@@ -182,7 +413,7 @@ mod test {
             "b" => [1, 2, 3, 6, 7]
         ]?;
 
-        let out = left.join_asof(&right, "a", "b")?;
+        let out = left.join_asof(&right, "a", "b", &AsOfOptions::default())?;
         let expected = df![
             "a" => [-10, 5, 10],
             "left_val" => ["a", "b", "c"],
@@ -191,4 +422,71 @@ mod test {
         assert!(out.frame_equal_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_join_asof_strategies() -> Result<()> {
+        let left = df![
+            "a" => [1, 5, 10],
+        ]?;
+        let right = df![
+            "b" => [1, 2, 3, 6, 7],
+            "right_val" => [1, 2, 3, 6, 7]
+        ]?;
+
+        let get_right_vals = |options: AsOfOptions| -> Result<Vec<Option<i32>>> {
+            let out = left.join_asof(&right, "a", "b", &options)?;
+            Ok(Vec::from(out.column("right_val")?.i32()?))
+        };
+
+        let options = AsOfOptions {
+            strategy: AsofStrategy::Forward,
+            ..Default::default()
+        };
+        assert_eq!(get_right_vals(options)?, &[Some(1), Some(6), None]);
+
+        let options = AsOfOptions {
+            strategy: AsofStrategy::Nearest,
+            ..Default::default()
+        };
+        assert_eq!(get_right_vals(options)?, &[Some(1), Some(6), Some(7)]);
+
+        let options = AsOfOptions {
+            allow_exact_matches: false,
+            ..Default::default()
+        };
+        assert_eq!(get_right_vals(options)?, &[None, Some(3), Some(7)]);
+
+        let options = AsOfOptions {
+            tolerance: Some(AnyValue::Int32(1)),
+            ..Default::default()
+        };
+        assert_eq!(get_right_vals(options)?, &[Some(1), None, None]);
+
+        let options = AsOfOptions {
+            strategy: AsofStrategy::Nearest,
+            tolerance: Some(AnyValue::Int32(1)),
+            ..Default::default()
+        };
+        assert_eq!(get_right_vals(options)?, &[Some(1), Some(6), None]);
+        Ok(())
+    }
+    #[test]
+    #[cfg(feature = "temporal")]
+    fn test_resolve_tolerance_str() -> Result<()> {
+        let options = |tolerance: &str| AsOfOptions {
+            tolerance_str: Some(tolerance.to_string()),
+            ..Default::default()
+        };
+        let resolved = options("1h30m").resolve_tolerance(&DataType::Datetime)?;
+        assert_eq!(resolved.tolerance, Some(AnyValue::Int64(5_400_000)));
+        let resolved = options("2d").resolve_tolerance(&DataType::Date)?;
+        assert_eq!(resolved.tolerance, Some(AnyValue::Int32(2)));
+
+        for invalid in &["", "1", "h", "1x", "1h2", "-1d", "1mo", "1.5h"] {
+            assert!(options(invalid)
+                .resolve_tolerance(&DataType::Datetime)
+                .is_err());
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,189 @@
+use crate::prelude::*;
+use crate::POOL;
+use rayon::prelude::*;
+use std::cmp::Ordering;
+
+/// The correlation coefficient computed by [DataFrame::corr].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CorrelationMethod {
+    /// Linear correlation of the values.
+    Pearson,
+    /// Pearson correlation of the ranks of the values. Ties get their average rank.
+    Spearman,
+}
+
+impl Default for CorrelationMethod {
+    fn default() -> Self {
+        CorrelationMethod::Pearson
+    }
+}
+
+fn is_numeric(dtype: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        dtype,
+        UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64
+    )
+}
+
+/// The rows where both values are valid.
+fn pairwise_complete(x: &Float64Chunked, y: &Float64Chunked) -> (Vec<f64>, Vec<f64>) {
+    x.into_iter()
+        .zip(y.into_iter())
+        .filter_map(|(x, y)| match (x, y) {
+            (Some(x), Some(y)) => Some((x, y)),
+            _ => None,
+        })
+        .unzip()
+}
+
+/// Sample covariance and sample variances of `x` and `y`.
+fn cov_var(x: &[f64], y: &[f64]) -> Option<(f64, f64, f64)> {
+    let n = x.len();
+    if n < 2 {
+        return None;
+    }
+    let mean_x = x.iter().sum::<f64>() / n as f64;
+    let mean_y = y.iter().sum::<f64>() / n as f64;
+
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in x.iter().zip(y) {
+        let dx = x - mean_x;
+        let dy = y - mean_y;
+        cov += dx * dy;
+        var_x += dx * dx;
+        var_y += dy * dy;
+    }
+    let ddof = (n - 1) as f64;
+    Some((cov / ddof, var_x / ddof, var_y / ddof))
+}
+
+fn pearson(x: &[f64], y: &[f64]) -> Option<f64> {
+    let (cov, var_x, var_y) = cov_var(x, y)?;
+    if var_x > 0.0 && var_y > 0.0 {
+        Some(cov / (var_x * var_y).sqrt())
+    } else {
+        None
+    }
+}
+
+/// 1-based ranks where ties get the mean of the ranks they span.
+fn average_ranks(values: &[f64]) -> Vec<f64> {
+    let mut idx = (0..values.len()).collect::<Vec<_>>();
+    idx.sort_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap_or(Ordering::Equal));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < idx.len() {
+        let mut end = start + 1;
+        while end < idx.len() && values[idx[end]] == values[idx[start]] {
+            end += 1;
+        }
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &i in &idx[start..end] {
+            ranks[i] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+impl DataFrame {
+    /// Apply `f` to every pair of numeric columns, using only the rows where both are valid.
+    fn pairwise_matrix<F>(&self, f: F) -> Result<DataFrame>
+    where
+        F: Fn(&[f64], &[f64]) -> Option<f64> + Sync,
+    {
+        let columns = self
+            .columns
+            .iter()
+            .filter(|s| is_numeric(s.dtype()))
+            .map(|s| s.cast(&DataType::Float64))
+            .collect::<Result<Vec<_>>>()?;
+        let n = columns.len();
+
+        // the matrix is symmetric, so we only compute the upper triangle
+        let pairs = (0..n)
+            .flat_map(|i| (i..n).map(move |j| (i, j)))
+            .collect::<Vec<_>>();
+        let values = POOL.install(|| {
+            pairs
+                .par_iter()
+                .map(|&(i, j)| {
+                    let (x, y) = pairwise_complete(columns[i].f64()?, columns[j].f64()?);
+                    Ok(f(&x, &y))
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        let mut matrix = vec![vec![None; n]; n];
+        for (&(i, j), v) in pairs.iter().zip(values) {
+            matrix[i][j] = v;
+            matrix[j][i] = v;
+        }
+        let columns = columns
+            .iter()
+            .zip(matrix)
+            .map(|(s, values)| Float64Chunked::new_from_opt_slice(s.name(), &values).into_series())
+            .collect();
+        DataFrame::new(columns)
+    }
+
+    /// Compute the sample covariance matrix of the numeric columns.
+    ///
+    /// Returns an N×N `DataFrame` of `Float64` where the i-th row and the i-th column belong
+    /// to the i-th numeric column. Every pair of columns is computed on the rows where both
+    /// values are valid. The pairs are computed in parallel.
+    pub fn cov(&self) -> Result<DataFrame> {
+        self.pairwise_matrix(|x, y| cov_var(x, y).map(|(cov, _, _)| cov))
+    }
+
+    /// Compute the correlation matrix of the numeric columns.
+    ///
+    /// Returns an N×N `DataFrame` of `Float64` where the i-th row and the i-th column belong
+    /// to the i-th numeric column. Every pair of columns is computed on the rows where both
+    /// values are valid. The pairs are computed in parallel. The correlation with a constant
+    /// column is null.
+    pub fn corr(&self, method: CorrelationMethod) -> Result<DataFrame> {
+        match method {
+            CorrelationMethod::Pearson => self.pairwise_matrix(pearson),
+            CorrelationMethod::Spearman => {
+                self.pairwise_matrix(|x, y| pearson(&average_ranks(x), &average_ranks(y)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_cov_corr_matrix() -> Result<()> {
+        let df = df![
+            "a" => [Some(1.0), Some(2.0), Some(3.0), None],
+            "b" => [2i32, 4, 6, 100],
+            "c" => ["x", "y", "z", "w"],
+            "d" => [3i64, 2, 1, 0]
+        ]?;
+
+        let cov = df.cov()?;
+        assert_eq!(cov.get_column_names(), &["a", "b", "d"]);
+        // the null in "a" drops the last row for the pairs with "a"
+        assert_eq!(
+            Vec::from(cov.column("a")?.f64()?),
+            &[Some(1.0), Some(2.0), Some(-1.0)]
+        );
+
+        let corr = df.corr(CorrelationMethod::Pearson)?;
+        assert_eq!(
+            Vec::from(corr.column("a")?.f64()?),
+            &[Some(1.0), Some(1.0), Some(-1.0)]
+        );
+
+        // the ranks of "b" and "d" are perfectly anti-correlated
+        let corr = df.corr(CorrelationMethod::Spearman)?;
+        assert_eq!(corr.column("b")?.f64()?.get(2), Some(-1.0));
+        Ok(())
+    }
+}
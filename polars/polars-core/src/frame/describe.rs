@@ -0,0 +1,273 @@
+use crate::prelude::*;
+use crate::POOL;
+use rayon::prelude::*;
+
+const DEFAULT_PERCENTILES: [f64; 3] = [0.25, 0.5, 0.75];
+
+enum Kind {
+    Numeric,
+    Temporal,
+    Textual,
+}
+
+fn column_kind(dtype: &DataType) -> Option<Kind> {
+    use DataType::*;
+    match dtype {
+        UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 => {
+            Some(Kind::Numeric)
+        }
+        Date | Datetime | Time => Some(Kind::Temporal),
+        Utf8 | Categorical | Boolean => Some(Kind::Textual),
+        _ => None,
+    }
+}
+
+fn fmt_temporal(dtype: &DataType, v: i64) -> String {
+    match dtype {
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => AnyValue::Date(v as i32).to_string(),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime => AnyValue::Datetime(v).to_string(),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => AnyValue::Time(v).to_string(),
+        _ => v.to_string(),
+    }
+}
+
+fn fmt_percentile(percentile: f64) -> String {
+    format!("{}%", (percentile * 1000.0).round() / 10.0)
+}
+
+/// The number of unique values and the most frequent value, nulls excluded.
+fn unique_and_top(s: &Series) -> Result<(usize, Option<String>)> {
+    let s = match s.dtype() {
+        DataType::Categorical => s.cast(&DataType::Utf8)?,
+        _ => s.clone(),
+    };
+    let s = s.drop_nulls();
+    let unique = s.n_unique()?;
+    let counts = s.value_counts(true, false)?;
+    let values = &counts.get_columns()[0];
+    let top = if values.is_empty() {
+        None
+    } else {
+        match values.get(0) {
+            AnyValue::Utf8(v) => Some(v.to_string()),
+            av => Some(av.to_string()),
+        }
+    };
+    Ok((unique, top))
+}
+
+struct Rows<'a> {
+    percentiles: &'a [f64],
+    numeric: bool,
+    textual: bool,
+}
+
+impl Rows<'_> {
+    fn names(&self) -> Vec<String> {
+        let mut names = vec!["count".to_string(), "null_count".to_string()];
+        if self.numeric {
+            names.extend_from_slice(&["mean".to_string(), "std".to_string(), "min".to_string()]);
+            names.extend(self.percentiles.iter().map(|&p| fmt_percentile(p)));
+            names.push("max".to_string());
+        }
+        if self.textual {
+            names.extend_from_slice(&["unique".to_string(), "top".to_string()]);
+        }
+        names
+    }
+
+    fn numeric_len(&self) -> usize {
+        if self.numeric {
+            self.percentiles.len() + 4
+        } else {
+            0
+        }
+    }
+
+    fn describe_numeric(&self, s: &Series) -> Result<Series> {
+        let s_f64 = s.cast(&DataType::Float64)?;
+        let ca = s_f64.f64()?;
+        let mut values = vec![
+            Some((s.len() - s.null_count()) as f64),
+            Some(s.null_count() as f64),
+        ];
+        if self.numeric {
+            values.extend_from_slice(&[ca.mean(), ca.std(), ca.min()]);
+            values.extend(ca.quantiles(self.percentiles, QuantileInterpolOptions::Linear)?);
+            values.push(ca.max());
+        }
+        if self.textual {
+            values.extend_from_slice(&[None, None]);
+        }
+        Ok(Float64Chunked::new_from_opt_slice(s.name(), &values).into_series())
+    }
+
+    fn describe_temporal(&self, s: &Series) -> Result<Series> {
+        // compute on the physical representation and format the results in the logical type
+        let phys = s.to_physical_repr().cast(&DataType::Int64)?;
+        let ca = phys.i64()?;
+        let mut values = vec![
+            Some((s.len() - s.null_count()).to_string()),
+            Some(s.null_count().to_string()),
+        ];
+        if self.numeric {
+            let fmt = |opt_v: Option<i64>| opt_v.map(|v| fmt_temporal(s.dtype(), v));
+            values.push(fmt(ca.mean().map(|v| v.round() as i64)));
+            values.push(None);
+            values.push(fmt(ca.min()));
+            // interpolate between the temporal values
+            let phys_f64 = phys.cast(&DataType::Float64)?;
+            let percentiles = phys_f64
+                .f64()?
+                .quantiles(self.percentiles, QuantileInterpolOptions::Linear)?;
+            for v in percentiles {
+                values.push(fmt(v.map(|v| v.round() as i64)));
+            }
+            values.push(fmt(ca.max()));
+        }
+        if self.textual {
+            values.extend_from_slice(&[None, None]);
+        }
+        Ok(Utf8Chunked::new_from_opt_slice(s.name(), &values).into_series())
+    }
+
+    fn describe_textual(&self, s: &Series) -> Result<Series> {
+        let mut values = vec![
+            Some((s.len() - s.null_count()).to_string()),
+            Some(s.null_count().to_string()),
+        ];
+        values.extend(std::iter::repeat(None).take(self.numeric_len()));
+        let (unique, top) = unique_and_top(s)?;
+        values.push(Some(unique.to_string()));
+        values.push(top);
+        Ok(Utf8Chunked::new_from_opt_slice(s.name(), &values).into_series())
+    }
+}
+
+impl DataFrame {
+    /// Summary statistics of the columns.
+    ///
+    /// Numeric and temporal columns get the count, null count, mean, standard deviation,
+    /// minimum, the given `percentiles` (default 25%, 50% and 75%) and maximum. String,
+    /// categorical and boolean columns get the count, null count, number of unique values
+    /// and the most frequent value. Other columns are skipped.
+    ///
+    /// The names of the statistics are in the first column, named `"describe"`. Numeric
+    /// columns are summarized as `Float64`, all other columns as `Utf8`. The columns are
+    /// summarized in parallel.
+    pub fn describe(&self, percentiles: Option<&[f64]>) -> Result<DataFrame> {
+        let percentiles = percentiles.unwrap_or(&DEFAULT_PERCENTILES);
+        if let Some(p) = percentiles.iter().find(|p| !(0.0..=1.0).contains(*p)) {
+            return Err(PolarsError::ValueError(
+                format!("percentiles should be between 0.0 and 1.0, got {}", p).into(),
+            ));
+        }
+
+        let kinds = self
+            .columns
+            .iter()
+            .map(|s| column_kind(s.dtype()))
+            .collect::<Vec<_>>();
+        let rows = Rows {
+            percentiles,
+            numeric: kinds
+                .iter()
+                .any(|k| matches!(k, Some(Kind::Numeric) | Some(Kind::Temporal))),
+            textual: kinds.iter().any(|k| matches!(k, Some(Kind::Textual))),
+        };
+
+        let summaries = POOL.install(|| {
+            self.columns
+                .par_iter()
+                .zip(kinds.par_iter())
+                .filter_map(|(s, kind)| {
+                    kind.as_ref().map(|kind| match kind {
+                        Kind::Numeric => rows.describe_numeric(s),
+                        Kind::Temporal => rows.describe_temporal(s),
+                        Kind::Textual => rows.describe_textual(s),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        let mut columns = Vec::with_capacity(summaries.len() + 1);
+        columns.push(Utf8Chunked::new_from_slice("describe", &rows.names()).into_series());
+        columns.extend(summaries);
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_describe() -> Result<()> {
+        let df = df![
+            "a" => [Some(1i32), Some(2), None, Some(3)],
+            "b" => ["x", "y", "y", "z"]
+        ]?;
+        let out = df.describe(None)?;
+        assert_eq!(
+            Vec::from(out.column("describe")?.utf8()?),
+            &[
+                Some("count"),
+                Some("null_count"),
+                Some("mean"),
+                Some("std"),
+                Some("min"),
+                Some("25%"),
+                Some("50%"),
+                Some("75%"),
+                Some("max"),
+                Some("unique"),
+                Some("top")
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("a")?.f64()?),
+            &[
+                Some(3.0),
+                Some(1.0),
+                Some(2.0),
+                Some(1.0),
+                Some(1.0),
+                Some(1.5),
+                Some(2.0),
+                Some(2.5),
+                Some(3.0),
+                None,
+                None
+            ]
+        );
+        let b = out.column("b")?.utf8()?;
+        assert_eq!(b.get(0), Some("4"));
+        assert_eq!(b.get(2), None);
+        assert_eq!(b.get(9), Some("3"));
+        assert_eq!(b.get(10), Some("y"));
+
+        assert!(df.describe(Some(&[1.5])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_describe_temporal() -> Result<()> {
+        let dates = Int32Chunked::new_from_slice("d", &[0, 1, 3])
+            .into_date()
+            .into_series();
+        let df = DataFrame::new(vec![dates])?;
+        let out = df.describe(Some(&[0.5, 0.75]))?;
+        let d = out.column("d")?.utf8()?;
+        let fmt = |days| super::fmt_temporal(&DataType::Date, days);
+        // min, percentiles interpolated between the days and max
+        assert_eq!(d.get(4), Some(fmt(0).as_str()));
+        assert_eq!(d.get(5), Some(fmt(1).as_str()));
+        assert_eq!(d.get(6), Some(fmt(2).as_str()));
+        assert_eq!(d.get(7), Some(fmt(3).as_str()));
+        Ok(())
+    }
+}
@@ -2,8 +2,55 @@ use crate::chunked_array::ops::explode::offsets_to_indexes;
 use crate::frame::select::Selection;
 use crate::prelude::*;
 use arrow::buffer::Buffer;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
+/// Arguments for [`DataFrame::melt_with_args`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct MeltArgs {
+    /// Columns that are kept as identifier columns.
+    pub id_vars: Vec<String>,
+    /// Columns that are melted.
+    pub value_vars: Vec<String>,
+    /// Also melt all columns of these data types that are not an identifier column.
+    /// If both `value_vars` and `value_dtypes` are empty, all columns that are not an
+    /// identifier column are melted.
+    pub value_dtypes: Vec<DataType>,
+    /// Name of the column with the names of the melted columns. Defaults to `"variable"`.
+    pub variable_name: Option<String>,
+    /// Name of the column with the values of the melted columns. Defaults to `"value"`.
+    pub value_name: Option<String>,
+}
+
+impl MeltArgs {
+    pub fn variable_name(&self) -> &str {
+        self.variable_name.as_deref().unwrap_or("variable")
+    }
+
+    pub fn value_name(&self) -> &str {
+        self.value_name.as_deref().unwrap_or("value")
+    }
+
+    /// Determine the melted columns with the `schema` of the input. The `value_dtypes` are
+    /// replaced by the names of the columns of these types.
+    pub fn resolve(mut self, schema: &Schema) -> Self {
+        let select_all = self.value_vars.is_empty() && self.value_dtypes.is_empty();
+        for field in schema.fields() {
+            let name = field.name();
+            if self.id_vars.contains(name) || self.value_vars.contains(name) {
+                continue;
+            }
+            if select_all || self.value_dtypes.contains(field.data_type()) {
+                self.value_vars.push(name.clone())
+            }
+        }
+        self.value_dtypes.clear();
+        self
+    }
+}
+
 fn get_exploded(series: &Series) -> Result<(Series, Buffer<i64>)> {
     match series.dtype() {
         DataType::List(_) => series.list().unwrap().explode_and_offsets(),
@@ -179,16 +226,51 @@ impl DataFrame {
         id_vars: SelId,
         value_vars: SelValue,
     ) -> Result<Self> {
-        let ids = self.select(id_vars)?;
-        let value_vars = value_vars.to_selection_vec();
+        let to_strings =
+            |names: Vec<&str>| names.into_iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        self.melt_with_args(MeltArgs {
+            id_vars: to_strings(id_vars.to_selection_vec()),
+            value_vars: to_strings(value_vars.to_selection_vec()),
+            ..Default::default()
+        })
+    }
+
+    /// Unpivot a `DataFrame` from wide to long format with custom names for the
+    /// `variable` and `value` columns and value columns selected by data type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("A" => &["a", "b", "a"],
+    ///              "B" => &[1, 3, 5],
+    ///              "C" => &[10, 11, 12],
+    ///              "D" => &[2, 4, 6]
+    ///     )?;
+    ///
+    /// let melted = df.melt_with_args(MeltArgs {
+    ///     id_vars: vec!["A".into()],
+    ///     value_dtypes: vec![DataType::Int32],
+    ///     variable_name: Some("column".into()),
+    ///     value_name: Some("number".into()),
+    ///     ..Default::default()
+    /// })?;
+    /// assert_eq!(melted.get_column_names(), &["A", "column", "number"]);
+    /// assert_eq!(melted.height(), 9);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn melt_with_args(&self, args: MeltArgs) -> Result<Self> {
+        let args = args.resolve(&self.schema());
+        let ids = self.select(&args.id_vars)?;
         let len = self.height();
 
-        let mut dataframe_chunks = VecDeque::with_capacity(value_vars.len());
+        let mut dataframe_chunks = VecDeque::with_capacity(args.value_vars.len());
 
-        for value_column_name in value_vars {
-            let variable_col = Utf8Chunked::full("variable", value_column_name, len).into_series();
+        for value_column_name in &args.value_vars {
+            let variable_col =
+                Utf8Chunked::full(args.variable_name(), value_column_name, len).into_series();
             let mut value_col = self.column(value_column_name)?.clone();
-            value_col.rename("value");
+            value_col.rename(args.value_name());
 
             let mut df_chunk = ids.clone();
             df_chunk.hstack_mut(&[variable_col, value_col])?;
@@ -275,6 +357,40 @@ mod test {
         assert_eq!(
             Vec::from(melted.column("value").unwrap().i32().unwrap()),
             &[Some(10), Some(11), Some(12), Some(2), Some(4), Some(6)]
-        )
+        );
+
+        let melted = df
+            .melt_with_args(MeltArgs {
+                id_vars: vec!["A".into()],
+                value_dtypes: vec![DataType::Int32],
+                variable_name: Some("column".into()),
+                value_name: Some("number".into()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(melted.get_column_names(), &["A", "column", "number"]);
+        assert_eq!(
+            Vec::from(melted.column("column").unwrap().utf8().unwrap()),
+            &[
+                Some("B"),
+                Some("B"),
+                Some("B"),
+                Some("C"),
+                Some("C"),
+                Some("C"),
+                Some("D"),
+                Some("D"),
+                Some("D")
+            ]
+        );
+
+        // melt all columns that are not an id column
+        let melted = df
+            .melt_with_args(MeltArgs {
+                id_vars: vec!["A".into(), "B".into()],
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(melted.shape(), (6, 4));
     }
 }
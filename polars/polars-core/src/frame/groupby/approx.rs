@@ -0,0 +1,276 @@
+//! Approximate aggregations that are computed with mergeable sketches.
+//!
+//! Next to the aggregations themselves, the partial states of the sketches can be computed per
+//! group and merged later. This allows partitioned groupby's and aggregations on scans, which
+//! only keep small states for every partition of the data.
+use crate::frame::groupby::GroupTuples;
+use crate::prelude::*;
+use crate::utils::NoNull;
+use ahash::RandomState;
+use polars_arrow::kernels::sketch::{Centroid, HyperLogLog, TDigest};
+use rayon::prelude::*;
+
+/// Fixed seeds, so that the hashes, and thus the sketches, of different partitions can be merged.
+fn sketch_random_state() -> RandomState {
+    RandomState::with_seeds(
+        0x243f_6a88_85a3_08d3,
+        0x1319_8a2e_0370_7344,
+        0xa409_3822_299f_31d0,
+        0x082e_fa98_ec4e_6c89,
+    )
+}
+
+fn sketch_hashes(s: &Series) -> Result<Vec<u64>> {
+    match s.dtype() {
+        DataType::List(_) => Err(PolarsError::InvalidOperation(
+            "approx_n_unique is not supported for the list dtype".into(),
+        )),
+        #[cfg(feature = "object")]
+        DataType::Object(_) => Err(PolarsError::InvalidOperation(
+            "approx_n_unique is not supported for the object dtype".into(),
+        )),
+        _ => Ok(s.vec_hash(sketch_random_state())),
+    }
+}
+
+fn digest_values(s: &Series) -> Result<Float64Chunked> {
+    use DataType::*;
+    let s = s.to_physical_repr();
+    match s.dtype() {
+        UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 => {
+            let s = s.cast(&Float64)?;
+            Ok(s.f64().unwrap().rechunk())
+        }
+        dt => Err(PolarsError::InvalidOperation(
+            format!("approx_quantile is not supported for dtype {:?}", dt).into(),
+        )),
+    }
+}
+
+fn check_quantile(quantile: f64) -> Result<()> {
+    if !(0.0..=1.0).contains(&quantile) {
+        return Err(PolarsError::ValueError(
+            "quantile should be within 0.0 and 1.0".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn group_hll(hashes: &[u64], idx: &[u32]) -> HyperLogLog {
+    let mut hll = HyperLogLog::new();
+    for i in idx {
+        hll.add(hashes[*i as usize])
+    }
+    hll
+}
+
+fn group_digest(ca: &Float64Chunked, idx: &[u32]) -> TDigest {
+    let taker = ca.take_rand();
+    TDigest::from_values(idx.iter().filter_map(|i| taker.get(*i as usize)))
+}
+
+fn hll_from_state(state: &Series) -> Result<HyperLogLog> {
+    let bytes: Vec<u8> = state.u8()?.into_no_null_iter().collect();
+    HyperLogLog::from_bytes(&bytes)
+        .ok_or_else(|| PolarsError::ComputeError("invalid state for approx_n_unique".into()))
+}
+
+/// The state of a digest is a `Float64` series of `[min, max, mean_0, weight_0, mean_1, ..]`.
+fn digest_to_state(digest: &TDigest) -> Series {
+    let mut values = Vec::with_capacity(2 + 2 * digest.centroids().len());
+    values.push(digest.min());
+    values.push(digest.max());
+    for c in digest.centroids() {
+        values.push(c.mean);
+        values.push(c.weight);
+    }
+    Float64Chunked::new_from_slice("", &values).into_series()
+}
+
+fn digest_from_state(state: &Series) -> Result<TDigest> {
+    let values: Vec<f64> = state.f64()?.into_no_null_iter().collect();
+    if values.len() < 2 || values.len() % 2 != 0 {
+        return Err(PolarsError::ComputeError(
+            "invalid state for approx_quantile".into(),
+        ));
+    }
+    let centroids = values[2..]
+        .chunks_exact(2)
+        .map(|c| Centroid {
+            mean: c[0],
+            weight: c[1],
+        })
+        .collect();
+    Ok(TDigest::from_parts(centroids, values[0], values[1]))
+}
+
+/// A single group with all rows, to merge all states of a column.
+fn all_rows(len: usize) -> GroupTuples {
+    vec![(0, (0..len as u32).collect())]
+}
+
+/// Approximate number of unique values per group, null counts as a value.
+pub fn agg_approx_n_unique(s: &Series, groups: &[(u32, Vec<u32>)]) -> Result<UInt32Chunked> {
+    let hashes = sketch_hashes(s)?;
+    let ca: NoNull<UInt32Chunked> = groups
+        .par_iter()
+        .map(|(_, idx)| group_hll(&hashes, idx).estimate() as u32)
+        .collect();
+    Ok(ca.into_inner())
+}
+
+/// Partial state of [agg_approx_n_unique] per group, as a `List(UInt8)` column of the
+/// serialized sketches.
+pub fn agg_approx_n_unique_state(s: &Series, groups: &[(u32, Vec<u32>)]) -> Result<ListChunked> {
+    let hashes = sketch_hashes(s)?;
+    Ok(groups
+        .iter()
+        .map(|(_, idx)| {
+            UInt8Chunked::new_from_slice("", &group_hll(&hashes, idx).to_bytes()).into_series()
+        })
+        .collect())
+}
+
+/// Merge the partial states of [agg_approx_n_unique_state] per group and estimate the number of
+/// unique values.
+pub fn agg_approx_n_unique_merge(
+    states: &Series,
+    groups: &[(u32, Vec<u32>)],
+) -> Result<UInt32Chunked> {
+    let states = states.list()?;
+    let ca = groups
+        .iter()
+        .map(|(_, idx)| {
+            let mut hll = HyperLogLog::new();
+            for i in idx {
+                if let Some(state) = states.get(*i as usize) {
+                    hll.merge(&hll_from_state(&state)?)
+                }
+            }
+            Ok(hll.estimate() as u32)
+        })
+        .collect::<Result<NoNull<UInt32Chunked>>>()?;
+    Ok(ca.into_inner())
+}
+
+/// Approximate quantile per group. Null and NaN values are ignored.
+pub fn agg_approx_quantile(
+    s: &Series,
+    groups: &[(u32, Vec<u32>)],
+    quantile: f64,
+) -> Result<Float64Chunked> {
+    check_quantile(quantile)?;
+    let ca = digest_values(s)?;
+    Ok(groups
+        .par_iter()
+        .map(|(_, idx)| group_digest(&ca, idx).quantile(quantile))
+        .collect())
+}
+
+/// Partial state of [agg_approx_quantile] per group, as a `List(Float64)` column.
+pub fn agg_approx_quantile_state(s: &Series, groups: &[(u32, Vec<u32>)]) -> Result<ListChunked> {
+    let ca = digest_values(s)?;
+    Ok(groups
+        .iter()
+        .map(|(_, idx)| digest_to_state(&group_digest(&ca, idx)))
+        .collect())
+}
+
+/// Merge the partial states of [agg_approx_quantile_state] per group and estimate the quantile.
+pub fn agg_approx_quantile_merge(
+    states: &Series,
+    groups: &[(u32, Vec<u32>)],
+    quantile: f64,
+) -> Result<Float64Chunked> {
+    check_quantile(quantile)?;
+    let states = states.list()?;
+    groups
+        .iter()
+        .map(|(_, idx)| {
+            let digests = idx
+                .iter()
+                .filter_map(|i| states.get(*i as usize))
+                .map(|state| digest_from_state(&state))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(TDigest::merge(&digests).quantile(quantile))
+        })
+        .collect()
+}
+
+impl Series {
+    /// Approximate number of unique values, estimated with HyperLogLog. Null counts as a value.
+    pub fn approx_n_unique(&self) -> Result<usize> {
+        let mut hll = HyperLogLog::new();
+        sketch_hashes(self)?.into_iter().for_each(|h| hll.add(h));
+        Ok(hll.estimate() as usize)
+    }
+
+    /// Approximate quantile, estimated with a t-digest. Null and NaN values are ignored.
+    pub fn approx_quantile(&self, quantile: f64) -> Result<Option<f64>> {
+        check_quantile(quantile)?;
+        let ca = digest_values(self)?;
+        Ok(TDigest::from_values(ca.into_iter().flatten()).quantile(quantile))
+    }
+
+    /// Partial state of [approx_n_unique](Series::approx_n_unique) as a `List(UInt8)` Series
+    /// of length 1. The states of multiple parts of a column can be merged with
+    /// [approx_n_unique_merge](Series::approx_n_unique_merge).
+    pub fn approx_n_unique_state(&self) -> Result<Series> {
+        let mut out = agg_approx_n_unique_state(self, &all_rows(self.len()))?;
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+
+    /// Merge all partial states in this Series, as computed by
+    /// [approx_n_unique_state](Series::approx_n_unique_state).
+    pub fn approx_n_unique_merge(&self) -> Result<Series> {
+        let mut out = agg_approx_n_unique_merge(self, &all_rows(self.len()))?;
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+
+    /// Partial state of [approx_quantile](Series::approx_quantile) as a `List(Float64)`
+    /// Series of length 1. The states of multiple parts of a column can be merged with
+    /// [approx_quantile_merge](Series::approx_quantile_merge).
+    pub fn approx_quantile_state(&self) -> Result<Series> {
+        let mut out = agg_approx_quantile_state(self, &all_rows(self.len()))?;
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+
+    /// Merge all partial states in this Series, as computed by
+    /// [approx_quantile_state](Series::approx_quantile_state), and estimate the quantile.
+    pub fn approx_quantile_merge(&self, quantile: f64) -> Result<Series> {
+        let mut out = agg_approx_quantile_merge(self, &all_rows(self.len()), quantile)?;
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_approx_aggregations() -> Result<()> {
+        let s = Series::new("a", &[Some(1i32), Some(2), None, Some(2), Some(5), Some(3)]);
+        assert_eq!(s.approx_n_unique()?, 5);
+        assert_eq!(s.approx_quantile(0.5)?, Some(2.0));
+
+        let groups = vec![(0, vec![0, 1, 2]), (3, vec![3, 4, 5])];
+        let out = agg_approx_n_unique(&s, &groups)?;
+        assert_eq!(Vec::from(&out), &[Some(3), Some(3)]);
+        let out = agg_approx_quantile(&s, &groups, 1.0)?;
+        assert_eq!(Vec::from(&out), &[Some(2.0), Some(5.0)]);
+
+        // merging the states of the groups is the same as aggregating everything
+        let states = agg_approx_n_unique_state(&s, &groups)?.into_series();
+        assert_eq!(
+            states.approx_n_unique_merge()?.u32()?.get(0),
+            Some(s.approx_n_unique()? as u32)
+        );
+        let states = agg_approx_quantile_state(&s, &groups)?.into_series();
+        assert_eq!(states.approx_quantile_merge(0.5)?.f64()?.get(0), Some(2.0));
+        Ok(())
+    }
+}
@@ -4,8 +4,11 @@ use crate::POOL;
 use polars_time::groupby::ClosedWindow;
 use polars_time::{Duration, Window};
 use rayon::prelude::*;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct DynamicGroupOptions {
     pub time_column: String,
     /// start a window at this interval
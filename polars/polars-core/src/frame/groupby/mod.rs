@@ -13,12 +13,16 @@ use ahash::{CallHasher, RandomState};
 use hashbrown::HashMap;
 use num::NumCast;
 use rayon::prelude::*;
+#[cfg(all(feature = "serde-lazy", not(feature = "dynamic_groupby")))]
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::hash::Hash;
 #[cfg(feature = "dtype-categorical")]
 use std::ops::Deref;
 
 pub mod aggregations;
+#[cfg(feature = "approx")]
+pub mod approx;
 #[cfg(feature = "dynamic_groupby")]
 mod dynamic;
 pub(crate) mod hashing;
@@ -26,6 +30,7 @@ pub(crate) mod hashing;
 pub(crate) mod pivot;
 #[cfg(not(feature = "dynamic_groupby"))]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct DynamicGroupOptions {
     pub time_column: String,
 }
@@ -1228,6 +1233,10 @@ pub enum GroupByMethod {
     List,
     Std,
     Var,
+    #[cfg(feature = "approx")]
+    ApproxNUnique,
+    #[cfg(feature = "approx")]
+    ApproxQuantile(f64),
 }
 
 // Formatting functions used in eager and lazy code for renaming grouped columns
@@ -1248,6 +1257,10 @@ pub fn fmt_groupby_column(name: &str, method: GroupByMethod) -> String {
         Quantile(quantile, _interpol) => format!("{}_quantile_{:.2}", name, quantile),
         Std => format!("{}_agg_std", name),
         Var => format!("{}_agg_var", name),
+        #[cfg(feature = "approx")]
+        ApproxNUnique => format!("{}_approx_n_unique", name),
+        #[cfg(feature = "approx")]
+        ApproxQuantile(quantile) => format!("{}_approx_quantile_{:.2}", name, quantile),
     }
 }
 
@@ -1,6 +1,8 @@
 pub(crate) mod multiple_keys;
 use polars_arrow::utils::CustomIterTools;
 
+#[cfg(feature = "semi_anti_join")]
+use crate::frame::hash_join::multiple_keys::semi_anti_join_multiple_keys;
 use crate::frame::hash_join::multiple_keys::{
     inner_join_multiple_keys, left_join_multiple_keys, outer_join_multiple_keys,
 };
@@ -16,6 +18,8 @@ use ahash::RandomState;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 use hashbrown::HashMap;
 use rayon::prelude::*;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::hash::{BuildHasher, Hash, Hasher};
@@ -24,23 +28,11 @@ use unsafe_unwrap::UnsafeUnwrap;
 
 #[cfg(feature = "private")]
 pub use self::multiple_keys::private_left_join_multiple_keys;
+#[cfg(feature = "dtype-categorical")]
+use crate::chunked_array::categorical::remap_categoricals;
 use crate::frame::groupby::hashing::HASHMAP_INIT_SIZE;
 use crate::utils::series::to_physical;
 
-/// If Categorical types are created without a global string cache or under
-/// a different global string cache the mapping will be incorrect.
-#[cfg(feature = "dtype-categorical")]
-pub(crate) fn check_categorical_src(l: &Series, r: &Series) -> Result<()> {
-    if let (Ok(l), Ok(r)) = (l.categorical(), r.categorical()) {
-        let l = l.categorical_map.as_ref().unwrap();
-        let r = r.categorical_map.as_ref().unwrap();
-        if !l.same_src(&*r) {
-            return Err(PolarsError::ValueError("joins on categorical dtypes can only happen if they are created under the same global string cache".into()));
-        }
-    }
-    Ok(())
-}
-
 macro_rules! det_hash_prone_order {
     ($self:expr, $other:expr) => {{
         // The shortest relation will be used to create a hash table.
@@ -60,6 +52,7 @@ macro_rules! det_hash_prone_order {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum JoinType {
     Left,
     Inner,
@@ -68,6 +61,121 @@ pub enum JoinType {
     AsOf,
     #[cfg(feature = "cross_join")]
     Cross,
+    /// Keep the rows of the left table that have a match in the right table.
+    #[cfg(feature = "semi_anti_join")]
+    Semi,
+    /// Keep the rows of the left table that don't have a match in the right table.
+    #[cfg(feature = "semi_anti_join")]
+    Anti,
+}
+
+/// Assert the cardinality of the relation between the join keys of the left and right table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum JoinValidation {
+    /// No check is done.
+    ManyToMany,
+    /// The keys are unique in both tables.
+    OneToOne,
+    /// The keys are unique in the left table.
+    OneToMany,
+    /// The keys are unique in the right table.
+    ManyToOne,
+}
+
+impl Default for JoinValidation {
+    fn default() -> Self {
+        JoinValidation::ManyToMany
+    }
+}
+
+impl JoinValidation {
+    fn name(&self) -> &'static str {
+        match self {
+            JoinValidation::ManyToMany => "m:m",
+            JoinValidation::OneToOne => "1:1",
+            JoinValidation::OneToMany => "1:m",
+            JoinValidation::ManyToOne => "m:1",
+        }
+    }
+
+    fn validate(&self, left: &[Series], right: &[Series], join_nulls: bool) -> Result<()> {
+        let (check_left, check_right) = match self {
+            JoinValidation::ManyToMany => return Ok(()),
+            JoinValidation::OneToOne => (true, true),
+            JoinValidation::OneToMany => (true, false),
+            JoinValidation::ManyToOne => (false, true),
+        };
+        if check_left {
+            self.check_unique(left, "left", join_nulls)?;
+        }
+        if check_right {
+            self.check_unique(right, "right", join_nulls)?;
+        }
+        Ok(())
+    }
+
+    fn check_unique(&self, keys: &[Series], side: &str, join_nulls: bool) -> Result<()> {
+        let keys = DataFrame::new_no_checks(keys.to_vec());
+        let mut duplicated = keys.is_duplicated()?;
+        // null keys that don't match don't multiply rows
+        if !join_nulls {
+            for s in keys.get_columns() {
+                duplicated = &duplicated & &s.is_not_null();
+            }
+        }
+        if duplicated.all_false() {
+            return Ok(());
+        }
+
+        let examples = keys
+            .filter(&duplicated)?
+            .drop_duplicates(true, None)?
+            .head(Some(3));
+        let examples = (0..examples.height())
+            .map(|i| {
+                let row = examples
+                    .get(i)
+                    .unwrap()
+                    .iter()
+                    .map(|av| format!("{}", av))
+                    .collect::<Vec<_>>();
+                format!("({})", row.join(", "))
+            })
+            .collect::<Vec<_>>();
+        Err(PolarsError::ComputeError(
+            format!(
+                "join keys did not fulfill {} validation: the {} table has duplicate keys, e.g. {}",
+                self.name(),
+                side,
+                examples.join(", ")
+            )
+            .into(),
+        ))
+    }
+}
+
+/// Options that determine how the join keys are matched and returned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct JoinKeyOptions {
+    /// Check the uniqueness of the join keys before joining.
+    pub validate: JoinValidation,
+    /// Combine the key columns of both tables into a single column in an outer join.
+    /// If `false`, the key columns of both tables are kept.
+    pub coalesce: bool,
+    /// Let null keys match each other.
+    pub join_nulls: bool,
+}
+
+impl Default for JoinKeyOptions {
+    fn default() -> Self {
+        JoinKeyOptions {
+            validate: JoinValidation::default(),
+            coalesce: true,
+            join_nulls: true,
+        }
+    }
 }
 
 pub(crate) unsafe fn get_hash_tbl_threaded_join_partitioned<T, H>(
@@ -313,6 +421,66 @@ where
     })
 }
 
+/// Returns the indexes of the probe relation that have a match (semi join)
+/// or don't have a match (anti join) in the build relation.
+#[cfg(feature = "semi_anti_join")]
+fn hash_join_tuples_left_semi_anti<T, IntoSlice>(
+    probe: Vec<IntoSlice>,
+    build: Vec<IntoSlice>,
+    anti: bool,
+) -> Vec<u32>
+where
+    IntoSlice: AsRef<[T]> + Send + Sync,
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+{
+    // we only need to know if a key exists in the build relation,
+    // the right columns are never materialized.
+    let hash_tbls = create_probe_table(build);
+
+    let offsets = probe
+        .iter()
+        .map(|ph| ph.as_ref().len())
+        .scan(0, |state, val| {
+            let out = *state;
+            *state += val;
+            Some(out)
+        })
+        .collect::<Vec<_>>();
+
+    let n_tables = hash_tbls.len() as u64;
+    debug_assert!(n_tables.is_power_of_two());
+
+    POOL.install(|| {
+        probe
+            .into_par_iter()
+            .zip(offsets)
+            .map(|(probe, offset)| {
+                let hash_tbls = &hash_tbls;
+                let probe = probe.as_ref();
+
+                probe
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx_a, k)| {
+                        let idx_a = (idx_a + offset) as u32;
+                        // probe table that contains the hashed value
+                        let current_probe_table = unsafe {
+                            get_hash_tbl_threaded_join_partitioned(k.as_u64(), hash_tbls, n_tables)
+                        };
+                        let has_match = current_probe_table.contains_key(k);
+                        if has_match != anti {
+                            Some(idx_a)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .flatten()
+            .collect()
+    })
+}
+
 /// Probe the build table and add tuples to the results (inner join)
 fn probe_outer<T, F, G, H>(
     probe_hashes: &[Vec<(u64, T)>],
@@ -443,6 +611,10 @@ pub(crate) trait HashJoin<T> {
     fn hash_join_outer(&self, _other: &ChunkedArray<T>) -> Vec<(Option<u32>, Option<u32>)> {
         unimplemented!()
     }
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, _other: &ChunkedArray<T>, _anti: bool) -> Vec<u32> {
+        unimplemented!()
+    }
 }
 
 impl HashJoin<Float32Type> for Float32Chunked {
@@ -461,6 +633,12 @@ impl HashJoin<Float32Type> for Float32Chunked {
         let other = other.bit_repr_small();
         ca.hash_join_outer(&other)
     }
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, other: &Float32Chunked, anti: bool) -> Vec<u32> {
+        let ca = self.bit_repr_small();
+        let other = other.bit_repr_small();
+        ca.hash_join_semi_anti(&other, anti)
+    }
 }
 
 impl HashJoin<Float64Type> for Float64Chunked {
@@ -479,6 +657,12 @@ impl HashJoin<Float64Type> for Float64Chunked {
         let other = other.bit_repr_large();
         ca.hash_join_outer(&other)
     }
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, other: &Float64Chunked, anti: bool) -> Vec<u32> {
+        let ca = self.bit_repr_large();
+        let other = other.bit_repr_large();
+        ca.hash_join_semi_anti(&other, anti)
+    }
 }
 
 impl HashJoin<CategoricalType> for CategoricalChunked {
@@ -491,6 +675,10 @@ impl HashJoin<CategoricalType> for CategoricalChunked {
     fn hash_join_outer(&self, other: &CategoricalChunked) -> Vec<(Option<u32>, Option<u32>)> {
         self.deref().hash_join_outer(other.deref())
     }
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, other: &CategoricalChunked, anti: bool) -> Vec<u32> {
+        self.deref().hash_join_semi_anti(other.deref(), anti)
+    }
 }
 
 fn num_group_join_inner<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> Vec<(u32, u32)>
@@ -653,6 +841,70 @@ where
     }
 }
 
+#[cfg(feature = "semi_anti_join")]
+fn num_group_join_semi_anti<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    anti: bool,
+) -> Vec<u32>
+where
+    T: PolarsIntegerType,
+    T::Native: Hash + Eq + Send + AsU64,
+    Option<T::Native>: AsU64,
+{
+    let n_threads = POOL.current_num_threads();
+    let splitted_a = split_ca(left, n_threads).unwrap();
+    let splitted_b = split_ca(right, n_threads).unwrap();
+    match (
+        left.has_validity(),
+        right.has_validity(),
+        left.chunks.len(),
+        right.chunks.len(),
+    ) {
+        (false, false, 1, 1) => {
+            let keys_a = splitted_a
+                .iter()
+                .map(|ca| ca.cont_slice().unwrap())
+                .collect::<Vec<_>>();
+            let keys_b = splitted_b
+                .iter()
+                .map(|ca| ca.cont_slice().unwrap())
+                .collect::<Vec<_>>();
+            hash_join_tuples_left_semi_anti(keys_a, keys_b, anti)
+        }
+        (false, false, _, _) => {
+            let keys_a = splitted_a
+                .iter()
+                .map(|ca| ca.into_no_null_iter().collect_trusted::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let keys_b = splitted_b
+                .iter()
+                .map(|ca| ca.into_no_null_iter().collect_trusted::<Vec<_>>())
+                .collect::<Vec<_>>();
+            hash_join_tuples_left_semi_anti(keys_a, keys_b, anti)
+        }
+        _ => {
+            let keys_a = splitted_a
+                .iter()
+                .map(|ca| {
+                    ca.into_iter()
+                        .map(|v| v.as_u64())
+                        .collect_trusted::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let keys_b = splitted_b
+                .iter()
+                .map(|ca| {
+                    ca.into_iter()
+                        .map(|v| v.as_u64())
+                        .collect_trusted::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            hash_join_tuples_left_semi_anti(keys_a, keys_b, anti)
+        }
+    }
+}
+
 impl<T> HashJoin<T> for ChunkedArray<T>
 where
     T: PolarsIntegerType + Sync,
@@ -742,6 +994,49 @@ where
         }
     }
 
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, other: &ChunkedArray<T>, anti: bool) -> Vec<u32> {
+        match self.dtype() {
+            DataType::UInt64 => {
+                // convince the compiler that we are this type.
+                let ca: &UInt64Chunked = unsafe {
+                    &*(self as *const ChunkedArray<T> as *const ChunkedArray<UInt64Type>)
+                };
+                let other: &UInt64Chunked = unsafe {
+                    &*(other as *const ChunkedArray<T> as *const ChunkedArray<UInt64Type>)
+                };
+                num_group_join_semi_anti(ca, other, anti)
+            }
+            DataType::UInt32 => {
+                // convince the compiler that we are this type.
+                let ca: &UInt32Chunked = unsafe {
+                    &*(self as *const ChunkedArray<T> as *const ChunkedArray<UInt32Type>)
+                };
+                let other: &UInt32Chunked = unsafe {
+                    &*(other as *const ChunkedArray<T> as *const ChunkedArray<UInt32Type>)
+                };
+                num_group_join_semi_anti(ca, other, anti)
+            }
+            DataType::Int64 | DataType::Float64 => {
+                let ca = self.bit_repr_large();
+                let other = other.bit_repr_large();
+                num_group_join_semi_anti(&ca, &other, anti)
+            }
+            DataType::Int32 | DataType::Float32 => {
+                let ca = self.bit_repr_small();
+                let other = other.bit_repr_small();
+                num_group_join_semi_anti(&ca, &other, anti)
+            }
+            _ => {
+                let ca = self.cast(&DataType::UInt32).unwrap();
+                let ca = ca.u32().unwrap();
+                let other = other.cast(&DataType::UInt32).unwrap();
+                let other = other.u32().unwrap();
+                num_group_join_semi_anti(ca, other, anti)
+            }
+        }
+    }
+
     fn hash_join_outer(&self, other: &ChunkedArray<T>) -> Vec<(Option<u32>, Option<u32>)> {
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
@@ -793,6 +1088,15 @@ impl HashJoin<BooleanType> for BooleanChunked {
         ca.hash_join_left(other)
     }
 
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, other: &BooleanChunked, anti: bool) -> Vec<u32> {
+        let ca = self.cast(&DataType::UInt32).unwrap();
+        let ca = ca.u32().unwrap();
+        let other = other.cast(&DataType::UInt32).unwrap();
+        let other = other.u32().unwrap();
+        ca.hash_join_semi_anti(other, anti)
+    }
+
     fn hash_join_outer(&self, other: &BooleanChunked) -> Vec<(Option<u32>, Option<u32>)> {
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
@@ -872,6 +1176,19 @@ impl HashJoin<Utf8Type> for Utf8Chunked {
         hash_join_tuples_left(str_hashes_a, str_hashes_b)
     }
 
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, other: &Utf8Chunked, anti: bool) -> Vec<u32> {
+        let n_threads = POOL.current_num_threads();
+
+        let hb = RandomState::default();
+        let splitted_a = split_ca(self, n_threads).unwrap();
+        let splitted_b = split_ca(other, n_threads).unwrap();
+
+        let str_hashes_a = prepare_strs(&splitted_a, &hb);
+        let str_hashes_b = prepare_strs(&splitted_b, &hb);
+        hash_join_tuples_left_semi_anti(str_hashes_a, str_hashes_b, anti)
+    }
+
     fn hash_join_outer(&self, other: &Utf8Chunked) -> Vec<(Option<u32>, Option<u32>)> {
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
@@ -1093,16 +1410,62 @@ impl DataFrame {
         right_on: S2,
         how: JoinType,
         suffix: Option<String>,
+    ) -> Result<DataFrame> {
+        self.join_with_options(
+            other,
+            left_on,
+            right_on,
+            how,
+            suffix,
+            &JoinKeyOptions::default(),
+        )
+    }
+
+    /// Generic join method that also controls the validation, coalescing and null matching
+    /// of the join keys.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> Result<DataFrame> {
+    ///     let options = JoinKeyOptions {
+    ///         // error if a key occurs more than once in the right table
+    ///         validate: JoinValidation::ManyToOne,
+    ///         join_nulls: false,
+    ///         ..Default::default()
+    ///     };
+    ///     left.join_with_options(right, "key", "key", JoinType::Left, None, &options)
+    /// }
+    /// ```
+    pub fn join_with_options<'a, J, S1: Selection<'a, J>, S2: Selection<'a, J>>(
+        &self,
+        other: &DataFrame,
+        left_on: S1,
+        right_on: S2,
+        how: JoinType,
+        suffix: Option<String>,
+        options: &JoinKeyOptions,
     ) -> Result<DataFrame> {
         #[cfg(feature = "cross_join")]
         if let JoinType::Cross = how {
             return self.cross_join(other);
         }
 
-        #[allow(unused_mut)]
-        let mut selected_left = self.select_series(left_on)?;
-        #[allow(unused_mut)]
-        let mut selected_right = other.select_series(right_on)?;
+        let selected_left = self.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        self.join_impl(other, selected_left, selected_right, how, suffix, options)
+    }
+
+    fn join_impl(
+        &self,
+        other: &DataFrame,
+        selected_left: Vec<Series>,
+        selected_right: Vec<Series>,
+        how: JoinType,
+        suffix: Option<String>,
+        options: &JoinKeyOptions,
+    ) -> Result<DataFrame> {
         if selected_right.len() != selected_left.len() {
             return Err(PolarsError::ValueError(
                 "the number of columns given as join key should be equal".into(),
@@ -1116,9 +1479,59 @@ impl DataFrame {
             return Err(PolarsError::ValueError("the dtype of the join keys don't match. first cast your columns to the correct dtype".into()));
         }
 
+        // categoricals that are not created under the same global string cache are
+        // re-encoded under a common rev map, so that their categories can be compared
         #[cfg(feature = "dtype-categorical")]
-        for (l, r) in selected_left.iter().zip(&selected_right) {
-            check_categorical_src(l, r)?
+        let (selected_left, selected_right): (Vec<_>, Vec<_>) = selected_left
+            .iter()
+            .zip(&selected_right)
+            .map(|(l, r)| remap_categoricals(l, r))
+            .unzip();
+
+        options
+            .validate
+            .validate(&selected_left, &selected_right, options.join_nulls)?;
+
+        // null keys of the right table are removed, so that they cannot be matched.
+        // In an outer join they are appended as unmatched rows.
+        if !options.join_nulls && selected_right.iter().any(|s| s.null_count() > 0) {
+            let mut valid = selected_right[0].is_not_null();
+            for s in &selected_right[1..] {
+                valid = &valid & &s.is_not_null();
+            }
+            let right_names = selected_right.iter().map(|s| s.name()).collect::<Vec<_>>();
+            let options = JoinKeyOptions {
+                validate: JoinValidation::ManyToMany,
+                join_nulls: true,
+                ..options.clone()
+            };
+
+            let other_valid = other.filter(&valid)?;
+            let selected_right_valid = other_valid.select_series(&right_names)?;
+            let out = self.join_impl(
+                &other_valid,
+                selected_left.clone(),
+                selected_right_valid,
+                how,
+                suffix.clone(),
+                &options,
+            )?;
+
+            return if how == JoinType::Outer {
+                let other_null = other.filter(&!valid)?;
+                let selected_right_null = other_null.select_series(&right_names)?;
+                let unmatched = self.slice(0, 0).join_impl(
+                    &other_null,
+                    selected_left.iter().map(|s| s.slice(0, 0)).collect(),
+                    selected_right_null,
+                    how,
+                    suffix,
+                    &options,
+                )?;
+                out.vstack(&unmatched)
+            } else {
+                Ok(out)
+            };
         }
 
         // Single keys
@@ -1128,15 +1541,24 @@ impl DataFrame {
             return match how {
                 JoinType::Inner => self.inner_join_from_series(other, s_left, s_right, suffix),
                 JoinType::Left => self.left_join_from_series(other, s_left, s_right, suffix),
-                JoinType::Outer => self.outer_join_from_series(other, s_left, s_right, suffix),
-                #[cfg(feature = "asof_join")]
-                JoinType::AsOf => {
-                    self.join_asof(other, selected_left[0].name(), selected_right[0].name())
+                JoinType::Outer => {
+                    self.outer_join_from_series(other, s_left, s_right, suffix, options.coalesce)
                 }
+                #[cfg(feature = "asof_join")]
+                JoinType::AsOf => self.join_asof(
+                    other,
+                    selected_left[0].name(),
+                    selected_right[0].name(),
+                    &AsOfOptions::default(),
+                ),
                 #[cfg(feature = "cross_join")]
                 JoinType::Cross => {
                     unreachable!()
                 }
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi => self.semi_anti_join_from_series(s_left, s_right, false),
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Anti => self.semi_anti_join_from_series(s_left, s_right, true),
             };
         }
 
@@ -1206,6 +1628,10 @@ impl DataFrame {
                 let (left, right, swap) = det_hash_prone_order!(left, right);
                 let opt_join_tuples = outer_join_multiple_keys(&left, &right, swap);
 
+                if !options.coalesce {
+                    return self.outer_join_no_coalesce(other, &opt_join_tuples, suffix);
+                }
+
                 // Take the left and right dataframes by join tuples
                 let (mut df_left, df_right) = POOL.join(
                     || unsafe {
@@ -1238,6 +1664,15 @@ impl DataFrame {
             JoinType::Cross => {
                 unreachable!()
             }
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => {
+                let left = DataFrame::new_no_checks(selected_left_physical);
+                let right = DataFrame::new_no_checks(selected_right_physical);
+                let idx = semi_anti_join_multiple_keys(&left, &right, how == JoinType::Anti);
+                // Safety:
+                // indices are in bounds
+                Ok(unsafe { self.take_iter_unchecked(idx.into_iter().map(|i| i as usize)) })
+            }
         }
     }
 
@@ -1268,7 +1703,7 @@ impl DataFrame {
         suffix: Option<String>,
     ) -> Result<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
-        check_categorical_src(s_left, s_right)?;
+        let (s_left, s_right) = &remap_categoricals(s_left, s_right);
         let join_tuples = s_left.hash_join_inner(s_right);
 
         let (df_left, df_right) = POOL.join(
@@ -1334,7 +1769,7 @@ impl DataFrame {
         suffix: Option<String>,
     ) -> Result<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
-        check_categorical_src(s_left, s_right)?;
+        let (s_left, s_right) = &remap_categoricals(s_left, s_right);
         let opt_join_tuples = s_left.hash_join_left(s_right);
 
         let (df_left, df_right) = POOL.join(
@@ -1350,6 +1785,23 @@ impl DataFrame {
         self.finish_join(df_left, df_right, suffix)
     }
 
+    /// Keep the rows of this DataFrame that have a match (semi join) or don't have a match (anti join)
+    /// in `other`. None of the columns of `other` are added to the result.
+    #[cfg(feature = "semi_anti_join")]
+    pub(crate) fn semi_anti_join_from_series(
+        &self,
+        s_left: &Series,
+        s_right: &Series,
+        anti: bool,
+    ) -> Result<DataFrame> {
+        #[cfg(feature = "dtype-categorical")]
+        let (s_left, s_right) = &remap_categoricals(s_left, s_right);
+        let idx = s_left.hash_join_semi_anti(s_right, anti);
+        // Safety:
+        // indices are in bounds
+        Ok(unsafe { self.take_iter_unchecked(idx.into_iter().map(|i| i as usize)) })
+    }
+
     /// Perform an outer join on two DataFrames
     /// # Example
     ///
@@ -1373,11 +1825,15 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
         suffix: Option<String>,
+        coalesce: bool,
     ) -> Result<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
-        check_categorical_src(s_left, s_right)?;
+        let (s_left, s_right) = &remap_categoricals(s_left, s_right);
         // Get the indexes of the joined relations
         let opt_join_tuples = s_left.hash_join_outer(s_right);
+        if !coalesce {
+            return self.outer_join_no_coalesce(other, &opt_join_tuples, suffix);
+        }
 
         // Take the left and right dataframes by join tuples
         let (mut df_left, df_right) = POOL.join(
@@ -1401,6 +1857,34 @@ impl DataFrame {
         df_left.hstack_mut(&[s])?;
         self.finish_join(df_left, df_right, suffix)
     }
+
+    /// Finish an outer join that keeps the key columns of both tables.
+    fn outer_join_no_coalesce(
+        &self,
+        other: &DataFrame,
+        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+        suffix: Option<String>,
+    ) -> Result<DataFrame> {
+        // Safety:
+        // join tuples are in bounds
+        let (df_left, df_right) = POOL.join(
+            || unsafe {
+                self.take_opt_iter_unchecked(
+                    opt_join_tuples
+                        .iter()
+                        .map(|(left, _right)| left.map(|i| i as usize)),
+                )
+            },
+            || unsafe {
+                other.take_opt_iter_unchecked(
+                    opt_join_tuples
+                        .iter()
+                        .map(|(_left, right)| right.map(|i| i as usize)),
+                )
+            },
+        );
+        self.finish_join(df_left, df_right, suffix)
+    }
 }
 
 #[cfg(test)]
@@ -1655,7 +2139,7 @@ mod test {
 
         assert_eq!(Vec::from(ca), correct_ham);
 
-        // Test an error when joining on different string cache
+        // categoricals of different string caches are re-encoded before joining
         let (mut df_a, mut df_b) = get_dfs();
         df_a.may_apply("b", |s| s.cast(&DataType::Categorical))
             .unwrap();
@@ -1665,8 +2149,20 @@ mod test {
 
         df_b.may_apply("bar", |s| s.cast(&DataType::Categorical))
             .unwrap();
-        let out = df_a.join(&df_b, "b", "bar", JoinType::Left, None);
-        assert!(out.is_err())
+        let out = df_a.join(&df_b, "b", "bar", JoinType::Left, None).unwrap();
+        let ham_col = out.column("ham").unwrap();
+        assert_eq!(Vec::from(ham_col.utf8().unwrap()), correct_ham);
+
+        // and so are local categoricals
+        toggle_string_cache(false);
+        let (mut df_a, mut df_b) = get_dfs();
+        df_a.may_apply("b", |s| s.cast(&DataType::Categorical))
+            .unwrap();
+        df_b.may_apply("bar", |s| s.cast(&DataType::Categorical))
+            .unwrap();
+        let out = df_a.join(&df_b, "b", "bar", JoinType::Left, None).unwrap();
+        let ham_col = out.column("ham").unwrap();
+        assert_eq!(Vec::from(ham_col.utf8().unwrap()), correct_ham);
     }
 
     #[test]
@@ -1941,4 +2437,111 @@ mod test {
         assert_eq!(out.shape(), (9, 1));
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_join_key_options() -> Result<()> {
+        let a = df![
+            "a" => [Some(1), Some(2), None],
+            "b" => ["x", "y", "z"]
+        ]?;
+        let b = df![
+            "a" => [Some(1), Some(1), None, Some(3)],
+            "c" => [1, 2, 3, 4]
+        ]?;
+
+        let validate = |validate| JoinKeyOptions {
+            validate,
+            ..Default::default()
+        };
+        let out = a.join_with_options(
+            &b,
+            "a",
+            "a",
+            JoinType::Left,
+            None,
+            &validate(JoinValidation::OneToMany),
+        )?;
+        assert_eq!(out.height(), 4);
+        let err = a
+            .join_with_options(
+                &b,
+                "a",
+                "a",
+                JoinType::Left,
+                None,
+                &validate(JoinValidation::ManyToOne),
+            )
+            .unwrap_err();
+        assert!(format!("{:?}", err).contains("(1)"));
+
+        // null keys don't match
+        let options = JoinKeyOptions {
+            join_nulls: false,
+            ..Default::default()
+        };
+        let out = a.join_with_options(&b, "a", "a", JoinType::Inner, None, &options)?;
+        assert_eq!(out.height(), 2);
+        assert_eq!(out.column("a")?.null_count(), 0);
+        let out = a.join_with_options(&b, "a", "a", JoinType::Left, None, &options)?;
+        assert_eq!(
+            Vec::from(out.column("c")?.i32()?),
+            &[Some(1), Some(2), None, None]
+        );
+        let out = a.join_with_options(&b, "a", "a", JoinType::Outer, None, &options)?;
+        assert_eq!(out.height(), 6);
+        assert_eq!(out.column("a")?.null_count(), 2);
+
+        // keep the keys of both tables
+        let options = JoinKeyOptions {
+            coalesce: false,
+            ..Default::default()
+        };
+        let out = a.join_with_options(&b, "a", "a", JoinType::Outer, None, &options)?;
+        assert_eq!(out.get_column_names(), &["a", "b", "a_right", "c"]);
+        assert_eq!(out.height(), 5);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "semi_anti_join")]
+    fn test_semi_anti_join() -> Result<()> {
+        let df_a = df![
+            "a" => [1, 2, 3, 4, 2],
+            "b" => ["a", "b", "c", "d", "e"],
+            "c" => ["x", "y", "x", "y", "x"]
+        ]?;
+        let df_b = df![
+            "a" => [2, 4, 4, 5],
+            "c" => ["x", "y", "y", "y"],
+            "d" => [1.0, 2.0, 3.0, 4.0]
+        ]?;
+
+        let out = df_a.join(&df_b, "a", "a", JoinType::Semi, None)?;
+        assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(
+            Vec::from(out.column("b")?.utf8()?),
+            &[Some("b"), Some("d"), Some("e")]
+        );
+
+        let out = df_a.join(&df_b, "a", "a", JoinType::Anti, None)?;
+        assert_eq!(Vec::from(out.column("b")?.utf8()?), &[Some("a"), Some("c")]);
+
+        // multiple keys
+        let out = df_a.join(&df_b, vec!["a", "c"], vec!["a", "c"], JoinType::Semi, None)?;
+        assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(Vec::from(out.column("b")?.utf8()?), &[Some("d"), Some("e")]);
+
+        let out = df_a.join(&df_b, vec!["a", "c"], vec!["a", "c"], JoinType::Anti, None)?;
+        assert_eq!(
+            Vec::from(out.column("b")?.utf8()?),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+
+        // string keys
+        let out = df_a.join(&df_b, "c", "c", JoinType::Anti, None)?;
+        assert_eq!(out.height(), 0);
+        Ok(())
+    }
 }
@@ -20,16 +20,23 @@ use crate::utils::{
 mod arithmetic;
 #[cfg(feature = "asof_join")]
 pub(crate) mod asof_join;
+#[cfg(feature = "cov")]
+pub mod cov;
 #[cfg(feature = "cross_join")]
 pub(crate) mod cross_join;
+#[cfg(feature = "describe")]
+mod describe;
 pub mod explode;
 pub mod groupby;
 pub mod hash_join;
+#[cfg(feature = "range_join")]
+pub(crate) mod range_join;
 #[cfg(feature = "rows")]
 pub mod row;
 pub mod select;
 mod upstream_traits;
 
+use crate::prelude::sort::arg_top_k;
 #[cfg(feature = "sort_multiple")]
 use crate::prelude::sort::prepare_argsort;
 use crate::vector_hasher::boost_hash_combine;
@@ -1614,6 +1621,65 @@ impl DataFrame {
         self.sort_impl(by_column, reverse)
     }
 
+    /// Return the rows `offset..offset + len` of this `DataFrame` sorted by `by_column`.
+    ///
+    /// This is equal to `df.sort(by_column, reverse)?.slice(offset, len)`, but only the first
+    /// `offset + len` rows of the sorted order are determined, which is much cheaper than a
+    /// full sort if these are few.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// // the 10 rows with the largest values in column "a"
+    /// fn top_10(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.sort_slice("a", true, 0, 10)
+    /// }
+    /// ```
+    pub fn sort_slice<'a, S, J>(
+        &self,
+        by_column: S,
+        reverse: impl IntoVec<bool>,
+        offset: usize,
+        len: usize,
+    ) -> Result<Self>
+    where
+        S: Selection<'a, J>,
+    {
+        let by_column = by_column.to_selection_vec();
+        let mut reverse = reverse.into_vec();
+        let columns = self.select_series(&by_column)?;
+        // broadcast ordering
+        if reverse.len() == 1 {
+            reverse = vec![reverse[0]; columns.len()];
+        }
+        if columns.is_empty() || columns.len() != reverse.len() {
+            return Err(PolarsError::ValueError(
+                format!(
+                    "The amount of ordering booleans: {} does not match that no. of Series: {}",
+                    reverse.len(),
+                    columns.len()
+                )
+                .into(),
+            ));
+        }
+
+        let take = arg_top_k(&columns, &reverse, offset.saturating_add(len));
+        let take = take.slice(offset as i64, len);
+        // Safety:
+        // the created indices are in bounds
+        let mut df = unsafe { self.take_unchecked(&take) };
+        // Mark the first sort column as sorted
+        df.apply(by_column[0], |s| {
+            let mut s = s.clone();
+            let inner = s.get_inner_mut();
+            inner.set_sorted(reverse[0]);
+            s
+        })
+        .expect("column is present");
+        Ok(df)
+    }
+
     /// Replace a column with a `Series`.
     ///
     /// # Example
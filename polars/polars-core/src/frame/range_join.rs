@@ -0,0 +1,553 @@
+use crate::frame::hash_join::multiple_keys::inner_join_multiple_keys;
+use crate::frame::select::Selection;
+use crate::prelude::*;
+use crate::utils::series::to_physical;
+use crate::POOL;
+use rayon::prelude::*;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
+
+/// Comparison between a column of the left and a column of the right table in a range join.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum InequalityOperator {
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// A predicate `left <op> right` where `left` is a column of the left table
+/// and `right` a column of the right table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct InequalityPredicate {
+    pub left: String,
+    pub op: InequalityOperator,
+    pub right: String,
+}
+
+impl InequalityPredicate {
+    pub fn new(left: &str, op: InequalityOperator, right: &str) -> Self {
+        InequalityPredicate {
+            left: left.to_string(),
+            op,
+            right: right.to_string(),
+        }
+    }
+}
+
+#[inline]
+fn compare<T: PartialOrd>(left: Option<T>, right: Option<T>, op: InequalityOperator) -> bool {
+    use InequalityOperator::*;
+    match (left, right) {
+        (Some(l), Some(r)) => match op {
+            Lt => l < r,
+            LtEq => l <= r,
+            Gt => l > r,
+            GtEq => l >= r,
+        },
+        // null values never match
+        _ => false,
+    }
+}
+
+/// The values of the columns of a predicate, cast to a common physical type.
+enum PredicateKeys {
+    Int(Vec<Option<i64>>, Vec<Option<i64>>),
+    Float(Vec<Option<f64>>, Vec<Option<f64>>),
+}
+
+impl PredicateKeys {
+    fn new(left: &Series, right: &Series) -> Result<Self> {
+        let left = left.to_physical_repr();
+        let right = right.to_physical_repr();
+        for s in [&left, &right] {
+            if !s.is_numeric_physical() || matches!(s.dtype(), DataType::Categorical) {
+                return Err(PolarsError::InvalidOperation(
+                    format!(
+                        "range join predicates are not supported on column '{}' of dtype {:?}",
+                        s.name(),
+                        s.dtype()
+                    )
+                    .into(),
+                ));
+            }
+        }
+        let is_float = |s: &Series| matches!(s.dtype(), DataType::Float32 | DataType::Float64);
+
+        if is_float(&left) || is_float(&right) {
+            let left = left.cast(&DataType::Float64)?;
+            let right = right.cast(&DataType::Float64)?;
+            Ok(PredicateKeys::Float(
+                left.f64()?.into_iter().collect(),
+                right.f64()?.into_iter().collect(),
+            ))
+        } else {
+            for s in [&left, &right] {
+                if let DataType::UInt64 = s.dtype() {
+                    if s.u64()?.max().map_or(false, |max| max > i64::MAX as u64) {
+                        return Err(PolarsError::InvalidOperation(
+                            format!(
+                                "range join predicates on column '{}' of dtype UInt64 only support values up to {}",
+                                s.name(),
+                                i64::MAX
+                            )
+                            .into(),
+                        ));
+                    }
+                }
+            }
+            let left = left.cast(&DataType::Int64)?;
+            let right = right.cast(&DataType::Int64)?;
+            Ok(PredicateKeys::Int(
+                left.i64()?.into_iter().collect(),
+                right.i64()?.into_iter().collect(),
+            ))
+        }
+    }
+
+    #[inline]
+    fn eval(&self, op: InequalityOperator, idx_left: u32, idx_right: u32) -> bool {
+        // Safety:
+        // join indices are in bounds
+        unsafe {
+            match self {
+                PredicateKeys::Int(l, r) => compare(
+                    *l.get_unchecked(idx_left as usize),
+                    *r.get_unchecked(idx_right as usize),
+                    op,
+                ),
+                PredicateKeys::Float(l, r) => compare(
+                    *l.get_unchecked(idx_left as usize),
+                    *r.get_unchecked(idx_right as usize),
+                    op,
+                ),
+            }
+        }
+    }
+}
+
+/// NaN is not ordered, so it never satisfies a predicate.
+#[inline]
+fn is_ordered<T: PartialOrd>(v: &T) -> bool {
+    v.partial_cmp(v).is_some()
+}
+
+/// The values that can satisfy a predicate with their row index, in ascending order.
+fn sorted_values<T: PartialOrd + Copy>(values: &[Option<T>]) -> Vec<(T, u32)> {
+    let mut sorted = values
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, opt_v)| opt_v.filter(is_ordered).map(|v| (v, idx as u32)))
+        .collect::<Vec<_>>();
+    // the unordered values are filtered out
+    sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    sorted
+}
+
+/// Sort the right table on the predicate column and search the range of matching rows
+/// for every left value. The candidates in that range are filtered by the remaining predicates.
+fn sorted_range_join<T, F>(
+    left: &[Option<T>],
+    right: &[Option<T>],
+    op: InequalityOperator,
+    filter: F,
+) -> Vec<(u32, u32)>
+where
+    T: PartialOrd + Copy + Send + Sync,
+    F: Fn(u32, u32) -> bool + Send + Sync,
+{
+    let sorted_right = sorted_values(right);
+
+    let n_threads = POOL.current_num_threads();
+    let chunk_size = std::cmp::max(left.len() / n_threads, 1);
+
+    POOL.install(|| {
+        left.par_chunks(chunk_size)
+            .enumerate()
+            .map(|(chunk_idx, chunk)| {
+                let offset = chunk_idx * chunk_size;
+                let mut results = Vec::with_capacity(chunk.len());
+
+                for (idx, opt_v) in chunk.iter().enumerate() {
+                    let idx_left = (idx + offset) as u32;
+                    let left_val = match opt_v {
+                        Some(v) if is_ordered(v) => *v,
+                        _ => continue,
+                    };
+                    let n_lt = || sorted_right.partition_point(|(v, _)| *v < left_val);
+                    let n_le = || sorted_right.partition_point(|(v, _)| *v <= left_val);
+
+                    let candidates = match op {
+                        // left < right
+                        InequalityOperator::Lt => &sorted_right[n_le()..],
+                        // left <= right
+                        InequalityOperator::LtEq => &sorted_right[n_lt()..],
+                        // left > right
+                        InequalityOperator::Gt => &sorted_right[..n_lt()],
+                        // left >= right
+                        InequalityOperator::GtEq => &sorted_right[..n_le()],
+                    };
+                    results.extend(
+                        candidates
+                            .iter()
+                            .filter(|(_, idx_right)| filter(idx_left, *idx_right))
+                            .map(|(_, idx_right)| (idx_left, *idx_right)),
+                    );
+                }
+                results
+            })
+            .flatten()
+            .collect()
+    })
+}
+
+fn is_lower_bound(op: InequalityOperator) -> bool {
+    matches!(op, InequalityOperator::Gt | InequalityOperator::GtEq)
+}
+
+fn is_upper_bound(op: InequalityOperator) -> bool {
+    matches!(op, InequalityOperator::Lt | InequalityOperator::LtEq)
+}
+
+/// Join on a `lower` and an `upper` bound of the same left column, e.g. `start <= ts < end`.
+///
+/// The left values are visited in ascending order, while the right rows that satisfy both
+/// bounds are kept in an active set. A right row enters the set once the left value reaches
+/// its lower bound and leaves it for good once the left value passes its upper bound, so every
+/// left row only visits the right rows that match both bounds. The active rows are filtered
+/// by the remaining predicates.
+fn sorted_interval_join<T, F>(
+    left: &[Option<T>],
+    lower: &[Option<T>],
+    lower_op: InequalityOperator,
+    upper: &[Option<T>],
+    upper_op: InequalityOperator,
+    filter: F,
+) -> Vec<(u32, u32)>
+where
+    T: PartialOrd + Copy + Send + Sync,
+    F: Fn(u32, u32) -> bool + Send + Sync,
+{
+    let sorted_left = sorted_values(left);
+    // the right rows in the order in which they enter the active set
+    let sorted_lower = sorted_values(lower)
+        .into_iter()
+        .filter_map(|(lower, idx)| {
+            upper[idx as usize]
+                .filter(is_ordered)
+                .map(|upper| (lower, upper, idx))
+        })
+        .collect::<Vec<_>>();
+
+    let n_threads = POOL.current_num_threads();
+    let chunk_size = std::cmp::max(sorted_left.len() / n_threads, 1);
+
+    POOL.install(|| {
+        let mut join_tuples: Vec<(u32, u32)> = sorted_left
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let mut results = Vec::with_capacity(chunk.len());
+                // (upper bound, right index)
+                let mut active: Vec<(T, u32)> = vec![];
+                let mut next = 0;
+
+                for (left_val, idx_left) in chunk {
+                    while let Some((lower, upper, idx_right)) = sorted_lower.get(next) {
+                        if !compare(Some(*left_val), Some(*lower), lower_op) {
+                            break;
+                        }
+                        active.push((*upper, *idx_right));
+                        next += 1;
+                    }
+                    // the left values only increase, so these rows never match again
+                    active.retain(|(upper, _)| compare(Some(*left_val), Some(*upper), upper_op));
+
+                    results.extend(
+                        active
+                            .iter()
+                            .filter(|(_, idx_right)| filter(*idx_left, *idx_right))
+                            .map(|(_, idx_right)| (*idx_left, *idx_right)),
+                    );
+                }
+                results
+            })
+            .flatten()
+            .collect();
+        // keep the order of the left table
+        join_tuples.par_sort_unstable();
+        join_tuples
+    })
+}
+
+impl DataFrame {
+    /// Join on inequality predicates, optionally combined with equality keys.
+    ///
+    /// Rows are joined if the keys in `left_on` and `right_on` are equal and all `predicates`
+    /// hold. If no equality keys are given, the right table is sorted on the column of the
+    /// first predicate, so that every left row only visits the right rows that satisfy it.
+    /// If the predicates bound a left column from below and from above, every left row only
+    /// visits the right rows that satisfy both bounds.
+    ///
+    /// Null and NaN values never match. `UInt64` predicate columns with values above
+    /// `i64::MAX` are not supported.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// // join events to the interval `start <= ts < end`
+    /// fn join_intervals(events: &DataFrame, intervals: &DataFrame) -> Result<DataFrame> {
+    ///     let predicates = [
+    ///         InequalityPredicate::new("ts", InequalityOperator::GtEq, "start"),
+    ///         InequalityPredicate::new("ts", InequalityOperator::Lt, "end"),
+    ///     ];
+    ///     events.join_where(intervals, &Vec::<&str>::new(), &Vec::<&str>::new(), &predicates, None)
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "range_join")))]
+    pub fn join_where<'a, J, S1: Selection<'a, J>, S2: Selection<'a, J>>(
+        &self,
+        other: &DataFrame,
+        left_on: S1,
+        right_on: S2,
+        predicates: &[InequalityPredicate],
+        suffix: Option<String>,
+    ) -> Result<DataFrame> {
+        let selected_left = self.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        if selected_right.len() != selected_left.len() {
+            return Err(PolarsError::ValueError(
+                "the number of columns given as join key should be equal".into(),
+            ));
+        }
+        if predicates.is_empty() {
+            if selected_left.is_empty() {
+                return Err(PolarsError::ValueError(
+                    "a range join needs at least one predicate or join key".into(),
+                ));
+            }
+            return self.join(
+                other,
+                &selected_left.iter().map(|s| s.name()).collect::<Vec<_>>(),
+                &selected_right.iter().map(|s| s.name()).collect::<Vec<_>>(),
+                JoinType::Inner,
+                suffix,
+            );
+        }
+
+        let keys = predicates
+            .iter()
+            .map(|p| {
+                let keys = PredicateKeys::new(self.column(&p.left)?, other.column(&p.right)?)?;
+                Ok((keys, p.op))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let join_tuples = if selected_left.is_empty() {
+            // a lower and an upper bound on the same left column
+            let bounds = (0..keys.len()).find_map(|i| {
+                (0..keys.len())
+                    .find(|&j| {
+                        predicates[i].left == predicates[j].left
+                            && is_lower_bound(keys[i].1)
+                            && is_upper_bound(keys[j].1)
+                            && matches!(
+                                (&keys[i].0, &keys[j].0),
+                                (PredicateKeys::Int(..), PredicateKeys::Int(..))
+                                    | (PredicateKeys::Float(..), PredicateKeys::Float(..))
+                            )
+                    })
+                    .map(|j| (i, j))
+            });
+
+            match bounds {
+                Some((i, j)) => {
+                    let filter = |idx_left, idx_right| {
+                        keys.iter()
+                            .enumerate()
+                            .filter(|(k, _)| *k != i && *k != j)
+                            .all(|(_, (keys, op))| keys.eval(*op, idx_left, idx_right))
+                    };
+                    let (lower_op, upper_op) = (keys[i].1, keys[j].1);
+                    match (&keys[i].0, &keys[j].0) {
+                        (PredicateKeys::Int(l, lower), PredicateKeys::Int(_, upper)) => {
+                            sorted_interval_join(l, lower, lower_op, upper, upper_op, filter)
+                        }
+                        (PredicateKeys::Float(l, lower), PredicateKeys::Float(_, upper)) => {
+                            sorted_interval_join(l, lower, lower_op, upper, upper_op, filter)
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+                None => {
+                    let (first, remaining) = keys.split_first().unwrap();
+                    let filter = |idx_left, idx_right| {
+                        remaining
+                            .iter()
+                            .all(|(keys, op)| keys.eval(*op, idx_left, idx_right))
+                    };
+                    match first {
+                        (PredicateKeys::Int(l, r), op) => sorted_range_join(l, r, *op, filter),
+                        (PredicateKeys::Float(l, r), op) => sorted_range_join(l, r, *op, filter),
+                    }
+                }
+            }
+        } else {
+            if selected_left
+                .iter()
+                .zip(&selected_right)
+                .any(|(l, r)| l.dtype() != r.dtype())
+            {
+                return Err(PolarsError::ValueError("the dtype of the join keys don't match. first cast your columns to the correct dtype".into()));
+            }
+            // first find the rows with equal keys, then check the predicates on those pairs.
+            let join_tuples = if selected_left.len() == 1 {
+                selected_left[0].hash_join_inner(&selected_right[0])
+            } else {
+                let left = DataFrame::new_no_checks(to_physical(&selected_left));
+                let right = DataFrame::new_no_checks(to_physical(&selected_right));
+                inner_join_multiple_keys(&left, &right, false)
+            };
+            POOL.install(|| {
+                let mut join_tuples = join_tuples
+                    .into_par_iter()
+                    .filter(|(idx_left, idx_right)| {
+                        keys.iter()
+                            .all(|(keys, op)| keys.eval(*op, *idx_left, *idx_right))
+                    })
+                    .collect::<Vec<_>>();
+                // keep the order of the left table
+                join_tuples.par_sort_unstable();
+                join_tuples
+            })
+        };
+
+        let mut other = other.clone();
+        for s in &selected_right {
+            other = other.drop(s.name())?;
+        }
+
+        // Safety:
+        // join tuples are in bounds
+        let (df_left, df_right) = POOL.join(
+            || unsafe {
+                self.take_iter_unchecked(join_tuples.iter().map(|(left, _right)| *left as usize))
+            },
+            || unsafe {
+                other.take_iter_unchecked(join_tuples.iter().map(|(_left, right)| *right as usize))
+            },
+        );
+        self.finish_join(df_left, df_right, suffix)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::df;
+
+    #[test]
+    fn test_range_join() -> Result<()> {
+        let events = df![
+            "ts" => [1, 4, 5, 9, 12],
+            "event" => ["a", "b", "c", "d", "e"]
+        ]?;
+        let intervals = df![
+            "start" => [0, 4, 8],
+            "end" => [4, 9, 10],
+            "name" => ["x", "y", "z"]
+        ]?;
+        let predicates = [
+            InequalityPredicate::new("ts", InequalityOperator::GtEq, "start"),
+            InequalityPredicate::new("ts", InequalityOperator::Lt, "end"),
+        ];
+        let no_keys = Vec::<&str>::new();
+
+        let out = events.join_where(&intervals, &no_keys, &no_keys, &predicates, None)?;
+        assert_eq!(
+            out.get_column_names(),
+            &["ts", "event", "start", "end", "name"]
+        );
+        assert_eq!(
+            Vec::from(out.column("event")?.utf8()?),
+            &[Some("a"), Some("b"), Some("c"), Some("d")]
+        );
+        assert_eq!(
+            Vec::from(out.column("name")?.utf8()?),
+            &[Some("x"), Some("y"), Some("y"), Some("z")]
+        );
+
+        // combined with an equality key
+        let events = events.hstack(&[Series::new("key", &[1, 1, 2, 2, 2])])?;
+        let intervals = intervals.hstack(&[Series::new("key", &[1, 1, 2])])?;
+        let out = events.join_where(&intervals, "key", "key", &predicates, None)?;
+        assert_eq!(
+            Vec::from(out.column("event")?.utf8()?),
+            &[Some("a"), Some("b"), Some("d")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_join_bounds() -> Result<()> {
+        // overlapping intervals, with the bounds in the opposite order of the predicates
+        let left = df![
+            "ts" => [Some(5.0), None, Some(1.0), Some(f64::NAN), Some(3.0)]
+        ]?;
+        let right = df![
+            "start" => [Some(0.0), Some(2.0), Some(f64::NAN), Some(3.0), None],
+            "end" => [Some(4.0), Some(6.0), Some(9.0), Some(5.0), Some(9.0)],
+            "name" => ["x", "y", "nan", "z", "null"]
+        ]?;
+        let predicates = [
+            InequalityPredicate::new("ts", InequalityOperator::LtEq, "end"),
+            InequalityPredicate::new("ts", InequalityOperator::Gt, "start"),
+        ];
+        let no_keys = Vec::<&str>::new();
+        let out = left.join_where(&right, &no_keys, &no_keys, &predicates, None)?;
+        assert_eq!(
+            Vec::from(out.column("ts")?.f64()?),
+            &[Some(5.0), Some(5.0), Some(1.0), Some(3.0), Some(3.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("name")?.utf8()?),
+            &[Some("y"), Some("z"), Some("x"), Some("x"), Some("y")]
+        );
+
+        // a single predicate skips the NaN values
+        let out = left.join_where(&right, &no_keys, &no_keys, &predicates[1..], None)?;
+        assert_eq!(
+            Vec::from(out.column("name")?.utf8()?),
+            &[
+                Some("x"),
+                Some("y"),
+                Some("z"),
+                Some("x"),
+                Some("x"),
+                Some("y")
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_join_u64_out_of_range() -> Result<()> {
+        let left = df!["a" => [1u64, u64::MAX]]?;
+        let right = df!["b" => [0u64, 2]]?;
+        let predicates = [InequalityPredicate::new("a", InequalityOperator::Gt, "b")];
+        let no_keys = Vec::<&str>::new();
+        assert!(left
+            .join_where(&right, &no_keys, &no_keys, &predicates, None)
+            .is_err());
+
+        let left = df!["a" => [1u64, 3]]?;
+        let out = left.join_where(&right, &no_keys, &no_keys, &predicates, None)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.u64()?),
+            &[Some(1), Some(3), Some(3)]
+        );
+        Ok(())
+    }
+}
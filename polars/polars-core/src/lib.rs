@@ -31,8 +31,6 @@ use ahash::AHashMap;
 use lazy_static::lazy_static;
 use rayon::{ThreadPool, ThreadPoolBuilder};
 #[cfg(feature = "dtype-categorical")]
-use std::sync::atomic::{AtomicBool, Ordering};
-#[cfg(feature = "dtype-categorical")]
 use std::sync::{Mutex, MutexGuard};
 
 #[cfg(feature = "object")]
@@ -75,7 +73,8 @@ impl Default for SCacheInner {
 }
 
 /// Used by categorical data that need to share global categories.
-/// In *eager* you need to specifically toggle global string cache to have a global effect.
+/// In *eager* you need to specifically toggle the global string cache or hold a [`StringCacheGuard`]
+/// to have a global effect.
 /// In *lazy* it is toggled on at the start of a computation run and turned of (deleted) when a
 /// result is produced.
 #[cfg(feature = "dtype-categorical")]
@@ -84,7 +83,8 @@ pub(crate) struct StringCache(pub(crate) Mutex<SCacheInner>);
 #[cfg(feature = "dtype-categorical")]
 impl StringCache {
     pub(crate) fn lock_map(&self) -> MutexGuard<SCacheInner> {
-        self.0.lock().unwrap()
+        // a panic while holding the lock doesn't leave the map in an invalid state
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
     }
 
     pub(crate) fn clear(&self) {
@@ -100,11 +100,33 @@ impl Default for StringCache {
     }
 }
 
+/// Who is keeping the global string cache alive.
 #[cfg(feature = "dtype-categorical")]
-pub(crate) static USE_STRING_CACHE: AtomicBool = AtomicBool::new(false);
+#[derive(Default)]
+struct StringCacheUsers {
+    /// Number of live [`StringCacheGuard`]s.
+    guards: u32,
+    /// Set by [`toggle_string_cache`].
+    toggled: bool,
+}
+
+#[cfg(feature = "dtype-categorical")]
+impl StringCacheUsers {
+    fn in_use(&self) -> bool {
+        self.guards > 0 || self.toggled
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 lazy_static! {
     pub(crate) static ref STRING_CACHE: StringCache = Default::default();
+    static ref STRING_CACHE_USERS: Mutex<StringCacheUsers> = Default::default();
+}
+
+#[cfg(feature = "dtype-categorical")]
+fn string_cache_users() -> MutexGuard<'static, StringCacheUsers> {
+    // the counters are always valid, also if a thread panicked while holding the lock
+    STRING_CACHE_USERS.lock().unwrap_or_else(|e| e.into_inner())
 }
 
 #[cfg(test)]
@@ -114,20 +136,82 @@ lazy_static! {
     pub(crate) static ref SINGLE_LOCK: Mutex<()> = Mutex::new(());
 }
 
+/// Keeps the global string cache for the Categorical Types in use for as long as it lives.
+///
+/// Guards can be nested and may be held by multiple threads at the same time. The cache is
+/// only turned off (and deleted) when the last guard is dropped and the cache is not toggled
+/// on with [`toggle_string_cache`]. This makes it safe to use in concurrent computations,
+/// where a global toggle of one computation would turn off the cache of another.
+///
+/// The cache only grows while it is in use. If guards of overlapping computations keep it
+/// alive for a long time, it holds every string that was seen in that time. Use
+/// [`reset_string_cache`] to free it.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_core::StringCacheGuard;
+/// let _guard = StringCacheGuard::new();
+/// let a = Series::new("a", &["x", "y"]).cast(&DataType::Categorical)?;
+/// let b = Series::new("b", &["y", "z"]).cast(&DataType::Categorical)?;
+/// // `a` and `b` share the same categories
+/// # Ok::<(), PolarsError>(())
+/// ```
+#[cfg(feature = "dtype-categorical")]
+#[must_use = "the string cache is only used while the guard is alive"]
+pub struct StringCacheGuard {
+    // prevent construction without `new`
+    _private: (),
+}
+
+#[cfg(feature = "dtype-categorical")]
+impl StringCacheGuard {
+    pub fn new() -> Self {
+        string_cache_users().guards += 1;
+        StringCacheGuard { _private: () }
+    }
+}
+
+#[cfg(feature = "dtype-categorical")]
+impl Default for StringCacheGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "dtype-categorical")]
+impl Drop for StringCacheGuard {
+    fn drop(&mut self) {
+        let mut users = string_cache_users();
+        users.guards -= 1;
+        if !users.in_use() {
+            STRING_CACHE.clear()
+        }
+    }
+}
+
 /// Use a global string cache for the Categorical Types.
 ///
 /// This is used to cache the string categories locally.
 /// This allows join operations on categorical types.
+///
+/// Turning the toggle off only deletes the cache if no [`StringCacheGuard`] is alive.
+/// Prefer a [`StringCacheGuard`] if multiple computations run concurrently.
 #[cfg(feature = "dtype-categorical")]
 pub fn toggle_string_cache(toggle: bool) {
-    USE_STRING_CACHE.store(toggle, Ordering::Release);
-
-    if !toggle {
+    let mut users = string_cache_users();
+    users.toggled = toggle;
+    if !users.in_use() {
         STRING_CACHE.clear()
     }
 }
 
 /// Reset the global string cache used for the Categorical Types.
+///
+/// This also frees the cache if it is in use. Categoricals created before and after the
+/// reset don't share their categories anymore; they are re-mapped when they are compared,
+/// joined or appended.
 #[cfg(feature = "dtype-categorical")]
 pub fn reset_string_cache() {
     STRING_CACHE.clear()
@@ -136,5 +220,5 @@ pub fn reset_string_cache() {
 /// Check if string cache is set.
 #[cfg(feature = "dtype-categorical")]
 pub(crate) fn use_string_cache() -> bool {
-    USE_STRING_CACHE.load(Ordering::Acquire)
+    string_cache_users().in_use()
 }
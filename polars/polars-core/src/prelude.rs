@@ -18,7 +18,11 @@ pub use crate::{
     datatypes::*,
     df,
     error::{PolarsError, Result},
-    frame::{hash_join::JoinType, DataFrame},
+    frame::{
+        explode::MeltArgs,
+        hash_join::{JoinKeyOptions, JoinType, JoinValidation},
+        DataFrame,
+    },
     named_from::NamedFrom,
     series::{
         arithmetic::{LhsNumOps, NumOpsDispatch},
@@ -41,6 +45,16 @@ pub use crate::chunked_array::temporal::conversion::*;
 #[cfg(feature = "checked_arithmetic")]
 pub use crate::series::arithmetic::checked::NumOpsDispatchChecked;
 
+#[cfg(feature = "asof_join")]
+pub use crate::frame::asof_join::{AsOfOptions, AsofStrategy};
+#[cfg(feature = "cov")]
+pub use crate::frame::cov::CorrelationMethod;
+#[cfg(feature = "range_join")]
+pub use crate::frame::range_join::{InequalityOperator, InequalityPredicate};
+
+#[cfg(feature = "interpolate")]
+pub use crate::chunked_array::ops::interpolate::{InterpolateOptions, InterpolationMethod};
+
 #[cfg(feature = "rank")]
 pub use crate::chunked_array::ops::unique::rank::{RankMethod, RankOptions};
 
@@ -48,7 +62,9 @@ pub use crate::chunked_array::ops::unique::rank::{RankMethod, RankOptions};
 pub use crate::chunked_array::ops::rolling_window::RollingOptions;
 
 #[cfg(feature = "dynamic_groupby")]
-pub use polars_time::{groupby::ClosedWindow, Duration};
+pub use polars_time::groupby::ClosedWindow;
+#[cfg(feature = "temporal")]
+pub use polars_time::Duration;
 
 #[cfg(feature = "ewma")]
 pub use polars_arrow::kernels::ewm::EWMOptions;
@@ -1,4 +1,5 @@
-use serde::{Deserialize, Serialize};
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::prelude::*;
 
@@ -22,8 +23,10 @@ enum TimeUnitDef {
 }
 
 /// Intermediate enum. Needed because [crate::datatypes::DataType] has
-/// a &static str and thus requires Deserialize<&static>
+/// a &static str and thus requires Deserialize<&static>.
+/// Also used to (de)serialize [crate::datatypes::DataType] itself.
 #[derive(Serialize, Deserialize, Debug)]
+#[serde(rename = "DataType")]
 enum DeDataType<'a> {
     Boolean,
     UInt8,
@@ -41,38 +44,245 @@ enum DeDataType<'a> {
     Datetime,
     #[serde(with = "TimeUnitDef")]
     Time64(TimeUnit),
+    /// A list of which the inner type is only known from its values. This is how lists were
+    /// serialized before the inner type was written, and is kept to read that data.
     List,
     Object(&'a str),
     Null,
     Categorical,
+    // new variants are added at the end, so that the variant indices of binary formats
+    // like bincode don't change
+    Time,
+    #[serde(borrow)]
+    TypedList(Box<DeDataType<'a>>),
 }
 
 impl From<&DataType> for DeDataType<'_> {
     fn from(dt: &DataType) -> Self {
         match dt {
-            DataType::Int32 => DeDataType::Int32,
+            DataType::Boolean => DeDataType::Boolean,
+            DataType::UInt8 => DeDataType::UInt8,
+            DataType::UInt16 => DeDataType::UInt16,
             DataType::UInt32 => DeDataType::UInt32,
-            DataType::Int64 => DeDataType::Int64,
             DataType::UInt64 => DeDataType::UInt64,
-            DataType::Date => DeDataType::Date,
-            DataType::Datetime => DeDataType::Datetime,
+            DataType::Int8 => DeDataType::Int8,
+            DataType::Int16 => DeDataType::Int16,
+            DataType::Int32 => DeDataType::Int32,
+            DataType::Int64 => DeDataType::Int64,
             DataType::Float32 => DeDataType::Float32,
             DataType::Float64 => DeDataType::Float64,
             DataType::Utf8 => DeDataType::Utf8,
-            DataType::Boolean => DeDataType::Boolean,
-            DataType::Null => DeDataType::Null,
-            DataType::List(_) => DeDataType::List,
+            DataType::Date => DeDataType::Date,
+            DataType::Datetime => DeDataType::Datetime,
+            DataType::Time => DeDataType::Time,
+            DataType::List(inner) => DeDataType::TypedList(Box::new(inner.as_ref().into())),
             #[cfg(feature = "object")]
             DataType::Object(s) => DeDataType::Object(s),
-            _ => unimplemented!(),
+            DataType::Null => DeDataType::Null,
+            DataType::Categorical => DeDataType::Categorical,
         }
     }
 }
 
+impl DeDataType<'_> {
+    fn into_dtype(self) -> std::result::Result<DataType, String> {
+        let dt = match self {
+            DeDataType::Boolean => DataType::Boolean,
+            DeDataType::UInt8 => DataType::UInt8,
+            DeDataType::UInt16 => DataType::UInt16,
+            DeDataType::UInt32 => DataType::UInt32,
+            DeDataType::UInt64 => DataType::UInt64,
+            DeDataType::Int8 => DataType::Int8,
+            DeDataType::Int16 => DataType::Int16,
+            DeDataType::Int32 => DataType::Int32,
+            DeDataType::Int64 => DataType::Int64,
+            DeDataType::Float32 => DataType::Float32,
+            DeDataType::Float64 => DataType::Float64,
+            DeDataType::Utf8 => DataType::Utf8,
+            DeDataType::Date => DataType::Date,
+            DeDataType::Datetime => DataType::Datetime,
+            DeDataType::Time | DeDataType::Time64(_) => DataType::Time,
+            DeDataType::TypedList(inner) => DataType::List(Box::new(inner.into_dtype()?)),
+            DeDataType::List => {
+                return Err("cannot deserialize a list data type without its inner type".into())
+            }
+            // the name of an object type is a static string that we cannot create
+            DeDataType::Object(name) => {
+                return Err(format!(
+                    "cannot deserialize the data type Object({}) of an object column",
+                    name
+                ))
+            }
+            DeDataType::Null => DataType::Null,
+            DeDataType::Categorical => DataType::Categorical,
+        };
+        Ok(dt)
+    }
+}
+
+impl Serialize for DataType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        DeDataType::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DataType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        DeDataType::deserialize(deserializer)?
+            .into_dtype()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// (De)serialize an optional scalar `AnyValue`, e.g. the tolerance of an asof join.
+/// Only numeric and boolean values are supported.
+#[cfg(feature = "serde-lazy")]
+pub(crate) mod scalar {
+    use super::*;
+    use serde::ser::Error as SerError;
+
+    #[derive(Serialize, Deserialize)]
+    enum Scalar {
+        Null,
+        Boolean(bool),
+        UInt8(u8),
+        UInt16(u16),
+        UInt32(u32),
+        UInt64(u64),
+        Int8(i8),
+        Int16(i16),
+        Int32(i32),
+        Int64(i64),
+        Float32(f32),
+        Float64(f64),
+        Date(i32),
+        Datetime(i64),
+        Time(i64),
+    }
+
+    pub(crate) fn serialize<S>(
+        value: &Option<AnyValue<'static>>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let scalar = match value {
+            None => None,
+            Some(av) => Some(match av {
+                AnyValue::Null => Scalar::Null,
+                AnyValue::Boolean(v) => Scalar::Boolean(*v),
+                AnyValue::UInt8(v) => Scalar::UInt8(*v),
+                AnyValue::UInt16(v) => Scalar::UInt16(*v),
+                AnyValue::UInt32(v) => Scalar::UInt32(*v),
+                AnyValue::UInt64(v) => Scalar::UInt64(*v),
+                AnyValue::Int8(v) => Scalar::Int8(*v),
+                AnyValue::Int16(v) => Scalar::Int16(*v),
+                AnyValue::Int32(v) => Scalar::Int32(*v),
+                AnyValue::Int64(v) => Scalar::Int64(*v),
+                AnyValue::Float32(v) => Scalar::Float32(*v),
+                AnyValue::Float64(v) => Scalar::Float64(*v),
+                #[cfg(feature = "dtype-date")]
+                AnyValue::Date(v) => Scalar::Date(*v),
+                #[cfg(feature = "dtype-datetime")]
+                AnyValue::Datetime(v) => Scalar::Datetime(*v),
+                #[cfg(feature = "dtype-time")]
+                AnyValue::Time(v) => Scalar::Time(*v),
+                av => {
+                    return Err(S::Error::custom(format!(
+                        "cannot serialize the non-numeric scalar {:?}",
+                        av
+                    )))
+                }
+            }),
+        };
+        scalar.serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> std::result::Result<Option<AnyValue<'static>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let scalar = Option::<Scalar>::deserialize(deserializer)?;
+        let av = match scalar {
+            None => return Ok(None),
+            Some(Scalar::Null) => AnyValue::Null,
+            Some(Scalar::Boolean(v)) => AnyValue::Boolean(v),
+            Some(Scalar::UInt8(v)) => AnyValue::UInt8(v),
+            Some(Scalar::UInt16(v)) => AnyValue::UInt16(v),
+            Some(Scalar::UInt32(v)) => AnyValue::UInt32(v),
+            Some(Scalar::UInt64(v)) => AnyValue::UInt64(v),
+            Some(Scalar::Int8(v)) => AnyValue::Int8(v),
+            Some(Scalar::Int16(v)) => AnyValue::Int16(v),
+            Some(Scalar::Int32(v)) => AnyValue::Int32(v),
+            Some(Scalar::Int64(v)) => AnyValue::Int64(v),
+            Some(Scalar::Float32(v)) => AnyValue::Float32(v),
+            Some(Scalar::Float64(v)) => AnyValue::Float64(v),
+            #[cfg(feature = "dtype-date")]
+            Some(Scalar::Date(v)) => AnyValue::Date(v),
+            #[cfg(feature = "dtype-datetime")]
+            Some(Scalar::Datetime(v)) => AnyValue::Datetime(v),
+            #[cfg(feature = "dtype-time")]
+            Some(Scalar::Time(v)) => AnyValue::Time(v),
+            #[allow(unreachable_patterns)]
+            Some(_) => {
+                return Err(D::Error::custom(
+                    "the data type of the scalar is not enabled in this build",
+                ))
+            }
+        };
+        Ok(Some(av))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_serde_dtype() {
+        let dtypes = [
+            DataType::Int64,
+            DataType::Utf8,
+            DataType::Datetime,
+            DataType::Time,
+            DataType::List(Box::new(DataType::List(Box::new(DataType::Float32)))),
+        ];
+        for dt in dtypes {
+            let json = serde_json::to_string(&dt).unwrap();
+            let out = serde_json::from_str::<DataType>(&json).unwrap();
+            assert_eq!(out, dt);
+        }
+        // the variant indices of the data types that existed before are unchanged
+        assert_eq!(
+            bincode::serialize(&DataType::Categorical).unwrap(),
+            18u32.to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn test_serde_legacy_list() {
+        // a list Series as it was serialized before the inner type was written
+        let json = r#"{"name":"list","datatype":"List","values":[{"name":"","datatype":"Int32","values":[1,2]},null,{"name":"","datatype":"Int32","values":[3]}]}"#;
+        let out = serde_json::from_str::<Series>(json).unwrap();
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int32)));
+        assert_eq!(out.len(), 3);
+        assert!(out.list().unwrap().get(1).is_none());
+
+        // and it round-trips in the new format
+        let json = serde_json::to_string(&out).unwrap();
+        let out2 = serde_json::from_str::<Series>(&json).unwrap();
+        assert!(out.series_equal_missing(&out2));
+    }
+
     #[test]
     fn test_serde() -> Result<()> {
         let ca = UInt32Chunked::new("foo", &[Some(1), None, Some(2)]);
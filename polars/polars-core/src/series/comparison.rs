@@ -2,6 +2,8 @@
 
 use super::Series;
 use crate::apply_method_numeric_series;
+#[cfg(feature = "dtype-categorical")]
+use crate::chunked_array::categorical::remap_categoricals;
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
 use std::ops::Deref;
@@ -35,10 +37,13 @@ macro_rules! impl_compare {
                 .$method(rhs.datetime().unwrap().deref()),
             DataType::List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical => lhs
-                .categorical()
-                .unwrap()
-                .$method(rhs.categorical().unwrap().deref()),
+            DataType::Categorical => {
+                // compare the categories under a common rev map
+                let (lhs, rhs) = remap_categoricals(lhs, rhs);
+                lhs.categorical()
+                    .unwrap()
+                    .$method(rhs.categorical().unwrap().deref())
+            }
 
             _ => unimplemented!(),
         }
@@ -142,6 +142,10 @@ impl private::PrivateSeries for SeriesWrap<BooleanChunked> {
     fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
         HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref())
     }
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, other: &Series, anti: bool) -> Vec<u32> {
+        HashJoin::hash_join_semi_anti(&self.0, other.as_ref().as_ref(), anti)
+    }
     fn zip_outer_join_column(
         &self,
         right_column: &Series,
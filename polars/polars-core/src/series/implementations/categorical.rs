@@ -121,6 +121,10 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
     fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
         HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref())
     }
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, other: &Series, anti: bool) -> Vec<u32> {
+        HashJoin::hash_join_semi_anti(&self.0, other.as_ref().as_ref(), anti)
+    }
     fn zip_outer_join_column(
         &self,
         right_column: &Series,
@@ -128,9 +132,16 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
     ) -> Series {
         let ca = self.0.deref();
         let right = right_column.cast(&DataType::UInt32).unwrap();
-        ZipOuterJoinColumn::zip_outer_join_column(ca, &right, opt_join_tuples)
+        let out = ZipOuterJoinColumn::zip_outer_join_column(ca, &right, opt_join_tuples)
             .cast(&DataType::Categorical)
-            .unwrap()
+            .unwrap();
+        // the categories of both columns are in the rev map
+        let mut out = out.categorical().unwrap().clone();
+        out.set_categorical_map(
+            self.0
+                .merge_categorical_map(right_column.categorical().unwrap()),
+        );
+        out.into_series()
     }
     fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
         IntoGroupTuples::group_tuples(&self.0, multithreaded)
@@ -273,10 +284,6 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         self.0.cast(data_type)
     }
 
-    fn value_counts(&self) -> Result<DataFrame> {
-        ChunkUnique::value_counts(&self.0)
-    }
-
     fn get(&self, index: usize) -> AnyValue {
         self.0.get_any_value(index)
     }
@@ -65,9 +65,9 @@ macro_rules! impl_dyn_series {
             }
 
             #[cfg(feature = "asof_join")]
-            fn join_asof(&self, other: &Series) -> Result<Vec<Option<u32>>> {
+            fn join_asof(&self, other: &Series, options: &AsOfOptions) -> Result<Vec<Option<u32>>> {
                 let other = other.to_physical_repr();
-                self.0.deref().join_asof(&other)
+                self.0.deref().join_asof(&other, options)
             }
 
             fn set_sorted(&mut self, reverse: bool) {
@@ -204,6 +204,11 @@ macro_rules! impl_dyn_series {
                 let other = other.to_physical_repr().into_owned();
                 self.0.hash_join_outer(&other.as_ref().as_ref())
             }
+            #[cfg(feature = "semi_anti_join")]
+            fn hash_join_semi_anti(&self, other: &Series, anti: bool) -> Vec<u32> {
+                let other = other.to_physical_repr().into_owned();
+                self.0.hash_join_semi_anti(&other.as_ref().as_ref(), anti)
+            }
             fn zip_outer_join_column(
                 &self,
                 right_column: &Series,
@@ -459,10 +464,6 @@ macro_rules! impl_dyn_series {
                 self.0.to_dummies()
             }
 
-            fn value_counts(&self) -> Result<DataFrame> {
-                self.0.value_counts()
-            }
-
             fn get(&self, index: usize) -> AnyValue {
                 self.0.get_any_value(index)
             }
@@ -91,8 +91,8 @@ macro_rules! impl_dyn_series {
             }
 
             #[cfg(feature = "asof_join")]
-            fn join_asof(&self, other: &Series) -> Result<Vec<Option<u32>>> {
-                self.0.join_asof(other)
+            fn join_asof(&self, other: &Series, options: &AsOfOptions) -> Result<Vec<Option<u32>>> {
+                self.0.join_asof(other, options)
             }
 
             fn set_sorted(&mut self, reverse: bool) {
@@ -214,6 +214,10 @@ macro_rules! impl_dyn_series {
             fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
                 HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref())
             }
+            #[cfg(feature = "semi_anti_join")]
+            fn hash_join_semi_anti(&self, other: &Series, anti: bool) -> Vec<u32> {
+                HashJoin::hash_join_semi_anti(&self.0, other.as_ref().as_ref(), anti)
+            }
             fn zip_outer_join_column(
                 &self,
                 right_column: &Series,
@@ -443,10 +447,6 @@ macro_rules! impl_dyn_series {
                 ToDummies::to_dummies(&self.0)
             }
 
-            fn value_counts(&self) -> Result<DataFrame> {
-                ChunkUnique::value_counts(&self.0)
-            }
-
             fn get(&self, index: usize) -> AnyValue {
                 self.0.get_any_value(index)
             }
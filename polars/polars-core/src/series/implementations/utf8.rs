@@ -116,6 +116,10 @@ impl private::PrivateSeries for SeriesWrap<Utf8Chunked> {
     fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
         HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref())
     }
+    #[cfg(feature = "semi_anti_join")]
+    fn hash_join_semi_anti(&self, other: &Series, anti: bool) -> Vec<u32> {
+        HashJoin::hash_join_semi_anti(&self.0, other.as_ref().as_ref(), anti)
+    }
     fn zip_outer_join_column(
         &self,
         right_column: &Series,
@@ -285,10 +289,6 @@ impl SeriesTrait for SeriesWrap<Utf8Chunked> {
         ToDummies::to_dummies(&self.0)
     }
 
-    fn value_counts(&self) -> Result<DataFrame> {
-        ChunkUnique::value_counts(&self.0)
-    }
-
     fn get(&self, index: usize) -> AnyValue {
         self.0.get_any_value(index)
     }
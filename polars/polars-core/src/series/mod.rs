@@ -461,6 +461,7 @@ impl Series {
     #[cfg(feature = "round_series")]
     #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
     /// Floor underlying floating point array to the lowest integers smaller or equal to the float value.
+    /// Integers are returned unchanged.
     pub fn floor(&self) -> Result<Self> {
         if let Ok(ca) = self.f32() {
             let s = ca.apply(|val| val.floor()).into_series();
@@ -470,6 +471,50 @@ impl Series {
             let s = ca.apply(|val| val.floor()).into_series();
             return Ok(s);
         }
+        if matches!(
+            self.dtype(),
+            DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+        ) {
+            return Ok(self.clone());
+        }
+        Err(PolarsError::SchemaMisMatch(
+            format!("{:?} is not a floating point datatype", self.dtype()).into(),
+        ))
+    }
+
+    #[cfg(feature = "round_series")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    /// Ceil underlying floating point array to the smallest integers greater or equal to the float value.
+    /// Integers are returned unchanged.
+    pub fn ceil(&self) -> Result<Self> {
+        if let Ok(ca) = self.f32() {
+            let s = ca.apply(|val| val.ceil()).into_series();
+            return Ok(s);
+        }
+        if let Ok(ca) = self.f64() {
+            let s = ca.apply(|val| val.ceil()).into_series();
+            return Ok(s);
+        }
+        if matches!(
+            self.dtype(),
+            DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+        ) {
+            return Ok(self.clone());
+        }
         Err(PolarsError::SchemaMisMatch(
             format!("{:?} is not a floating point datatype", self.dtype()).into(),
         ))
@@ -887,5 +932,14 @@ mod test {
         let out = series.round(2).unwrap();
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
+
+        let series = Series::new("a", &[1.5, -1.5]);
+        let out = series.ceil().unwrap();
+        assert_eq!(Vec::from(out.f64().unwrap()), &[Some(2.0), Some(-1.0)]);
+
+        let series = Series::new("a", &[1i32, -2]);
+        assert!(series.ceil().unwrap().series_equal(&series));
+        assert!(series.floor().unwrap().series_equal(&series));
+        assert!(Series::new("a", &["a"]).ceil().is_err());
     }
 }
@@ -0,0 +1,284 @@
+use crate::prelude::*;
+
+fn check_breaks(breaks: &[f64]) -> Result<()> {
+    if breaks.iter().any(|b| b.is_nan()) || breaks.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(PolarsError::ValueError(
+            "the breaks should be strictly increasing and not NaN".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Index of the interval `(breaks[i - 1], breaks[i]]` that contains `v`.
+fn bin_index(breaks: &[f64], v: f64) -> usize {
+    breaks.partition_point(|b| *b < v)
+}
+
+fn interval_label(lower: f64, upper: f64) -> String {
+    format!("({}, {}]", lower, upper)
+}
+
+/// Labels of the intervals between `lower` and the `upper` edges.
+fn interval_labels(lower: f64, upper: &[f64]) -> Vec<String> {
+    std::iter::once(lower)
+        .chain(upper.iter().copied())
+        .zip(upper)
+        .map(|(l, u)| interval_label(l, *u))
+        .collect()
+}
+
+impl Series {
+    /// Bin the values into the intervals `(-inf, breaks[0]]`, `(breaks[0], breaks[1]]`, ...,
+    /// `(breaks[n - 1], inf]`.
+    ///
+    /// Returns a categorical `Series` of the interval labels, or of the given `labels`, one
+    /// for every interval. Null and NaN values stay null.
+    pub fn cut(&self, breaks: &[f64], labels: Option<&[&str]>) -> Result<Series> {
+        self.cut_utf8(breaks, labels)?
+            .into_series()
+            .cast(&DataType::Categorical)
+    }
+
+    /// Like [cut](Series::cut), but the labels are returned as `Utf8`.
+    pub fn cut_utf8(&self, breaks: &[f64], labels: Option<&[&str]>) -> Result<Utf8Chunked> {
+        check_breaks(breaks)?;
+        let labels = match labels {
+            Some(labels) => {
+                if labels.len() != breaks.len() + 1 {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "expected {} labels for {} breaks, got {}",
+                            breaks.len() + 1,
+                            breaks.len(),
+                            labels.len()
+                        )
+                        .into(),
+                    ));
+                }
+                labels.iter().map(|l| l.to_string()).collect()
+            }
+            None => {
+                let mut upper = breaks.to_vec();
+                upper.push(f64::INFINITY);
+                interval_labels(f64::NEG_INFINITY, &upper)
+            }
+        };
+
+        let s = self.cast(&DataType::Float64)?;
+        let ca = s.f64()?;
+        let mut out: Utf8Chunked = ca
+            .into_iter()
+            .map(|opt_v| {
+                opt_v
+                    .filter(|v| !v.is_nan())
+                    .map(|v| labels[bin_index(breaks, v)].as_str())
+            })
+            .collect();
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Bin the values into intervals bounded by the given `quantiles` of the data. Equal
+    /// quantiles are merged into a single break. See [cut](Series::cut).
+    pub fn qcut(&self, quantiles: &[f64], labels: Option<&[&str]>) -> Result<Series> {
+        self.qcut_utf8(quantiles, labels)?
+            .into_series()
+            .cast(&DataType::Categorical)
+    }
+
+    /// Like [qcut](Series::qcut), but the labels are returned as `Utf8`.
+    pub fn qcut_utf8(&self, quantiles: &[f64], labels: Option<&[&str]>) -> Result<Utf8Chunked> {
+        if quantiles.iter().any(|q| !(0.0..=1.0).contains(q)) {
+            return Err(PolarsError::ValueError(
+                "quantiles should be between 0.0 and 1.0".into(),
+            ));
+        }
+        let s = self.cast(&DataType::Float64)?;
+        // NaN values are not binned, so they don't determine the breaks either
+        let s: Float64Chunked = s
+            .f64()?
+            .into_iter()
+            .map(|opt_v| opt_v.filter(|v| !v.is_nan()))
+            .collect();
+        let mut breaks = Vec::with_capacity(quantiles.len());
+        for q in quantiles {
+            // all values are null if the quantile is missing
+            if let Some(v) = s.quantile(*q, QuantileInterpolOptions::Linear)? {
+                breaks.push(v)
+            }
+        }
+        // the NaN values are filtered out
+        breaks.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        breaks.dedup();
+        self.cut_utf8(&breaks, labels)
+    }
+
+    /// Count the values in histogram bins.
+    ///
+    /// Either the bin edges are given by `bins`, which defines the same intervals as
+    /// [cut](Series::cut), or `bin_count` equal-width bins between the minimum and the maximum
+    /// are created, where the first bin includes the minimum.
+    ///
+    /// Returns a `DataFrame` with the upper edge of every bin as `"break_point"`, its label as
+    /// `"category"` and the number of values in the bin as `"count"`.
+    pub fn hist(&self, bins: Option<&[f64]>, bin_count: Option<usize>) -> Result<DataFrame> {
+        let s = self.cast(&DataType::Float64)?;
+        let ca = s.f64()?;
+
+        let (lower, breaks) = match (bins, bin_count) {
+            (Some(bins), None) => {
+                check_breaks(bins)?;
+                let mut breaks = bins.to_vec();
+                breaks.push(f64::INFINITY);
+                (f64::NEG_INFINITY, breaks)
+            }
+            (None, Some(bin_count)) => {
+                if bin_count == 0 {
+                    return Err(PolarsError::ValueError(
+                        "bin_count should be larger than 0".into(),
+                    ));
+                }
+                match (ca.min(), ca.max()) {
+                    (Some(min), Some(max)) => {
+                        // widen an empty range, otherwise the breaks are not increasing
+                        let (min, max) = if min == max {
+                            (min - 0.5, max + 0.5)
+                        } else {
+                            (min, max)
+                        };
+                        let width = (max - min) / bin_count as f64;
+                        let breaks = (1..=bin_count)
+                            .map(|i| {
+                                if i == bin_count {
+                                    max
+                                } else {
+                                    min + width * i as f64
+                                }
+                            })
+                            .collect();
+                        (min, breaks)
+                    }
+                    _ => (f64::NEG_INFINITY, vec![]),
+                }
+            }
+            _ => {
+                return Err(PolarsError::ValueError(
+                    "either bins or bin_count should be given".into(),
+                ))
+            }
+        };
+
+        let mut counts = vec![0u32; breaks.len()];
+        if !breaks.is_empty() {
+            ca.into_iter()
+                .flatten()
+                .filter(|v| !v.is_nan())
+                .for_each(|v| counts[bin_index(&breaks, v)] += 1);
+        }
+
+        let category =
+            Utf8Chunked::new_from_iter("category", interval_labels(lower, &breaks).into_iter())
+                .into_series()
+                .cast(&DataType::Categorical)?;
+        DataFrame::new(vec![
+            Float64Chunked::new_from_slice("break_point", &breaks).into_series(),
+            category,
+            UInt32Chunked::new_from_slice("count", &counts).into_series(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cut_qcut() -> Result<()> {
+        let s = Series::new("a", &[Some(1i32), Some(3), None, Some(5), Some(8)]);
+
+        let out = s.cut(&[3.0, 6.0], None)?;
+        assert_eq!(out.dtype(), &DataType::Categorical);
+        let out = out.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[
+                Some("(-inf, 3]"),
+                Some("(-inf, 3]"),
+                None,
+                Some("(3, 6]"),
+                Some("(6, inf]")
+            ]
+        );
+
+        let out = s
+            .cut(&[3.0], Some(&["low", "high"]))?
+            .cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("low"), Some("low"), None, Some("high"), Some("high")]
+        );
+        assert!(s.cut(&[3.0], Some(&["low"])).is_err());
+        assert!(s.cut(&[3.0, 1.0], None).is_err());
+
+        // the median is 4.0
+        let out = s
+            .qcut(&[0.5], Some(&["low", "high"]))?
+            .cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("low"), Some("low"), None, Some("high"), Some("high")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_hist() -> Result<()> {
+        let s = Series::new("a", &[1.0f64, 2.0, 2.5, 4.0, 5.0]);
+
+        let out = s.hist(None, Some(2))?;
+        assert_eq!(
+            Vec::from(out.column("break_point")?.f64()?),
+            &[Some(3.0), Some(5.0)]
+        );
+        assert_eq!(Vec::from(out.column("count")?.u32()?), &[Some(3), Some(2)]);
+
+        let out = s.hist(Some(&[2.0]), None)?;
+        assert_eq!(Vec::from(out.column("count")?.u32()?), &[Some(2), Some(3)]);
+        let category = out.column("category")?.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(category.utf8()?),
+            &[Some("(-inf, 2]"), Some("(2, inf]")]
+        );
+        assert!(s.hist(None, None).is_err());
+
+        // a single distinct value gets a range of width one
+        let s = Series::new("a", &[2.0f64, 2.0]);
+        let out = s.hist(None, Some(2))?;
+        assert_eq!(
+            Vec::from(out.column("break_point")?.f64()?),
+            &[Some(2.0), Some(2.5)]
+        );
+        assert_eq!(Vec::from(out.column("count")?.u32()?), &[Some(2), Some(0)]);
+        let category = out.column("category")?.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(category.utf8()?),
+            &[Some("(1.5, 2]"), Some("(2, 2.5]")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_qcut_missing_values() -> Result<()> {
+        let s = Series::new("a", &[None::<f64>, None]);
+        let out = s.qcut(&[0.5], None)?;
+        assert_eq!(out.null_count(), 2);
+
+        let s = Series::new("a", &[1.0, f64::NAN, 3.0]);
+        let out = s.qcut(&[0.5], None)?.cast(&DataType::Utf8)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("(-inf, 2]"), None, Some("(2, inf]")]
+        );
+        Ok(())
+    }
+}
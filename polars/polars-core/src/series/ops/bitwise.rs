@@ -0,0 +1,139 @@
+use crate::prelude::*;
+use std::ops::Not;
+
+/// Dispatch a method of the integer `ChunkedArray`s over the integer dtypes.
+macro_rules! apply_integer_method {
+    ($s:expr, $method:ident $(, $args:expr)*) => {{
+        match $s.dtype() {
+            #[cfg(feature = "dtype-u8")]
+            DataType::UInt8 => $s.u8().unwrap().$method($($args),*).into_series(),
+            #[cfg(feature = "dtype-u16")]
+            DataType::UInt16 => $s.u16().unwrap().$method($($args),*).into_series(),
+            DataType::UInt32 => $s.u32().unwrap().$method($($args),*).into_series(),
+            DataType::UInt64 => $s.u64().unwrap().$method($($args),*).into_series(),
+            #[cfg(feature = "dtype-i8")]
+            DataType::Int8 => $s.i8().unwrap().$method($($args),*).into_series(),
+            #[cfg(feature = "dtype-i16")]
+            DataType::Int16 => $s.i16().unwrap().$method($($args),*).into_series(),
+            DataType::Int32 => $s.i32().unwrap().$method($($args),*).into_series(),
+            DataType::Int64 => $s.i64().unwrap().$method($($args),*).into_series(),
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!(
+                        "{} not supported for dtype {:?}, expected an integer dtype",
+                        stringify!($method),
+                        dt
+                    )
+                    .into(),
+                ))
+            }
+        }
+    }};
+}
+
+impl Series {
+    /// Flip all bits of an integer `Series`.
+    pub fn bit_not(&self) -> Result<Series> {
+        Ok(apply_integer_method!(self, not))
+    }
+
+    fn shift_amount(&self, n: &Series) -> Result<UInt32Chunked> {
+        if n.len() != 1 && n.len() != self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "the shift amount should have length 1 or the length of the Series".into(),
+            ));
+        }
+        let n = n.cast(&DataType::UInt32)?;
+        Ok(n.u32()?.clone())
+    }
+
+    /// Shift the bits of an integer `Series` to the left by `n` positions. `n` is cast
+    /// to `UInt32` and may have length 1.
+    pub fn shift_left(&self, n: &Series) -> Result<Series> {
+        let n = self.shift_amount(n)?;
+        Ok(apply_integer_method!(self, shift_left, &n))
+    }
+
+    /// Shift the bits of an integer `Series` to the right by `n` positions. Signed
+    /// integers are shifted arithmetically. `n` is cast to `UInt32` and may have length 1.
+    pub fn shift_right(&self, n: &Series) -> Result<Series> {
+        let n = self.shift_amount(n)?;
+        Ok(apply_integer_method!(self, shift_right, &n))
+    }
+
+    /// Count the set bits of every value of an integer `Series`.
+    pub fn count_ones(&self) -> Result<Series> {
+        Ok(apply_integer_method!(self, count_ones))
+    }
+
+    /// Count the unset bits of every value of an integer `Series`.
+    pub fn count_zeros(&self) -> Result<Series> {
+        Ok(apply_integer_method!(self, count_zeros))
+    }
+
+    /// Count the leading unset bits of every value of an integer `Series`.
+    pub fn leading_zeros(&self) -> Result<Series> {
+        Ok(apply_integer_method!(self, leading_zeros))
+    }
+
+    /// Count the trailing unset bits of every value of an integer `Series`.
+    pub fn trailing_zeros(&self) -> Result<Series> {
+        Ok(apply_integer_method!(self, trailing_zeros))
+    }
+
+    /// Check if bit `n`, counted from the least significant bit, is set.
+    pub fn is_bit_set(&self, n: u32) -> Result<Series> {
+        Ok(apply_integer_method!(self, is_bit_set, n))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bitwise_integers() -> Result<()> {
+        let s = Series::new("a", &[Some(0b1010i32), None, Some(-1), Some(1)]);
+        let mask = Series::new("mask", &[0b0110i32]);
+
+        let out = s.bitand(&mask)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(0b0010), None, Some(0b0110), Some(0)]
+        );
+        let out = s.bitxor(&mask)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(0b1100), None, Some(!0b0110), Some(0b0111)]
+        );
+        let out = s.bit_not()?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(!0b1010), None, Some(0), Some(-2)]
+        );
+
+        let out = s.shift_left(&Series::new("n", &[1u32]))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(0b10100), None, Some(-2), Some(2)]
+        );
+        let out = s.shift_right(&Series::new("n", &[1u32, 1, 40, 40]))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(0b101), None, Some(-1), Some(0)]
+        );
+
+        let out = s.count_ones()?;
+        assert_eq!(Vec::from(out.u32()?), &[Some(2), None, Some(32), Some(1)]);
+        let out = s.leading_zeros()?;
+        assert_eq!(Vec::from(out.u32()?), &[Some(28), None, Some(0), Some(31)]);
+        let out = s.is_bit_set(1)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[Some(true), None, Some(true), Some(false)]
+        );
+
+        assert!(Series::new("f", &[1.0f64]).count_ones().is_err());
+        Ok(())
+    }
+}
@@ -1,189 +1,345 @@
 use crate::prelude::*;
 use arrow::bitmap::MutableBitmap;
 use arrow::types::NativeType;
+use num::Float;
 pub use polars_arrow::kernels::ewm::EWMOptions;
 use polars_arrow::kernels::ewm::{
-    ewm_std, ewm_var, ewma, ewma_inf_hist_no_nulls, ewma_inf_hists, ewma_no_nulls,
+    ewm_cov, ewm_mean, ewm_var, ewma_inf_hist_no_nulls, ewma_no_nulls,
 };
 use polars_arrow::prelude::FromData;
-use polars_utils::mem::to_mutable_slice;
-use std::convert::TryFrom;
-
-fn prepare_primitive_array<T: NativeType>(
-    vals: Vec<T>,
-    min_periods: usize,
-    leading_nulls: usize,
-) -> PrimitiveArray<T> {
-    let leading = std::cmp::max(min_periods, leading_nulls);
-    if leading > 1 {
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+/// The ewm functions return `Float32` for `Float32` input and `Float64` otherwise.
+fn ewm_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    }
+}
+
+fn ewm_pair_dtype(a: &DataType, b: &DataType) -> DataType {
+    match (a, b) {
+        (DataType::Float32, DataType::Float32) => DataType::Float32,
+        _ => DataType::Float64,
+    }
+}
+
+/// Mask the values before `min_periods` observations are seen.
+fn mask_min_periods<T: NativeType>(vals: Vec<T>, min_periods: usize) -> PrimitiveArray<T> {
+    let leading = std::cmp::min(min_periods.saturating_sub(1), vals.len());
+    let validity = if leading > 0 {
         let mut validity = MutableBitmap::with_capacity(vals.len());
-        validity.extend_constant(min_periods, false);
-        validity.extend_constant(vals.len() - min_periods, true);
+        validity.extend_constant(leading, false);
+        validity.extend_constant(vals.len() - leading, true);
+        Some(validity.into())
+    } else {
+        None
+    };
+    PrimitiveArray::from_data_default(vals.into(), validity)
+}
 
-        PrimitiveArray::from_data_default(vals.into(), Some(validity.into()))
+fn ewm_mean_ca<T>(
+    ca: &ChunkedArray<T>,
+    options: &EWMOptions,
+    deltas: Option<&[T::Native]>,
+) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float + AddAssign + Debug,
+{
+    if ca.null_count() > 0 || deltas.is_some() {
+        return ewm_mean(ca.into_iter(), options, deltas)
+            .into_iter()
+            .collect();
+    }
+    let alpha = T::Native::from(options.alpha).unwrap();
+    let out = if options.adjust {
+        ewma_no_nulls(ca.into_no_null_iter(), alpha)
     } else {
-        PrimitiveArray::from_data_default(vals.into(), None)
+        ewma_inf_hist_no_nulls(ca.into_no_null_iter(), alpha)
+    };
+    let arr = mask_min_periods(out, options.min_periods);
+    ChunkedArray::new_from_chunks(ca.name(), vec![Arc::new(arr) as ArrayRef])
+}
+
+/// Null values stay null in the output.
+fn ewm_var_ca<T>(ca: &ChunkedArray<T>, options: &EWMOptions) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float + AddAssign,
+{
+    if ca.null_count() > 0 {
+        return ewm_cov(ca.into_iter().map(|v| (v, v)), options, None)
+            .into_iter()
+            .zip(ca.into_iter())
+            .map(|(var, v)| v.and(var))
+            .collect();
     }
+    let ca = ca.rechunk();
+    let arr = ewm_var(ca.cont_slice().unwrap(), options);
+    ChunkedArray::new_from_chunks(ca.name(), vec![Arc::new(arr) as ArrayRef])
 }
 
 impl Series {
-    pub fn ewm_mean(&self, options: EWMOptions) -> Result<Self> {
-        match (self.dtype(), self.null_count()) {
-            (DataType::Float32, 0) => {
-                let ca = self.f32().unwrap();
-                match self.n_chunks() {
-                    1 => {
-                        let vals = ca.downcast_iter().next().unwrap();
-                        let vals = vals.values().as_slice();
-                        let out = if options.adjust {
-                            ewma_no_nulls(vals.iter().copied(), options.alpha as f32)
-                        } else {
-                            ewma_inf_hist_no_nulls(vals.iter().copied(), options.alpha as f32)
-                        };
-                        let arr = prepare_primitive_array(out, options.min_periods, 0);
-                        Series::try_from((self.name(), Arc::new(arr) as ArrayRef))
-                    }
-                    _ => {
-                        let iter = ca.into_no_null_iter();
-                        let out = if options.adjust {
-                            ewma_no_nulls(iter, options.alpha as f32)
-                        } else {
-                            ewma_inf_hist_no_nulls(iter, options.alpha as f32)
-                        };
-                        let arr = prepare_primitive_array(out, options.min_periods, 0);
-                        Series::try_from((self.name(), Arc::new(arr) as ArrayRef))
-                    }
-                }
-            }
-            (DataType::Float64, 0) => {
-                let ca = self.f64().unwrap();
-                match self.n_chunks() {
-                    1 => {
-                        let vals = ca.downcast_iter().next().unwrap();
-                        let vals = vals.values().as_slice();
-                        let out = if options.adjust {
-                            ewma_no_nulls(vals.iter().copied(), options.alpha)
-                        } else {
-                            ewma_inf_hist_no_nulls(vals.iter().copied(), options.alpha)
-                        };
-                        let arr = prepare_primitive_array(out, options.min_periods, 0);
-                        Series::try_from((self.name(), Arc::new(arr) as ArrayRef))
-                    }
-                    _ => {
-                        let iter = ca.into_no_null_iter();
-                        let out = if options.adjust {
-                            ewma_no_nulls(iter, options.alpha)
-                        } else {
-                            ewma_inf_hist_no_nulls(iter, options.alpha)
-                        };
-                        let arr = prepare_primitive_array(out, options.min_periods, 0);
-                        Series::try_from((self.name(), Arc::new(arr) as ArrayRef))
-                    }
-                }
-            }
-            (DataType::Float32, _) => {
-                let ca = self.f32().unwrap();
-                let iter = ca.into_iter();
-                let (leading_nulls, out) = if options.adjust {
-                    ewma(iter, options.alpha as f32)
-                } else {
-                    ewma_inf_hists(iter, options.alpha as f32)
-                };
-                let arr = prepare_primitive_array(out, options.min_periods, leading_nulls);
-                Series::try_from((self.name(), Arc::new(arr) as ArrayRef))
-            }
-            (DataType::Float64, _) => {
-                let ca = self.f64().unwrap();
-                let iter = ca.into_iter();
-                let (leading_nulls, out) = if options.adjust {
-                    ewma(iter, options.alpha as f64)
-                } else {
-                    ewma_inf_hists(iter, options.alpha)
-                };
-                let arr = prepare_primitive_array(out, options.min_periods, leading_nulls);
-                Series::try_from((self.name(), Arc::new(arr) as ArrayRef))
+    fn ewm_finish(&self, out: Float64Chunked, dtype: &DataType) -> Result<Series> {
+        let mut out = out.into_series().cast(&ewm_dtype(dtype))?;
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    fn ewm_mean_impl(&self, options: &EWMOptions, deltas: Option<&[f64]>) -> Result<Series> {
+        let s = self.cast(&ewm_dtype(self.dtype()))?;
+        let mut out = match s.dtype() {
+            DataType::Float32 => {
+                let deltas = deltas.map(|d| d.iter().map(|&v| v as f32).collect::<Vec<_>>());
+                ewm_mean_ca(s.f32()?, options, deltas.as_deref()).into_series()
             }
-            _ => self.cast(&DataType::Float64)?.ewm_mean(options),
+            _ => ewm_mean_ca(s.f64()?, options, deltas).into_series(),
+        };
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    fn ewm_var_impl(&self, options: &EWMOptions) -> Result<Series> {
+        let s = self.cast(&ewm_dtype(self.dtype()))?;
+        let mut out = match s.dtype() {
+            DataType::Float32 => ewm_var_ca(s.f32()?, options).into_series(),
+            _ => ewm_var_ca(s.f64()?, options).into_series(),
+        };
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    fn ewm_cov_impl(&self, other: &Series, options: &EWMOptions) -> Result<Float64Chunked> {
+        if self.len() != other.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "ewm covariance requires series of equal length".into(),
+            ));
         }
+        let x = self.cast(&DataType::Float64)?;
+        let y = other.cast(&DataType::Float64)?;
+        let vals = x.f64()?.into_iter().zip(y.f64()?.into_iter());
+        Ok(ewm_cov(vals, options, None).into_iter().collect())
     }
 
-    pub fn ewm_std(&self, options: EWMOptions) -> Result<Self> {
-        let ca = self.rechunk();
-        match ca.dtype() {
-            DataType::Float32 | DataType::Float64 => {}
-            _ => return self.cast(&DataType::Float64)?.ewm_std(options),
+    /// Exponentially weighted moving average.
+    pub fn ewm_mean(&self, options: EWMOptions) -> Result<Self> {
+        self.ewm_mean_impl(&options, None)
+    }
+
+    /// Exponentially weighted moving average of irregularly spaced values. The weight of a
+    /// value halves with every `half_life` that passed between its timestamp in `by` and the
+    /// current timestamp.
+    ///
+    /// `by` should be a `Date` or `Datetime` column, sorted in ascending order, without nulls.
+    /// `options.alpha` is ignored.
+    #[cfg(feature = "dtype-datetime")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dtype-datetime")))]
+    pub fn ewm_mean_by(
+        &self,
+        by: &Series,
+        half_life: Duration,
+        options: EWMOptions,
+    ) -> Result<Self> {
+        if half_life.months() != 0 || half_life.negative() || half_life.is_zero() {
+            return Err(PolarsError::ComputeError(
+                "half_life of ewm_mean_by should be a positive duration without months or years"
+                    .into(),
+            ));
+        }
+        if by.len() != self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "the 'by' column of ewm_mean_by should have the length of the series".into(),
+            ));
+        }
+        if by.null_count() > 0 {
+            return Err(PolarsError::ComputeError(
+                "the 'by' column of ewm_mean_by may not contain nulls".into(),
+            ));
         }
-        let ewma = ca.ewm_mean(options)?;
-
-        match ewma.dtype() {
-            DataType::Float64 => {
-                let ewma_arr = ewma.f64().unwrap().downcast_iter().next().unwrap();
-                // Safety:
-                // we are the only owners for arr;
-                let ewma_slice = unsafe { to_mutable_slice(ewma_arr.values().as_slice()) };
-                let arr = ca.f64().unwrap().downcast_iter().next().unwrap();
-                let x_slice = arr.values().as_slice();
-
-                ewm_std(x_slice, ewma_slice, options.alpha);
-                ewma_arr.with_validity(arr.validity().cloned());
-                // we mask the original null values until we know better how to deal with them.
-                let out = Arc::new(ewma_arr.with_validity(arr.validity().cloned())) as ArrayRef;
-                Series::try_from((self.name(), out))
+        let time = match by.dtype() {
+            DataType::Date | DataType::Datetime => by.cast(&DataType::Datetime)?,
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!(
+                        "the 'by' column of ewm_mean_by should be temporal, got {:?}",
+                        dt
+                    )
+                    .into(),
+                ))
             }
-            DataType::Float32 => {
-                let ewma_arr = ewma.f32().unwrap().downcast_iter().next().unwrap();
-                // Safety:
-                // we are the only owners for arr;
-                let ewma_slice = unsafe { to_mutable_slice(ewma_arr.values().as_slice()) };
-                let arr = ca.f32().unwrap().downcast_iter().next().unwrap();
-                let x_slice = arr.values().as_slice();
-
-                ewm_std(x_slice, ewma_slice, options.alpha as f32);
-                // we mask the original null values until we know better how to deal with them.
-                let out = Arc::new(ewma_arr.with_validity(arr.validity().cloned())) as ArrayRef;
-                Series::try_from((self.name(), out))
+        };
+        let time = time.to_physical_repr();
+        let time: Vec<i64> = time.i64()?.into_no_null_iter().collect();
+        let half_life = half_life.nanoseconds() as f64;
+
+        let mut deltas = Vec::with_capacity(time.len());
+        let mut previous = time.first().copied().unwrap_or_default();
+        for t in time {
+            if t < previous {
+                return Err(PolarsError::ComputeError(
+                    "the 'by' column of ewm_mean_by should be sorted in ascending order".into(),
+                ));
             }
-            _ => unimplemented!(),
+            deltas.push((t - previous) as f64 / half_life);
+            previous = t;
+        }
+        // with alpha = 0.5 the weights decay with 0.5^(elapsed / half_life)
+        let options = EWMOptions {
+            alpha: 0.5,
+            ..options
+        };
+        self.ewm_mean_impl(&options, Some(&deltas))
+    }
+
+    /// Exponentially weighted moving standard deviation. See [Series::ewm_var].
+    pub fn ewm_std(&self, options: EWMOptions) -> Result<Self> {
+        let var = self.ewm_var_impl(&options)?;
+        match var.dtype() {
+            DataType::Float32 => Ok(var.f32()?.apply(|v| v.sqrt()).into_series()),
+            _ => Ok(var.f64()?.apply(|v| v.sqrt()).into_series()),
         }
     }
 
+    /// Exponentially weighted moving variance, as pandas' `ewm(..).var()`.
+    ///
+    /// By default this is the bias corrected variance, which is null at the first value.
+    /// Set `options.bias` for the biased variance. Null values stay null in the output.
     pub fn ewm_var(&self, options: EWMOptions) -> Result<Self> {
-        let ca = self.rechunk();
-        match ca.dtype() {
-            DataType::Float32 | DataType::Float64 => {}
-            _ => return self.cast(&DataType::Float64)?.ewm_var(options),
+        self.ewm_var_impl(&options)
+    }
+
+    /// Exponentially weighted moving covariance with `other`. A row is only used if
+    /// both values are non-null.
+    pub fn ewm_cov(&self, other: &Series, options: EWMOptions) -> Result<Self> {
+        let cov = self.ewm_cov_impl(other, &options)?;
+        self.ewm_finish(cov, &ewm_pair_dtype(self.dtype(), other.dtype()))
+    }
+
+    /// Exponentially weighted moving correlation with `other`. A row is only used if
+    /// both values are non-null.
+    pub fn ewm_corr(&self, other: &Series, options: EWMOptions) -> Result<Self> {
+        // the bias correction cancels out
+        let options = options.and_bias(true);
+        let cov = self.ewm_cov_impl(other, &options)?;
+        let var_x = self.ewm_cov_impl(self, &options)?;
+        let var_y = other.ewm_cov_impl(other, &options)?;
+        let corr: Float64Chunked = cov
+            .into_iter()
+            .zip(var_x.into_iter())
+            .zip(var_y.into_iter())
+            .map(|((cov, var_x), var_y)| match (cov, var_x, var_y) {
+                (Some(cov), Some(var_x), Some(var_y)) if var_x > 0.0 && var_y > 0.0 => {
+                    Some(cov / (var_x * var_y).sqrt())
+                }
+                _ => None,
+            })
+            .collect();
+        self.ewm_finish(corr, &ewm_pair_dtype(self.dtype(), other.dtype()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ewm_cov_corr() -> Result<()> {
+        let x = Series::new("x", &[1.0f32, 2.0, 3.0, 4.0]);
+        let y = Series::new("y", &[2.0f32, 4.0, 6.0, 8.0]);
+
+        let corr = x.ewm_corr(&y, EWMOptions::default())?;
+        assert_eq!(corr.dtype(), &DataType::Float32);
+        assert_eq!(corr.name(), "x");
+        let corr = corr.f32()?;
+        assert_eq!(corr.get(0), None);
+        for v in corr.into_no_null_iter().skip(1) {
+            assert!((v - 1.0).abs() < 1e-6);
+        }
+
+        // the covariance of a series with itself is the variance
+        let var = x.ewm_var(EWMOptions::default())?;
+        let cov = x.ewm_cov(&x, EWMOptions::default())?;
+        assert_eq!(var.null_count(), 1);
+        assert_eq!(cov.null_count(), 1);
+        for (var, cov) in var.f32()?.into_iter().zip(cov.f32()?.into_iter()).skip(1) {
+            assert!((var.unwrap() - cov.unwrap()).abs() < 1e-6);
         }
-        let ewma = ca.ewm_mean(options)?;
-
-        match ewma.dtype() {
-            DataType::Float64 => {
-                let ewma_arr = ewma.f64().unwrap().downcast_iter().next().unwrap();
-                // Safety:
-                // we are the only owners for arr;
-                let ewma_slice = unsafe { to_mutable_slice(ewma_arr.values().as_slice()) };
-                let arr = ca.f64().unwrap().downcast_iter().next().unwrap();
-                let x_slice = arr.values().as_slice();
-
-                ewm_var(x_slice, ewma_slice, options.alpha);
-                ewma_arr.with_validity(arr.validity().cloned());
-                // we mask the original null values until we know better how to deal with them.
-                let out = Arc::new(ewma_arr.with_validity(arr.validity().cloned())) as ArrayRef;
-                Series::try_from((self.name(), out))
+        Ok(())
+    }
+
+    #[test]
+    fn test_ewm_no_nulls_fast_path() -> Result<()> {
+        let values = [1.0, 5.0, 7.0, 1.0, 2.0];
+        let s = Series::new("a", &values);
+        // the same values with a null appended go through the kernels that handle nulls
+        let mut with_null = s.clone();
+        with_null.append(&Series::new("a", &[None::<f64>]))?;
+
+        for adjust in [true, false] {
+            let options = EWMOptions::default().and_adjust(adjust).and_min_periods(2);
+            let fast = s.ewm_mean(options)?;
+            let slow = with_null.ewm_mean(options)?;
+            assert_eq!(fast.null_count(), 1);
+            let fast = fast.f64()?;
+            let slow = slow.f64()?;
+            for i in 1..values.len() {
+                assert!((fast.get(i).unwrap() - slow.get(i).unwrap()).abs() < 1e-12);
             }
-            DataType::Float32 => {
-                let ewma_arr = ewma.f32().unwrap().downcast_iter().next().unwrap();
-                // Safety:
-                // we are the only owners for arr;
-                let ewma_slice = unsafe { to_mutable_slice(ewma_arr.values().as_slice()) };
-                let arr = ca.f32().unwrap().downcast_iter().next().unwrap();
-                let x_slice = arr.values().as_slice();
-
-                ewm_var(x_slice, ewma_slice, options.alpha as f32);
-                // we mask the original null values until we know better how to deal with them.
-                let out = Arc::new(ewma_arr.with_validity(arr.validity().cloned())) as ArrayRef;
-                Series::try_from((self.name(), out))
+            // a null value doesn't change the mean
+            assert_eq!(slow.get(5), slow.get(4));
+
+            let fast = s.ewm_var(options)?;
+            let slow = with_null.ewm_var(options)?;
+            let fast = fast.f64()?;
+            let slow = slow.f64()?;
+            for i in 1..values.len() {
+                assert!((fast.get(i).unwrap() - slow.get(i).unwrap()).abs() < 1e-12);
             }
-            _ => unimplemented!(),
+            // null values stay null
+            assert_eq!(slow.get(5), None);
         }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ewm_var_bias() -> Result<()> {
+        let s = Series::new("a", &[1i32, 5]);
+
+        // bias corrected by default, which is undefined for a single value
+        let var = s.ewm_var(EWMOptions::default())?;
+        assert_eq!(var.dtype(), &DataType::Float64);
+        let var = var.f64()?;
+        assert_eq!(var.get(0), None);
+        assert!((var.get(1).unwrap() - 8.0).abs() < 1e-12);
+
+        let std = s.ewm_std(EWMOptions::default().and_bias(true))?;
+        let std = std.f64()?;
+        assert_eq!(std.get(0), Some(0.0));
+        assert!((std.get(1).unwrap() - (32.0f64 / 9.0).sqrt()).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+    fn test_ewm_mean_by() -> Result<()> {
+        let s = Series::new("a", &[1.0, 5.0, 2.0]);
+        // a gap of two days is two half lives
+        let by = Int32Chunked::new_from_slice("t", &[0, 1, 3])
+            .into_date()
+            .into_series();
+        let out = s.ewm_mean_by(&by, Duration::parse("1d"), Default::default())?;
+        let out = out.f64()?;
+        // weights: 1 * 0.125, 5 * 0.25, 2 * 1
+        assert!((out.get(2).unwrap() - 3.375 / 1.375).abs() < 1e-12);
+
+        let unsorted = Int32Chunked::new_from_slice("t", &[0, 3, 1])
+            .into_date()
+            .into_series();
+        assert!(s
+            .ewm_mean_by(&unsorted, Duration::parse("1d"), Default::default())
+            .is_err());
+        Ok(())
     }
 }
@@ -0,0 +1,111 @@
+use crate::prelude::*;
+
+impl Series {
+    /// Fill the null values between non-null values using the given interpolation method.
+    ///
+    /// If `by` is given, the values are interpolated with respect to the positions in `by`
+    /// (e.g. timestamps) instead of the row index. `by` should be sorted in ascending order
+    /// and may not contain nulls. Leading and trailing nulls are not filled.
+    ///
+    /// Numeric, `Date`, `Datetime` and `Time` series are supported.
+    pub fn interpolate_with(
+        &self,
+        options: InterpolateOptions,
+        by: Option<&Series>,
+    ) -> Result<Series> {
+        let by = match by {
+            Some(by) => {
+                if by.len() != self.len() {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "the 'by' series of length {} does not match the length {} of the series to interpolate",
+                            by.len(),
+                            self.len()
+                        )
+                        .into(),
+                    ));
+                }
+                if by.null_count() > 0 {
+                    return Err(PolarsError::ComputeError(
+                        "the 'by' series of interpolate may not contain nulls".into(),
+                    ));
+                }
+                let by = by.to_physical_repr().cast(&DataType::Float64)?;
+                let by: Vec<f64> = by.f64()?.into_no_null_iter().collect();
+                if by.windows(2).any(|w| w[0] > w[1]) {
+                    return Err(PolarsError::ComputeError(
+                        "the 'by' series of interpolate should be sorted in ascending order".into(),
+                    ));
+                }
+                Some(by)
+            }
+            None => None,
+        };
+        let by = by.as_deref();
+
+        let physical = self.to_physical_repr();
+        let out = match physical.dtype() {
+            #[cfg(feature = "dtype-u8")]
+            DataType::UInt8 => physical.u8()?.interpolate_impl(by, options)?.into_series(),
+            #[cfg(feature = "dtype-u16")]
+            DataType::UInt16 => physical.u16()?.interpolate_impl(by, options)?.into_series(),
+            DataType::UInt32 => physical.u32()?.interpolate_impl(by, options)?.into_series(),
+            DataType::UInt64 => physical.u64()?.interpolate_impl(by, options)?.into_series(),
+            #[cfg(feature = "dtype-i8")]
+            DataType::Int8 => physical.i8()?.interpolate_impl(by, options)?.into_series(),
+            #[cfg(feature = "dtype-i16")]
+            DataType::Int16 => physical.i16()?.interpolate_impl(by, options)?.into_series(),
+            DataType::Int32 => physical.i32()?.interpolate_impl(by, options)?.into_series(),
+            DataType::Int64 => physical.i64()?.interpolate_impl(by, options)?.into_series(),
+            DataType::Float32 => physical.f32()?.interpolate_impl(by, options)?.into_series(),
+            DataType::Float64 => physical.f64()?.interpolate_impl(by, options)?.into_series(),
+            _ => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("interpolate not supported for dtype {:?}", self.dtype()).into(),
+                ))
+            }
+        };
+        out.cast(self.dtype())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_interpolate_by_datetime() -> Result<()> {
+        let s = Int64Chunked::new("a", &[Some(0), None, None, Some(60)])
+            .into_date()
+            .into_series();
+        let by = Int64Chunked::new("t", &[0, 1, 2, 6])
+            .into_date()
+            .into_series();
+
+        let out = s.interpolate_with(Default::default(), Some(&by))?;
+        assert_eq!(out.dtype(), s.dtype());
+        let out = out.to_physical_repr().into_owned();
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(0), Some(10), Some(20), Some(60)]
+        );
+
+        let options = InterpolateOptions {
+            method: InterpolationMethod::Nearest,
+            limit: None,
+        };
+        let out = s.interpolate_with(options, Some(&by))?;
+        let out = out.to_physical_repr().into_owned();
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(0), Some(0), Some(0), Some(60)]
+        );
+
+        let unsorted = Int64Chunked::new("t", &[0, 2, 1, 6]).into_series();
+        assert!(s
+            .interpolate_with(Default::default(), Some(&unsorted))
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use crate::prelude::*;
+use num::{Bounded, NumCast};
+
+/// Dispatch a generic function over the numeric dtypes.
+macro_rules! apply_numeric {
+    ($s:expr, $op:expr, $func:ident $(, $args:expr)*) => {{
+        match $s.dtype() {
+            #[cfg(feature = "dtype-u8")]
+            DataType::UInt8 => $func($s.u8().unwrap() $(, $args)*).into_series(),
+            #[cfg(feature = "dtype-u16")]
+            DataType::UInt16 => $func($s.u16().unwrap() $(, $args)*).into_series(),
+            DataType::UInt32 => $func($s.u32().unwrap() $(, $args)*).into_series(),
+            DataType::UInt64 => $func($s.u64().unwrap() $(, $args)*).into_series(),
+            #[cfg(feature = "dtype-i8")]
+            DataType::Int8 => $func($s.i8().unwrap() $(, $args)*).into_series(),
+            #[cfg(feature = "dtype-i16")]
+            DataType::Int16 => $func($s.i16().unwrap() $(, $args)*).into_series(),
+            DataType::Int32 => $func($s.i32().unwrap() $(, $args)*).into_series(),
+            DataType::Int64 => $func($s.i64().unwrap() $(, $args)*).into_series(),
+            DataType::Float32 => $func($s.f32().unwrap() $(, $args)*).into_series(),
+            DataType::Float64 => $func($s.f64().unwrap() $(, $args)*).into_series(),
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("{} not supported for dtype {:?}", $op, dt).into(),
+                ))
+            }
+        }
+    }};
+}
+
+macro_rules! impl_float_unary {
+    ($($(#[$doc:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(&self) -> Result<Series> {
+                let s = self.to_float()?;
+                let out = match s.dtype() {
+                    DataType::Float32 => s.f32().unwrap().$name().into_series(),
+                    _ => s.f64().unwrap().$name().into_series(),
+                };
+                Ok(out)
+            }
+        )*
+    };
+}
+
+fn sign<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> ChunkedArray<T> {
+    ca.sign()
+}
+
+fn clip<T: PolarsNumericType>(ca: &ChunkedArray<T>, min: f64, max: f64) -> ChunkedArray<T> {
+    // integer bounds are rounded inwards, bounds outside of the domain saturate
+    let is_float = matches!(T::get_dtype(), DataType::Float32 | DataType::Float64);
+    let (min, max) = if is_float {
+        (min, max)
+    } else {
+        (min.ceil(), max.floor())
+    };
+    let to_native = |v: f64| {
+        NumCast::from(v).unwrap_or_else(|| {
+            if v < 0.0 {
+                T::Native::min_value()
+            } else {
+                T::Native::max_value()
+            }
+        })
+    };
+    ca.clip(to_native(min), to_native(max))
+}
+
+impl Series {
+    /// Cast integers to `Float64`, floats are returned as is.
+    fn to_float(&self) -> Result<Series> {
+        use DataType::*;
+        match self.dtype() {
+            Float32 | Float64 => Ok(self.clone()),
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 => self.cast(&Float64),
+            dt => Err(PolarsError::InvalidOperation(
+                format!("{:?} is not a numeric datatype", dt).into(),
+            )),
+        }
+    }
+
+    /// Compute the logarithm to the given `base`. Integers are cast to `Float64`.
+    pub fn log(&self, base: f64) -> Result<Series> {
+        let s = self.to_float()?;
+        let out = match s.dtype() {
+            DataType::Float32 => s.f32().unwrap().log(base).into_series(),
+            _ => s.f64().unwrap().log(base).into_series(),
+        };
+        Ok(out)
+    }
+
+    impl_float_unary! {
+        /// Compute the logarithm to base 10. Integers are cast to `Float64`.
+        log10,
+        /// Compute `ln(1 + x)`. Integers are cast to `Float64`.
+        log1p,
+        /// Compute `e^x`. Integers are cast to `Float64`.
+        exp,
+        /// Compute the square root. Integers are cast to `Float64`.
+        sqrt,
+        /// Compute the cube root. Integers are cast to `Float64`.
+        cbrt,
+        /// Compute the sine. Integers are cast to `Float64`.
+        sin,
+        /// Compute the cosine. Integers are cast to `Float64`.
+        cos,
+        /// Compute the tangent. Integers are cast to `Float64`.
+        tan,
+        /// Compute the inverse sine. Integers are cast to `Float64`.
+        arcsin,
+        /// Compute the inverse cosine. Integers are cast to `Float64`.
+        arccos,
+        /// Compute the inverse tangent. Integers are cast to `Float64`.
+        arctan,
+        /// Compute the hyperbolic sine. Integers are cast to `Float64`.
+        sinh,
+        /// Compute the hyperbolic cosine. Integers are cast to `Float64`.
+        cosh,
+        /// Compute the hyperbolic tangent. Integers are cast to `Float64`.
+        tanh,
+        /// Compute the inverse hyperbolic sine. Integers are cast to `Float64`.
+        arcsinh,
+        /// Compute the inverse hyperbolic cosine. Integers are cast to `Float64`.
+        arccosh,
+        /// Compute the inverse hyperbolic tangent. Integers are cast to `Float64`.
+        arctanh,
+    }
+
+    /// Replace the values by `-1`, `0` or `1` depending on their sign. The dtype is kept.
+    pub fn sign(&self) -> Result<Series> {
+        Ok(apply_numeric!(self, "sign", sign))
+    }
+
+    /// Limit the values to the range `[min, max]`. The dtype is kept, for integers the
+    /// bounds are rounded inwards.
+    pub fn clip(&self, min: f64, max: f64) -> Result<Series> {
+        if min.is_nan() || max.is_nan() {
+            return Err(PolarsError::ValueError(
+                format!(
+                    "clip bounds may not be NaN, got min: {} and max: {}",
+                    min, max
+                )
+                .into(),
+            ));
+        }
+        if min > max {
+            return Err(PolarsError::ValueError(
+                format!("clip bound min: {} is larger than max: {}", min, max).into(),
+            ));
+        }
+        Ok(apply_numeric!(self, "clip", clip, min, max))
+    }
+
+    /// Raise the values to the power of the values in `exponent`. `exponent` should have the
+    /// length of `self` or a length of one. The result is `Float64`.
+    pub fn pow_series(&self, exponent: &Series) -> Result<Series> {
+        let base = self.cast(&DataType::Float64)?;
+        let base = base.f64()?;
+        let exponent = exponent.cast(&DataType::Float64)?;
+        let exponent = exponent.f64()?;
+
+        let mut out: Float64Chunked = if exponent.len() == 1 {
+            match exponent.get(0) {
+                Some(e) => base.apply(|v| v.powf(e)),
+                None => Float64Chunked::full_null(self.name(), self.len()),
+            }
+        } else if exponent.len() == self.len() {
+            base.into_iter()
+                .zip(exponent.into_iter())
+                .map(|(b, e)| b.zip(e).map(|(b, e)| b.powf(e)))
+                .collect()
+        } else {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "exponent of length {} cannot be applied to a series of length {}",
+                    exponent.len(),
+                    self.len()
+                )
+                .into(),
+            ));
+        };
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_math_series() -> Result<()> {
+        let s = Series::new("a", &[1, 8, 27]);
+        let out = s.cbrt()?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.0), Some(2.0), Some(3.0)]);
+
+        let s = Series::new("a", &[1.0f32, 0.0]);
+        assert_eq!(s.exp()?.dtype(), &DataType::Float32);
+
+        let s = Series::new("a", &[-5i64, 0, 3, 10]);
+        let out = s.clip(-1.5, 4.5)?;
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(-1), Some(0), Some(3), Some(4)]
+        );
+        assert!(s.clip(1.0, 0.0).is_err());
+        assert!(s.clip(f64::NAN, 1.0).is_err());
+        assert!(s.clip(0.0, f64::NAN).is_err());
+        assert_eq!(
+            Vec::from(s.sign()?.i64()?),
+            &[Some(-1), Some(0), Some(1), Some(1)]
+        );
+
+        let s = Series::new("a", &[2, 3]);
+        let out = s.pow_series(&Series::new("e", &[3, 2]))?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(8.0), Some(9.0)]);
+        let out = s.pow_series(&Series::new("e", &[2]))?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(4.0), Some(9.0)]);
+        Ok(())
+    }
+}
@@ -1,12 +1,22 @@
+#[cfg(feature = "binning")]
+mod binning;
+mod bitwise;
 #[cfg(feature = "diff")]
 pub mod diff;
 #[cfg(feature = "ewma")]
 mod ewm;
 mod extend;
+#[cfg(feature = "interpolate")]
+mod interpolate;
+#[cfg(feature = "math")]
+mod math;
 #[cfg(feature = "moment")]
 pub mod moment;
 mod null;
+#[cfg(feature = "rolling_window")]
+mod rolling_cov;
 mod to_list;
+pub(crate) mod value_counts;
 
 #[derive(Copy, Clone)]
 pub enum NullBehavior {
@@ -0,0 +1,219 @@
+use crate::prelude::*;
+
+/// Running means and co-moments of the pairs in a window, updated with Welford's algorithm.
+/// Unlike plain sums of products, this doesn't lose precision for values with a large offset.
+#[derive(Default)]
+struct PairSums {
+    n: usize,
+    mean_x: f64,
+    mean_y: f64,
+    /// sum of `(x - mean_x) * (y - mean_y)`
+    c_xy: f64,
+    /// sum of `(x - mean_x)^2`
+    m2_x: f64,
+    /// sum of `(y - mean_y)^2`
+    m2_y: f64,
+}
+
+impl PairSums {
+    fn add(&mut self, x: f64, y: f64) {
+        self.n += 1;
+        let n = self.n as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / n;
+        self.mean_y += dy / n;
+        self.c_xy += dx * (y - self.mean_y);
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+    }
+
+    fn remove(&mut self, x: f64, y: f64) {
+        if self.n == 1 {
+            *self = Self::default();
+            return;
+        }
+        self.n -= 1;
+        let n = self.n as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x -= dx / n;
+        self.mean_y -= dy / n;
+        self.c_xy -= dx * (y - self.mean_y);
+        self.m2_x -= dx * (x - self.mean_x);
+        self.m2_y -= dy * (y - self.mean_y);
+    }
+
+    /// Sample covariance and sample variances of the pairs.
+    fn cov_var(&self) -> (f64, f64, f64) {
+        let ddof = self.n as f64 - 1.0;
+        (self.c_xy / ddof, self.m2_x / ddof, self.m2_y / ddof)
+    }
+}
+
+impl Series {
+    /// The sample covariance and variances of every window. A pair is only used if both
+    /// values are valid.
+    fn rolling_cov_var(
+        &self,
+        other: &Series,
+        options: &RollingOptions,
+    ) -> Result<Vec<Option<(f64, f64, f64)>>> {
+        if self.len() != other.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "rolling covariance requires series of equal length".into(),
+            ));
+        }
+        if options.weights.is_some() {
+            return Err(PolarsError::InvalidOperation(
+                "weights are not supported in rolling covariance and correlation".into(),
+            ));
+        }
+        let x = self.cast(&DataType::Float64)?;
+        let y = other.cast(&DataType::Float64)?;
+        let pairs = x
+            .f64()?
+            .into_iter()
+            .zip(y.f64()?.into_iter())
+            .map(|(x, y)| match (x, y) {
+                (Some(x), Some(y)) => Some((x, y)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let len = pairs.len();
+        let window_size = options.window_size;
+        // the sample covariance needs at least two pairs
+        let min_periods = std::cmp::max(options.min_periods, 2);
+        let mut sums = PairSums::default();
+        let (mut start, mut end) = (0, 0);
+
+        Ok((0..len)
+            .map(|i| {
+                let (new_start, new_end) = if options.center {
+                    let offset = window_size / 2;
+                    (
+                        i.saturating_sub(offset),
+                        std::cmp::min(len, i + window_size - offset),
+                    )
+                } else {
+                    ((i + 1).saturating_sub(window_size), i + 1)
+                };
+                for (x, y) in pairs[end..new_end].iter().flatten() {
+                    sums.add(*x, *y)
+                }
+                for (x, y) in pairs[start..new_start].iter().flatten() {
+                    sums.remove(*x, *y)
+                }
+                start = new_start;
+                end = new_end;
+
+                if sums.n >= min_periods {
+                    Some(sums.cov_var())
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Apply a rolling sample covariance with `other`. A pair is only used if both values
+    /// are valid, and at least `min_periods` (and at least two) pairs are needed for a result.
+    /// Weights are not supported.
+    pub fn rolling_cov(&self, other: &Series, options: RollingOptions) -> Result<Series> {
+        let cov_var = self.rolling_cov_var(other, &options)?;
+        let mut ca: Float64Chunked = cov_var
+            .into_iter()
+            .map(|opt| opt.map(|(cov, _, _)| cov))
+            .collect();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+
+    /// Apply a rolling pearson correlation with `other`. A pair is only used if both values
+    /// are valid, and at least `min_periods` (and at least two) pairs are needed for a result.
+    /// Weights are not supported.
+    pub fn rolling_corr(&self, other: &Series, options: RollingOptions) -> Result<Series> {
+        let cov_var = self.rolling_cov_var(other, &options)?;
+        let mut ca: Float64Chunked = cov_var
+            .into_iter()
+            .map(|opt| match opt {
+                Some((cov, var_x, var_y)) if var_x > 0.0 && var_y > 0.0 => {
+                    Some(cov / (var_x * var_y).sqrt())
+                }
+                _ => None,
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rolling_cov_corr() -> Result<()> {
+        let x = Series::new(
+            "x",
+            &[Some(1.0), Some(2.0), Some(3.0), Some(4.0), None, Some(6.0)],
+        );
+        let y = Series::new("y", &[2i32, 4, 6, 8, 10, 12]);
+        let options = RollingOptions {
+            window_size: 3,
+            ..Default::default()
+        };
+
+        let cov = x.rolling_cov(&y, options.clone())?;
+        let expected = [None, Some(1.0), Some(2.0), Some(2.0), Some(1.0), Some(4.0)];
+        for (out, expected) in cov.f64()?.into_iter().zip(expected) {
+            match (out, expected) {
+                (Some(out), Some(expected)) => assert!((out - expected).abs() < 1e-12),
+                _ => assert_eq!(out, expected),
+            }
+        }
+        let corr = x.rolling_corr(&y, options)?;
+        assert_eq!(corr.f64()?.get(2), Some(1.0));
+        assert_eq!(corr.f64()?.get(0), None);
+        assert!(x.rolling_cov(&y.slice(0, 2), Default::default()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_cov_large_offset() -> Result<()> {
+        // naive sums of products are off by about 1e-4 with this offset
+        let offset = 1e6;
+        let x = Series::new(
+            "x",
+            &(0..100)
+                .map(|i| offset + (i % 7) as f64)
+                .collect::<Vec<_>>(),
+        );
+        let y = Series::new(
+            "y",
+            &(0..100)
+                .map(|i| offset + 2.0 * (i % 7) as f64)
+                .collect::<Vec<_>>(),
+        );
+        let options = RollingOptions {
+            window_size: 3,
+            ..Default::default()
+        };
+
+        let cov = x.rolling_cov(&y, options.clone())?;
+        let corr = x.rolling_corr(&y, options)?;
+        for i in 2..100 {
+            let window = [(i - 2) % 7, (i - 1) % 7, i % 7];
+            let mean = window.iter().sum::<usize>() as f64 / 3.0;
+            let var = window
+                .iter()
+                .map(|v| (*v as f64 - mean).powi(2))
+                .sum::<f64>()
+                / 2.0;
+            assert!((cov.f64()?.get(i).unwrap() - 2.0 * var).abs() < 1e-6);
+            assert!((corr.f64()?.get(i).unwrap() - 1.0).abs() < 1e-6);
+        }
+        Ok(())
+    }
+}
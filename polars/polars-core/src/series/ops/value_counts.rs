@@ -0,0 +1,69 @@
+use crate::prelude::*;
+
+/// See [SeriesTrait::value_counts].
+pub(crate) fn value_counts<S: SeriesTrait + ?Sized>(
+    s: &S,
+    sort: bool,
+    normalize: bool,
+) -> Result<DataFrame> {
+    #[cfg(feature = "object")]
+    if let DataType::Object(_) = s.dtype() {
+        return Err(PolarsError::InvalidOperation(
+            "value_counts is not supported for the object dtype".into(),
+        ));
+    }
+
+    let mut groups = s.group_tuples(true);
+    groups.sort_unstable_by_key(|g| g.0);
+    if sort {
+        // stable sort, ties keep the order of first occurrence
+        groups.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+    }
+
+    // Safety:
+    // the first indices of the groups are in bounds
+    let values = unsafe { s.take_iter_unchecked(&mut groups.iter().map(|g| g.0 as usize)) };
+    let counts = if normalize {
+        let len = s.len() as f64;
+        Float64Chunked::new_from_iter("proportion", groups.iter().map(|g| g.1.len() as f64 / len))
+            .into_series()
+    } else {
+        UInt32Chunked::new_from_iter("counts", groups.iter().map(|g| g.1.len() as u32))
+            .into_series()
+    };
+    Ok(DataFrame::new_no_checks(vec![values, counts]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_value_counts() -> Result<()> {
+        let s = Series::new(
+            "a",
+            &[Some("b"), Some("a"), None, Some("a"), Some("b"), Some("a")],
+        );
+
+        let out = s.value_counts(false, false)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.utf8()?),
+            &[Some("b"), Some("a"), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("counts")?.u32()?),
+            &[Some(2), Some(3), Some(1)]
+        );
+
+        let out = s.value_counts(true, true)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.utf8()?),
+            &[Some("a"), Some("b"), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("proportion")?.f64()?),
+            &[Some(0.5), Some(2.0 / 6.0), Some(1.0 / 6.0)]
+        );
+        Ok(())
+    }
+}
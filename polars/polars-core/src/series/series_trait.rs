@@ -126,7 +126,7 @@ pub(crate) mod private {
         }
 
         #[cfg(feature = "asof_join")]
-        fn join_asof(&self, _other: &Series) -> Result<Vec<Option<u32>>> {
+        fn join_asof(&self, _other: &Series, _options: &AsOfOptions) -> Result<Vec<Option<u32>>> {
             invalid_operation!(self)
         }
 
@@ -233,6 +233,10 @@ pub(crate) mod private {
         fn hash_join_outer(&self, _other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
             invalid_operation_panic!(self)
         }
+        #[cfg(feature = "semi_anti_join")]
+        fn hash_join_semi_anti(&self, _other: &Series, _anti: bool) -> Vec<u32> {
+            invalid_operation_panic!(self)
+        }
         fn zip_outer_join_column(
             &self,
             _right_column: &Series,
@@ -615,8 +619,14 @@ pub trait SeriesTrait:
         invalid_operation_panic!(self)
     }
 
-    fn value_counts(&self) -> Result<DataFrame> {
-        invalid_operation_panic!(self)
+    /// Count the occurrences of the unique values.
+    ///
+    /// Returns a `DataFrame` with the unique values and a `"counts"` column, or a
+    /// `"proportion"` column of the fraction of all rows if `normalize` is set. The values
+    /// are ordered by their first occurrence, or by descending count if `sort` is set, where
+    /// ties keep the order of first occurrence. Null is counted as a value.
+    fn value_counts(&self, sort: bool, normalize: bool) -> Result<DataFrame> {
+        crate::series::ops::value_counts::value_counts(self, sort, normalize)
     }
 
     /// Get a single value by index. Don't use this operation for loops as a runtime cast is
@@ -764,10 +774,10 @@ pub trait SeriesTrait:
     /// fn example() -> Result<()> {
     ///     let s = Series::new("some_missing", &[Some(1), None, Some(2)]);
     ///
-    ///     let filled = s.fill_null(FillNullStrategy::Forward)?;
+    ///     let filled = s.fill_null(FillNullStrategy::Forward(None))?;
     ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(1), Some(2)]);
     ///
-    ///     let filled = s.fill_null(FillNullStrategy::Backward)?;
+    ///     let filled = s.fill_null(FillNullStrategy::Backward(None))?;
     ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(2), Some(2)]);
     ///
     ///     let filled = s.fill_null(FillNullStrategy::Min)?;
@@ -48,6 +48,8 @@ use crate::utils::resolve_homedir;
 use crate::{PhysicalIoExpr, ScanAggregation, SerReader, SerWriter};
 pub use arrow::io::csv::write;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "temporal")]
 use std::borrow::Cow;
 use std::fs::File;
@@ -143,6 +145,7 @@ pub enum CsvEncoding {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NullValues {
     /// A single value that's used for all columns
     AllColumns(String),
@@ -220,6 +223,7 @@ where
     null_values: Option<NullValues>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     aggregate: Option<&'a [ScanAggregation]>,
+    reservoir_sample: Option<(usize, u64)>,
     quote_char: Option<u8>,
     #[cfg(feature = "temporal")]
     parse_dates: bool,
@@ -392,6 +396,17 @@ where
         self.aggregate = aggregate;
         self
     }
+
+    /// Read a uniform random sample of at most `n` rows without replacement, using reservoir
+    /// sampling. Only the sample is kept in memory, so this can sample files that don't fit in
+    /// memory. The rows keep their order in the file. The sample is deterministic for a given
+    /// `seed` and number of threads.
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    pub fn with_reservoir_sample(mut self, n: Option<usize>, seed: u64) -> Self {
+        self.reservoir_sample = n.map(|n| (n, seed));
+        self
+    }
 }
 
 impl<'a> CsvReader<'a, File> {
@@ -433,6 +448,7 @@ where
             null_values: None,
             predicate: None,
             aggregate: None,
+            reservoir_sample: None,
             quote_char: Some(b'"'),
             #[cfg(feature = "temporal")]
             parse_dates: false,
@@ -508,6 +524,7 @@ where
                 self.null_values,
                 self.predicate,
                 self.aggregate,
+                self.reservoir_sample,
                 &to_cast,
             )?;
             csv_reader.as_df()?
@@ -536,6 +553,7 @@ where
                 self.null_values,
                 self.predicate,
                 self.aggregate,
+                self.reservoir_sample,
                 &[],
             )?;
             csv_reader.as_df()?
@@ -1365,4 +1383,53 @@ A3,\"B4_\"\"with_embedded_double_quotes\"\"\",C4,4";
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "random")]
+    fn test_reservoir_sample() -> Result<()> {
+        let mut csv = "a\n".to_string();
+        for i in 0..1000 {
+            csv.push_str(&format!("{}\n", i));
+        }
+        let read = |seed| {
+            CsvReader::new(Cursor::new(&csv))
+                .with_chunk_size(64)
+                .with_reservoir_sample(Some(10), seed)
+                .finish()
+        };
+
+        let df = read(0)?;
+        assert_eq!(df.get_column_names(), &["a"]);
+        assert_eq!(df.height(), 10);
+        let a = df
+            .column("a")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        // the rows keep their order in the file
+        assert!(a.windows(2).all(|w| w[0] < w[1]));
+        // the sample is not just the first rows
+        assert!(a.iter().any(|v| *v >= 10));
+        // the same seed gives the same sample
+        assert!(df.frame_equal(&read(0)?));
+
+        // the rows are sampled from the whole file, also from the last chunks
+        let mut sampled_tail = false;
+        for seed in 0..20 {
+            let df = read(seed)?;
+            assert_eq!(df.height(), 10);
+            sampled_tail |= df.column("a")?.i64()?.into_no_null_iter().any(|v| v >= 900);
+        }
+        assert!(sampled_tail);
+
+        // a file smaller than the sample is read completely
+        let df = CsvReader::new(Cursor::new("a\n1\n2\n3\n"))
+            .with_reservoir_sample(Some(10), 0)
+            .finish()?;
+        assert_eq!(
+            Vec::from(df.column("a")?.i64()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        Ok(())
+    }
 }
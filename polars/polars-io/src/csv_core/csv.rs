@@ -33,6 +33,40 @@ pub fn to_cast(df: &mut DataFrame, to_cast: &[&Field]) -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "random")]
+const RESERVOIR_KEY: &str = "__POLARS_RESERVOIR_KEY";
+
+/// Give every row a random key for reservoir sampling. The seed is combined with the byte
+/// offset of the chunk, so every chunk gets different keys.
+#[cfg(feature = "random")]
+fn add_reservoir_keys(df: &mut DataFrame, seed: u64, offset: usize) -> Result<()> {
+    let mut keys = Float64Chunked::init_rand(df.height(), 0.0, seed.wrapping_add(offset as u64));
+    keys.rename(RESERVOIR_KEY);
+    df.with_column(keys)?;
+    Ok(())
+}
+
+/// Keep the `n` rows with the largest reservoir keys in their original order. This gives a
+/// uniform sample without replacement, and the reservoirs of different chunks can be merged by
+/// concatenating them and shrinking again.
+#[cfg(feature = "random")]
+fn shrink_reservoir(df: DataFrame, n: usize) -> Result<DataFrame> {
+    if df.height() <= n {
+        return Ok(df);
+    }
+    let keys = df.column(RESERVOIR_KEY)?.f64()?;
+    let mut idx = keys
+        .into_no_null_iter()
+        .enumerate()
+        .map(|(i, key)| (i as u32, key))
+        .collect::<Vec<_>>();
+    idx.sort_unstable_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    idx.truncate(n);
+    idx.sort_unstable_by_key(|v| v.0);
+    let idx = UInt32Chunked::new_from_iter("", idx.into_iter().map(|v| v.0));
+    df.take(&idx)
+}
+
 /// CSV file reader
 pub(crate) struct CoreReader<'a> {
     reader_bytes: Option<ReaderBytes<'a>>,
@@ -57,6 +91,8 @@ pub(crate) struct CoreReader<'a> {
     null_values: Option<Vec<String>>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     aggregate: Option<&'a [ScanAggregation]>,
+    /// Number of rows to sample and the seed
+    reservoir_sample: Option<(usize, u64)>,
     to_cast: &'a [&'a Field],
 }
 
@@ -143,6 +179,7 @@ impl<'a> CoreReader<'a> {
         null_values: Option<NullValues>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         aggregate: Option<&'a [ScanAggregation]>,
+        reservoir_sample: Option<(usize, u64)>,
         to_cast: &'a [&'a Field],
     ) -> Result<CoreReader<'a>> {
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
@@ -236,6 +273,7 @@ impl<'a> CoreReader<'a> {
             null_values,
             predicate,
             aggregate,
+            reservoir_sample,
             to_cast,
         })
     }
@@ -380,7 +418,8 @@ impl<'a> CoreReader<'a> {
         // all the buffers returned from the threads
         // Structure:
         //      the inner vec has got buffers from all the columns.
-        if predicate.is_some() {
+        // sampling is also done per chunk, so that only the reservoir is kept in memory
+        if predicate.is_some() || self.reservoir_sample.is_some() {
             // assume 10 chars per str
             // this is not updated in low memory mode
             let init_str_bytes = chunk_size * 10;
@@ -465,6 +504,10 @@ impl<'a> CoreReader<'a> {
                                     s.bool().expect("filter predicates was not of type boolean");
                                 local_df = local_df.filter(mask)?;
                             }
+                            #[cfg(feature = "random")]
+                            if let Some((_, seed)) = self.reservoir_sample {
+                                add_reservoir_keys(&mut local_df, seed, last_read)?;
+                            }
 
                             // update the running str bytes statistics
                             for (str_index, name) in str_columns.iter().enumerate() {
@@ -498,6 +541,17 @@ impl<'a> CoreReader<'a> {
                                     df.vstack_mut(&local_df).unwrap();
                                 }
                             }
+                            // shrink the reservoir once it has doubled in size
+                            #[cfg(feature = "random")]
+                            if let Some((n, _)) = self.reservoir_sample {
+                                if let Some(reservoir) = df.take() {
+                                    df = Some(if reservoir.height() > 2 * n {
+                                        shrink_reservoir(reservoir, n)?
+                                    } else {
+                                        reservoir
+                                    });
+                                }
+                            }
                         }
 
                         df.map(|mut df| {
@@ -598,6 +652,16 @@ impl<'a> CoreReader<'a> {
 
         let mut df = self.parse_csv(n_threads, &reader_bytes, predicate.as_ref())?;
 
+        #[cfg(feature = "random")]
+        if let Some((n, _)) = self.reservoir_sample {
+            // merge the reservoirs of the threads.
+            // An empty file has no keys.
+            if df.column(RESERVOIR_KEY).is_ok() {
+                df = shrink_reservoir(df, n)?;
+                df.drop_in_place(RESERVOIR_KEY)?;
+            }
+        }
+
         if let Some(aggregate) = aggregate {
             let cols = aggregate
                 .iter()
@@ -145,6 +145,18 @@ pub enum ScanAggregation {
         column: String,
         alias: Option<String>,
     },
+    // batches are aggregated to mergeable sketches
+    #[cfg(feature = "approx")]
+    ApproxNUnique {
+        column: String,
+        alias: Option<String>,
+    },
+    #[cfg(feature = "approx")]
+    ApproxQuantile {
+        column: String,
+        quantile: f64,
+        alias: Option<String>,
+    },
 }
 
 impl ScanAggregation {
@@ -158,6 +170,10 @@ impl ScanAggregation {
             Max { column, .. } => df.column(column)?.max_as_series(),
             First { column, .. } => df.column(column)?.head(Some(1)),
             Last { column, .. } => df.column(column)?.tail(Some(1)),
+            #[cfg(feature = "approx")]
+            ApproxNUnique { column, .. } => df.column(column)?.approx_n_unique_state()?,
+            #[cfg(feature = "approx")]
+            ApproxQuantile { column, .. } => df.column(column)?.approx_quantile_state()?,
         };
         Ok(s)
     }
@@ -201,6 +217,38 @@ impl ScanAggregation {
                 }
                 Ok(s)
             }
+            // the column holds the states of the batches, or the raw values if the scan
+            // does not aggregate per batch
+            #[cfg(feature = "approx")]
+            ApproxNUnique { column, alias } => {
+                let s = df.column(column)?;
+                let mut s = match s.dtype() {
+                    DataType::List(_) => s.approx_n_unique_merge()?,
+                    _ => s.approx_n_unique_state()?.approx_n_unique_merge()?,
+                };
+                if let Some(alias) = alias {
+                    s.rename(alias);
+                }
+                Ok(s)
+            }
+            #[cfg(feature = "approx")]
+            ApproxQuantile {
+                column,
+                quantile,
+                alias,
+            } => {
+                let s = df.column(column)?;
+                let mut s = match s.dtype() {
+                    DataType::List(_) => s.approx_quantile_merge(*quantile)?,
+                    _ => s
+                        .approx_quantile_state()?
+                        .approx_quantile_merge(*quantile)?,
+                };
+                if let Some(alias) = alias {
+                    s.rename(alias);
+                }
+                Ok(s)
+            }
         }
     }
 }
@@ -79,6 +79,12 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         let schema = read::get_schema(&metadata)?;
         Ok(schema.into())
     }
+
+    /// Number of rows in the parquet file, read from its metadata.
+    pub fn num_rows(mut self) -> Result<usize> {
+        let metadata = read::read_metadata(&mut self.reader)?;
+        Ok(metadata.num_rows as usize)
+    }
 }
 
 impl<R: Read + Seek> ArrowReader for read::RecordReader<R> {
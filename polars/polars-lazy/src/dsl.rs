@@ -22,6 +22,8 @@ use polars_core::frame::select::Selection;
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::{get_supertype, NoNull};
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 
 /// A wrapper trait for any closure `Fn(Vec<Series>) -> Result<Series>`
 pub trait SeriesUdf: Send + Sync {
@@ -64,6 +66,11 @@ impl Debug for dyn SeriesBinaryUdf {
 }
 
 #[derive(Clone)]
+#[cfg_attr(
+    feature = "serde-lazy",
+    derive(Serialize, Deserialize),
+    serde(transparent)
+)]
 /// Wrapper type that indicates that the inner type is not equal to anything
 pub struct NoEq<T>(T);
 
@@ -169,6 +176,7 @@ where
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum ApplyOptions {
     /// Collect groups to a list and apply the function over the groups.
     /// This can be important in aggregation context.
@@ -180,6 +188,7 @@ pub enum ApplyOptions {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct WindowOptions {
     /// Explode the aggregated list and just do a hstack instead of a join
     /// this requires the groups to be sorted to make any sense
@@ -187,6 +196,7 @@ pub struct WindowOptions {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct FunctionOptions {
     /// Collect groups to a list and apply the function over the groups.
     /// This can be important in aggregation context.
@@ -206,9 +216,13 @@ pub struct FunctionOptions {
     ///
     /// this also accounts for regex expansion
     pub(crate) input_wildcard_expansion: bool,
+    /// Set if the function is a forward or backward fill. In a window context the fill
+    /// is then computed per group on the original rows instead of on aggregated lists.
+    pub(crate) fill_strategy: Option<FillNullStrategy>,
 }
 
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum AggExpr {
     Min(Box<Expr>),
     Max(Box<Expr>),
@@ -228,6 +242,13 @@ pub enum AggExpr {
     AggGroups(Box<Expr>),
     Std(Box<Expr>),
     Var(Box<Expr>),
+    #[cfg(feature = "approx")]
+    ApproxNUnique(Box<Expr>),
+    #[cfg(feature = "approx")]
+    ApproxQuantile {
+        expr: Box<Expr>,
+        quantile: f64,
+    },
 }
 
 impl AsRef<Expr> for AggExpr {
@@ -248,6 +269,10 @@ impl AsRef<Expr> for AggExpr {
             AggGroups(e) => e,
             Std(e) => e,
             Var(e) => e,
+            #[cfg(feature = "approx")]
+            ApproxNUnique(e) => e,
+            #[cfg(feature = "approx")]
+            ApproxQuantile { expr, .. } => expr,
         }
     }
 }
@@ -260,6 +285,7 @@ impl From<AggExpr> for Expr {
 
 /// Queries consists of multiple expressions.
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum Expr {
     Alias(Box<Expr>, Arc<str>),
     Column(Arc<str>),
@@ -355,11 +381,74 @@ pub enum Expr {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum Excluded {
     Name(Arc<str>),
     Dtype(DataType),
 }
 
+#[cfg(feature = "math")]
+macro_rules! impl_float_unary_expr {
+    ($($(#[$doc:meta])* $name:ident),* $(,)?) => {
+        impl Expr {
+            $(
+                $(#[$doc])*
+                #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+                pub fn $name(self) -> Self {
+                    self.map(move |s: Series| s.$name(), float_output())
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(feature = "math")]
+impl_float_unary_expr! {
+    /// Compute the logarithm to base 10.
+    log10,
+    /// Compute `ln(1 + x)`.
+    log1p,
+    /// Compute `e^x`.
+    exp,
+    /// Compute the square root.
+    sqrt,
+    /// Compute the cube root.
+    cbrt,
+    /// Compute the sine.
+    sin,
+    /// Compute the cosine.
+    cos,
+    /// Compute the tangent.
+    tan,
+    /// Compute the inverse sine.
+    arcsin,
+    /// Compute the inverse cosine.
+    arccos,
+    /// Compute the inverse tangent.
+    arctan,
+    /// Compute the hyperbolic sine.
+    sinh,
+    /// Compute the hyperbolic cosine.
+    cosh,
+    /// Compute the hyperbolic tangent.
+    tanh,
+    /// Compute the inverse hyperbolic sine.
+    arcsinh,
+    /// Compute the inverse hyperbolic cosine.
+    arccosh,
+    /// Compute the inverse hyperbolic tangent.
+    arctanh,
+}
+
+/// `Float32` stays `Float32`, everything else becomes `Float64`.
+#[cfg(feature = "math")]
+fn float_output() -> GetOutput {
+    GetOutput::map_dtype(|dt| match dt {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    })
+}
+
 impl Expr {
     /// Get Field result of the expression. The schema is the input data.
     pub(crate) fn to_field(&self, schema: &Schema, ctxt: Context) -> Result<Field> {
@@ -439,6 +528,10 @@ impl fmt::Debug for Expr {
                     Var(expr) => write!(f, "AGG VAR {:?}", expr),
                     Std(expr) => write!(f, "AGG STD {:?}", expr),
                     Quantile { expr, .. } => write!(f, "AGG QUANTILE {:?}", expr),
+                    #[cfg(feature = "approx")]
+                    ApproxNUnique(expr) => write!(f, "AGG APPROX N UNIQUE {:?}", expr),
+                    #[cfg(feature = "approx")]
+                    ApproxQuantile { expr, .. } => write!(f, "AGG APPROX QUANTILE {:?}", expr),
                 }
             }
             Cast {
@@ -474,6 +567,7 @@ impl fmt::Debug for Expr {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum Operator {
     Eq,
     NotEq,
@@ -491,6 +585,8 @@ pub enum Operator {
     And,
     Or,
     Xor,
+    ShiftLeft,
+    ShiftRight,
 }
 
 pub fn binary_expr(l: Expr, op: Operator, r: Expr) -> Expr {
@@ -660,7 +756,7 @@ impl Expr {
         binary_expr(self, Operator::LtEq, other)
     }
 
-    /// Negate `Expr`
+    /// Negate `Expr`, for integers all bits are flipped.
     #[allow(clippy::should_implement_trait)]
     pub fn not(self) -> Expr {
         Expr::Not(Box::new(self))
@@ -738,6 +834,24 @@ impl Expr {
         .into()
     }
 
+    /// Get the approximate number of unique values in the groups, estimated with HyperLogLog.
+    #[cfg(feature = "approx")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+    pub fn approx_n_unique(self) -> Self {
+        AggExpr::ApproxNUnique(Box::new(self)).into()
+    }
+
+    /// Compute the approximate quantile per group, estimated with a t-digest.
+    #[cfg(feature = "approx")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+    pub fn approx_quantile(self, quantile: f64) -> Self {
+        AggExpr::ApproxQuantile {
+            expr: Box::new(self),
+            quantile,
+        }
+        .into()
+    }
+
     /// Get the group indexes of the group by operation.
     pub fn agg_groups(self) -> Self {
         AggExpr::AggGroups(Box::new(self)).into()
@@ -821,6 +935,102 @@ impl Expr {
         )
     }
 
+    /// Count the occurrences of the unique values, as `UInt32`, or as the fraction of all values
+    /// if `normalize` is set. The counts are ordered by the first occurrence of their value,
+    /// or by descending count if `sort` is set. The matching values are given by
+    /// [value_counts_keys](Expr::value_counts_keys). Runs per group in a groupby context.
+    pub fn value_counts(self, sort: bool, normalize: bool) -> Self {
+        let dtype = if normalize {
+            DataType::Float64
+        } else {
+            DataType::UInt32
+        };
+        self.apply(
+            move |s: Series| {
+                let df = s.value_counts(sort, normalize)?;
+                let mut counts = df.select_at_idx(1).unwrap().clone();
+                counts.rename(s.name());
+                Ok(counts)
+            },
+            GetOutput::from_type(dtype),
+        )
+    }
+
+    /// Get the unique values in the order of [value_counts](Expr::value_counts).
+    ///
+    /// Without `sort` these are the unique values in order of first occurrence, which doesn't
+    /// need the counts. With `sort` the values are counted to determine their order.
+    pub fn value_counts_keys(self, sort: bool) -> Self {
+        self.apply(
+            move |s: Series| {
+                if sort {
+                    let df = s.value_counts(true, false)?;
+                    Ok(df.select_at_idx(0).unwrap().clone())
+                } else {
+                    let idx = s.arg_unique()?;
+                    // Safety:
+                    // the indices of the first occurrences are in bounds
+                    Ok(unsafe { s.take_unchecked(&idx)? })
+                }
+            },
+            GetOutput::same_type(),
+        )
+    }
+
+    /// Bin the values into the intervals defined by `breaks`. See [Series::cut].
+    ///
+    /// The labels are `Utf8`, so that they are the same in every group of a groupby context.
+    /// Cast the result to `Categorical` if needed.
+    #[cfg(feature = "binning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "binning")))]
+    pub fn cut(self, breaks: Vec<f64>, labels: Option<Vec<String>>) -> Self {
+        self.map(
+            move |s: Series| {
+                let labels = labels
+                    .as_ref()
+                    .map(|labels| labels.iter().map(|l| l.as_str()).collect::<Vec<_>>());
+                s.cut_utf8(&breaks, labels.as_deref())
+                    .map(|ca| ca.into_series())
+            },
+            GetOutput::from_type(DataType::Utf8),
+        )
+    }
+
+    /// Bin the values into intervals bounded by the `quantiles` of the data. The quantiles are
+    /// computed per group in a groupby context. See [Series::qcut].
+    ///
+    /// The labels are `Utf8`, see [cut](Expr::cut).
+    #[cfg(feature = "binning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "binning")))]
+    pub fn qcut(self, quantiles: Vec<f64>, labels: Option<Vec<String>>) -> Self {
+        self.apply(
+            move |s: Series| {
+                let labels = labels
+                    .as_ref()
+                    .map(|labels| labels.iter().map(|l| l.as_str()).collect::<Vec<_>>());
+                s.qcut_utf8(&quantiles, labels.as_deref())
+                    .map(|ca| ca.into_series())
+            },
+            GetOutput::from_type(DataType::Utf8),
+        )
+    }
+
+    /// Count the values in the histogram bins given by `bins` or `bin_count`. Returns the count
+    /// of every bin, per group in a groupby context. See [Series::hist].
+    #[cfg(feature = "binning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "binning")))]
+    pub fn hist(self, bins: Option<Vec<f64>>, bin_count: Option<usize>) -> Self {
+        self.apply(
+            move |s: Series| {
+                let df = s.hist(bins.as_deref(), bin_count)?;
+                let mut counts = df.column("count")?.clone();
+                counts.rename(s.name());
+                Ok(counts)
+            },
+            GetOutput::from_type(DataType::UInt32),
+        )
+    }
+
     /// Get the index values that would sort this expression.
     pub fn arg_sort(self, reverse: bool) -> Self {
         assert!(
@@ -906,6 +1116,7 @@ impl Expr {
             options: FunctionOptions {
                 collect_groups: ApplyOptions::ApplyFlat,
                 input_wildcard_expansion: false,
+                fill_strategy: None,
             },
         }
     }
@@ -930,6 +1141,7 @@ impl Expr {
             options: FunctionOptions {
                 collect_groups: ApplyOptions::ApplyList,
                 input_wildcard_expansion: false,
+                fill_strategy: None,
             },
         }
     }
@@ -956,6 +1168,7 @@ impl Expr {
             options: FunctionOptions {
                 collect_groups: ApplyOptions::ApplyGroups,
                 input_wildcard_expansion: false,
+                fill_strategy: None,
             },
         }
     }
@@ -1079,20 +1292,35 @@ impl Expr {
         )
     }
 
-    /// Fill missing value with next non-null.
-    pub fn backward_fill(self) -> Self {
-        self.apply(
-            move |s: Series| s.fill_null(FillNullStrategy::Backward),
-            GetOutput::same_type(),
-        )
+    fn fill_directional(self, strategy: FillNullStrategy) -> Self {
+        Expr::Function {
+            input: vec![self],
+            function: NoEq::new(Arc::new(move |s: &mut [Series]| {
+                std::mem::take(&mut s[0]).fill_null(strategy)
+            })),
+            output_type: GetOutput::same_type(),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ApplyGroups,
+                input_wildcard_expansion: false,
+                fill_strategy: Some(strategy),
+            },
+        }
     }
 
-    /// Fill missing value with previous non-null.
-    pub fn forward_fill(self) -> Self {
-        self.apply(
-            move |s: Series| s.fill_null(FillNullStrategy::Forward),
-            GetOutput::same_type(),
-        )
+    /// Fill missing value with next non-null, filling at most `limit` consecutive nulls.
+    ///
+    /// In a window context (`.over(..)`) the fill is done per group and the result is
+    /// aligned with the original rows.
+    pub fn backward_fill(self, limit: Option<usize>) -> Self {
+        self.fill_directional(FillNullStrategy::Backward(limit))
+    }
+
+    /// Fill missing value with previous non-null, filling at most `limit` consecutive nulls.
+    ///
+    /// In a window context (`.over(..)`) the fill is done per group and the result is
+    /// aligned with the original rows.
+    pub fn forward_fill(self, limit: Option<usize>) -> Self {
+        self.fill_directional(FillNullStrategy::Forward(limit))
     }
 
     /// Round underlying floating point array to given decimal numbers.
@@ -1109,6 +1337,13 @@ impl Expr {
         self.map(move |s: Series| s.floor(), GetOutput::same_type())
     }
 
+    /// Ceil underlying floating point array to the smallest integers greater or equal to the float value.
+    #[cfg(feature = "round_series")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    pub fn ceil(self) -> Self {
+        self.map(move |s: Series| s.ceil(), GetOutput::same_type())
+    }
+
     /// Convert all values to their absolute/positive value.
     #[cfg(feature = "abs")]
     #[cfg_attr(docsrs, doc(cfg(feature = "abs")))]
@@ -1116,6 +1351,39 @@ impl Expr {
         self.map(move |s: Series| s.abs(), GetOutput::same_type())
     }
 
+    /// Compute the logarithm to the given `base`.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn log(self, base: f64) -> Self {
+        self.map(move |s: Series| s.log(base), float_output())
+    }
+
+    /// Replace the values by `-1`, `0` or `1` depending on their sign.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn sign(self) -> Self {
+        self.map(move |s: Series| s.sign(), GetOutput::same_type())
+    }
+
+    /// Limit the values to the range `[min, max]`.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn clip(self, min: f64, max: f64) -> Self {
+        self.map(move |s: Series| s.clip(min, max), GetOutput::same_type())
+    }
+
+    /// Raise expression to the power of the values of `exponent`.
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn pow_expr(self, exponent: Expr) -> Self {
+        map_binary_lazy_field(
+            self,
+            exponent,
+            |s: Series, exponent: Series| s.pow_series(&exponent),
+            |_schema, _ctxt, l, _r| Some(Field::new(l.name(), DataType::Float64)),
+        )
+    }
+
     /// Apply window function over a subgroup.
     /// This is similar to a groupby + aggregation + self join.
     /// Or similar to [window functions in Postgres](https://www.postgresql.org/docs/9.1/tutorial-window.html).
@@ -1240,21 +1508,72 @@ impl Expr {
         Expr::IsUnique(Box::new(self))
     }
 
-    /// and operation
+    /// and operation, bitwise for integers
     pub fn and(self, expr: Expr) -> Self {
         binary_expr(self, Operator::And, expr)
     }
 
-    // xor operation
+    // xor operation, bitwise for integers
     pub fn xor(self, expr: Expr) -> Self {
         binary_expr(self, Operator::Xor, expr)
     }
 
-    /// or operation
+    /// or operation, bitwise for integers
     pub fn or(self, expr: Expr) -> Self {
         binary_expr(self, Operator::Or, expr)
     }
 
+    /// Shift the bits of an integer expression `n` positions to the left.
+    pub fn shift_left(self, n: Expr) -> Self {
+        binary_expr(self, Operator::ShiftLeft, n)
+    }
+
+    /// Shift the bits of an integer expression `n` positions to the right.
+    /// Signed integers are shifted arithmetically.
+    pub fn shift_right(self, n: Expr) -> Self {
+        binary_expr(self, Operator::ShiftRight, n)
+    }
+
+    /// Count the set bits of an integer expression.
+    pub fn count_ones(self) -> Self {
+        self.map(
+            |s: Series| s.count_ones(),
+            GetOutput::from_type(DataType::UInt32),
+        )
+    }
+
+    /// Count the unset bits of an integer expression.
+    pub fn count_zeros(self) -> Self {
+        self.map(
+            |s: Series| s.count_zeros(),
+            GetOutput::from_type(DataType::UInt32),
+        )
+    }
+
+    /// Count the leading unset bits of an integer expression.
+    pub fn leading_zeros(self) -> Self {
+        self.map(
+            |s: Series| s.leading_zeros(),
+            GetOutput::from_type(DataType::UInt32),
+        )
+    }
+
+    /// Count the trailing unset bits of an integer expression.
+    pub fn trailing_zeros(self) -> Self {
+        self.map(
+            |s: Series| s.trailing_zeros(),
+            GetOutput::from_type(DataType::UInt32),
+        )
+    }
+
+    /// Check if bit `n`, counted from the least significant bit, is set.
+    pub fn is_bit_set(self, n: u32) -> Self {
+        self.map(
+            move |s: Series| s.is_bit_set(n),
+            GetOutput::from_type(DataType::Boolean),
+        )
+    }
+
     /// Raise expression to the power `exponent`
     pub fn pow(self, exponent: f64) -> Self {
         self.map(
@@ -1509,6 +1828,26 @@ impl Expr {
         self.apply(|s| Ok(s.interpolate()), GetOutput::same_type())
     }
 
+    /// Interpolate None values with the given method and fill limit.
+    /// See [Series::interpolate_with](polars_core::series::Series::interpolate_with).
+    #[cfg(feature = "interpolate")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "interpolate")))]
+    pub fn interpolate_with(self, options: InterpolateOptions) -> Expr {
+        self.apply(
+            move |s| s.interpolate_with(options, None),
+            GetOutput::same_type(),
+        )
+    }
+
+    /// Interpolate None values weighted by the positions in `by`, e.g. a datetime column
+    /// of irregular timestamps. `by` should be sorted in ascending order.
+    #[cfg(feature = "interpolate")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "interpolate")))]
+    pub fn interpolate_by(self, by: Expr, options: InterpolateOptions) -> Expr {
+        let function = move |s: Series, by: Series| s.interpolate_with(options, Some(&by));
+        map_binary_lazy_field(self, by, function, |_schema, _ctxt, l, _r| Some(l.clone()))
+    }
+
     /// Apply a rolling min See:
     /// [ChunkedArray::rolling_min](polars::prelude::ChunkWindow::rolling_min).
     #[cfg_attr(docsrs, doc(cfg(feature = "rolling_window")))]
@@ -1822,6 +2161,27 @@ impl Expr {
         self.apply(move |s| Ok(s.shuffle(seed)), GetOutput::same_type())
     }
 
+    /// Sample `n` values. In a groupby context every group is sampled, which gives a stratified
+    /// sample. The sampled positions only depend on `n`, the length of the group and `seed`,
+    /// so columns sampled with the same arguments keep their rows together.
+    #[cfg(feature = "random")]
+    pub fn sample_n(self, n: usize, with_replacement: bool, seed: u64) -> Self {
+        self.apply(
+            move |s| s.sample_n(n, with_replacement, seed),
+            GetOutput::same_type(),
+        )
+    }
+
+    /// Sample a fraction between 0.0-1.0 of the values. In a groupby context every group is
+    /// sampled, which gives a stratified sample. [See sample_n](Expr::sample_n).
+    #[cfg(feature = "random")]
+    pub fn sample_frac(self, frac: f64, with_replacement: bool, seed: u64) -> Self {
+        self.apply(
+            move |s| s.sample_frac(frac, with_replacement, seed),
+            GetOutput::same_type(),
+        )
+    }
+
     #[cfg(feature = "ewma")]
     pub fn ewm_mean(self, options: EWMOptions) -> Self {
         use DataType::*;
@@ -1857,6 +2217,32 @@ impl Expr {
             }),
         )
     }
+
+    /// Exponentially weighted moving average of irregularly spaced values, weighted by the
+    /// time in `by`. See [Series::ewm_mean_by](polars_core::series::Series::ewm_mean_by).
+    #[cfg(all(feature = "ewma", feature = "dtype-datetime"))]
+    pub fn ewm_mean_by(self, by: Expr, half_life: Duration, options: EWMOptions) -> Self {
+        let function = move |s: Series, by: Series| s.ewm_mean_by(&by, half_life, options);
+        map_binary_lazy_field(self, by, function, |_schema, _ctxt, l, _r| {
+            let dtype = match l.data_type() {
+                DataType::Float32 => DataType::Float32,
+                _ => DataType::Float64,
+            };
+            Some(Field::new(l.name(), dtype))
+        })
+    }
+
+    #[cfg(feature = "ewma")]
+    pub fn ewm_cov(self, other: Expr, options: EWMOptions) -> Self {
+        let function = move |s: Series, other: Series| s.ewm_cov(&other, options);
+        map_binary_lazy_field(self, other, function, ewm_pair_field)
+    }
+
+    #[cfg(feature = "ewma")]
+    pub fn ewm_corr(self, other: Expr, options: EWMOptions) -> Self {
+        let function = move |s: Series, other: Series| s.ewm_corr(&other, options);
+        map_binary_lazy_field(self, other, function, ewm_pair_field)
+    }
 }
 
 /// Create a Column Expression based on a column name.
@@ -1949,6 +2335,15 @@ pub fn quantile(name: &str, quantile: f64, interpol: QuantileInterpolOptions) ->
 }
 
 /// Apply a closure on the two columns that are evaluated from `Expr` a and `Expr` b.
+#[cfg(feature = "ewma")]
+fn ewm_pair_field(_schema: &Schema, _ctxt: Context, l: &Field, r: &Field) -> Option<Field> {
+    let dtype = match (l.data_type(), r.data_type()) {
+        (DataType::Float32, DataType::Float32) => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    Some(Field::new(l.name(), dtype))
+}
+
 pub fn map_binary<F: 'static>(a: Expr, b: Expr, f: F, output_field: Option<Field>) -> Expr
 where
     F: Fn(Series, Series) -> Result<Series> + Send + Sync,
@@ -2008,6 +2403,7 @@ where
             options: FunctionOptions {
                 collect_groups: ApplyOptions::ApplyFlat,
                 input_wildcard_expansion: true,
+                fill_strategy: None,
             },
         }
     } else {
@@ -2179,6 +2575,7 @@ where
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyFlat,
             input_wildcard_expansion: false,
+            fill_strategy: None,
         },
     }
 }
@@ -2204,6 +2601,7 @@ where
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyList,
             input_wildcard_expansion: false,
+            fill_strategy: None,
         },
     }
 }
@@ -2231,6 +2629,7 @@ where
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyGroups,
             input_wildcard_expansion: false,
+            fill_strategy: None,
         },
     }
 }
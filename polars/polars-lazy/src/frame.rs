@@ -5,18 +5,25 @@ use polars_core::frame::groupby::DynamicGroupOptions;
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-categorical")]
-use polars_core::toggle_string_cache;
+use polars_core::StringCacheGuard;
+use polars_core::POOL;
 use std::sync::Arc;
 
 use crate::logical_plan::optimizer::aggregate_pushdown::AggregatePushdown;
 #[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use crate::logical_plan::optimizer::aggregate_scan_projections::AggScanProjection;
+use crate::logical_plan::optimizer::common_subexpr::eliminate_common_subexprs;
+use crate::logical_plan::optimizer::common_subplan::eliminate_common_subplans;
+use crate::logical_plan::optimizer::join_reorder::{reorder_joins, Statistics};
 use crate::logical_plan::optimizer::simplify_expr::SimplifyExprRule;
+use crate::logical_plan::optimizer::slice_pushdown::SlicePushDown;
 use crate::logical_plan::optimizer::stack_opt::{OptimizationRule, StackOptimizer};
 use crate::logical_plan::optimizer::{
     predicate_pushdown::PredicatePushDown, projection_pushdown::ProjectionPushDown,
 };
+use crate::logical_plan::record_step;
 use crate::logical_plan::ScanOptions;
+use crate::physical_plan::node_timer::NodeTimer;
 use crate::physical_plan::state::ExecutionState;
 #[cfg(any(feature = "parquet", feature = "csv-file"))]
 use crate::prelude::aggregate_scan_projections::agg_projection;
@@ -30,6 +37,8 @@ use polars_io::csv::NullValues;
 use polars_io::csv_core::utils::get_reader_bytes;
 #[cfg(feature = "csv-file")]
 use polars_io::csv_core::utils::infer_file_schema;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone)]
 #[cfg(feature = "csv-file")]
@@ -48,6 +57,7 @@ pub struct LazyCsvReader<'a> {
     quote_char: Option<u8>,
     null_values: Option<NullValues>,
     infer_schema_length: Option<usize>,
+    reservoir_sample: Option<(usize, u64)>,
 }
 
 #[cfg(feature = "csv-file")]
@@ -68,6 +78,7 @@ impl<'a> LazyCsvReader<'a> {
             quote_char: Some(b'"'),
             null_values: None,
             infer_schema_length: Some(100),
+            reservoir_sample: None,
         }
     }
 
@@ -78,6 +89,18 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Only read a uniform random sample of at most `n` rows, using reservoir sampling.
+    /// [See eager with_reservoir_sample](polars_io::csv::CsvReader::with_reservoir_sample).
+    ///
+    /// Filters and slices on the scan are applied to the sample, they are not pushed
+    /// down into the scan.
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    pub fn with_reservoir_sample(mut self, n: Option<usize>, seed: u64) -> Self {
+        self.reservoir_sample = n.map(|n| (n, seed));
+        self
+    }
+
     /// Set the number of rows to use when inferring the csv schema.
     /// the default is 100 rows.
     /// Setting to `None` will do a full table scan, very slow.
@@ -193,6 +216,7 @@ impl<'a> LazyCsvReader<'a> {
             self.quote_char,
             self.null_values,
             self.infer_schema_length,
+            self.reservoir_sample,
         )?
         .build()
         .into();
@@ -202,6 +226,7 @@ impl<'a> LazyCsvReader<'a> {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct JoinOptions {
     pub allow_parallel: bool,
     pub force_parallel: bool,
@@ -209,6 +234,13 @@ pub struct JoinOptions {
     pub suffix: Option<String>,
     pub asof_by_left: Vec<String>,
     pub asof_by_right: Vec<String>,
+    /// Validation, coalescing and null matching of the join keys.
+    pub key_options: JoinKeyOptions,
+    #[cfg(feature = "asof_join")]
+    pub asof_options: AsOfOptions,
+    /// Inequality predicates that must hold in addition to the equality of the join keys.
+    #[cfg(feature = "range_join")]
+    pub range_predicates: Vec<InequalityPredicate>,
 }
 
 impl Default for JoinOptions {
@@ -220,10 +252,32 @@ impl Default for JoinOptions {
             suffix: None,
             asof_by_left: vec![],
             asof_by_right: vec![],
+            key_options: Default::default(),
+            #[cfg(feature = "asof_join")]
+            asof_options: Default::default(),
+            #[cfg(feature = "range_join")]
+            range_predicates: vec![],
         }
     }
 }
 
+/// Options for [LazyFrame::pivot].
+#[derive(Clone, Debug, Default)]
+pub struct PivotOptions {
+    /// Columns whose values identify the rows of the output.
+    pub index: Vec<String>,
+    /// Column whose values become the names of the new columns.
+    pub columns: String,
+    /// Columns whose values are aggregated into the new columns.
+    /// If empty, all columns that are not in `index` or `columns` are used.
+    pub values: Vec<String>,
+    /// The values of `columns` that become new columns. If `None`, the distinct values are
+    /// determined by executing the query up to the pivot once.
+    pub column_values: Option<Vec<String>>,
+    /// Keep the rows in the order of the first occurrence of their index values.
+    pub maintain_order: bool,
+}
+
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
 }
@@ -265,6 +319,21 @@ pub struct OptState {
     pub aggregate_pushdown: bool,
     pub global_string_cache: bool,
     pub join_pruning: bool,
+    /// Execute identical subplans only once
+    pub common_subplan_elimination: bool,
+    /// Evaluate repeated subexpressions in a projection or aggregation only once
+    pub common_subexpr_elimination: bool,
+    /// Only read and sort the rows that are needed for a slice
+    pub slice_pushdown: bool,
+    /// Reorder chains of inner joins to keep the intermediate results small. Off by default,
+    /// as the order of the output rows changes
+    pub join_reorder: bool,
+    /// Let join reordering read the number of rows of scanned files. Off by default, as the
+    /// files are read during optimization
+    pub file_statistics: bool,
+    /// The maximum number of independent branches of the plan, e.g. the inputs of a union,
+    /// that are executed concurrently
+    pub max_parallel_branches: usize,
 }
 
 impl Default for OptState {
@@ -276,6 +345,12 @@ impl Default for OptState {
             simplify_expr: true,
             global_string_cache: true,
             join_pruning: true,
+            common_subplan_elimination: true,
+            common_subexpr_elimination: true,
+            slice_pushdown: true,
+            join_reorder: false,
+            file_statistics: false,
+            max_parallel_branches: POOL.current_num_threads(),
             // will be toggled by a scan operation such as csv scan or parquet scan
             agg_scan_projection: false,
             aggregate_pushdown: false,
@@ -397,6 +472,51 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle common subplan elimination. Subplans that occur more than once are only executed once.
+    pub fn with_common_subplan_elimination(mut self, toggle: bool) -> Self {
+        self.opt_state.common_subplan_elimination = toggle;
+        self
+    }
+
+    /// Toggle common subexpression elimination. Subexpressions that occur more than once in
+    /// a `select`, `with_columns` or `agg` are only evaluated once.
+    pub fn with_common_subexpr_elimination(mut self, toggle: bool) -> Self {
+        self.opt_state.common_subexpr_elimination = toggle;
+        self
+    }
+
+    /// Toggle slice pushdown optimization. A slice, e.g. by `limit`, is pushed down to the
+    /// scans, and a sort followed by a slice only sorts the rows that are needed.
+    pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
+        self.opt_state.slice_pushdown = toggle;
+        self
+    }
+
+    /// Toggle join reordering. Chains of inner joins are executed in the order that is
+    /// estimated to produce the smallest intermediate results. The output has the same rows,
+    /// but not necessarily in the same order. Off by default.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.join_reorder = toggle;
+        self
+    }
+
+    /// Toggle reading the number of rows of scanned parquet and csv files for join
+    /// reordering. Without it, only joins of in-memory tables are reordered. Off by default.
+    pub fn with_file_statistics(mut self, toggle: bool) -> Self {
+        self.opt_state.file_statistics = toggle;
+        self
+    }
+
+    /// Set the maximum number of independent branches of the plan that are executed
+    /// concurrently, e.g. the inputs of a `concat` or the sides of a join.
+    /// A lower limit reduces the number of intermediate results that are in memory at the
+    /// same time. Setting it to `1` executes all branches sequentially.
+    /// Defaults to the number of threads of the thread pool.
+    pub fn with_max_parallel_branches(mut self, max_parallel_branches: usize) -> Self {
+        self.opt_state.max_parallel_branches = max_parallel_branches;
+        self
+    }
+
     /// Toggle global string cache.
     pub fn with_string_cache(mut self, toggle: bool) -> Self {
         self.opt_state.global_string_cache = toggle;
@@ -423,6 +543,50 @@ impl LazyFrame {
         Ok(logical_plan.describe())
     }
 
+    /// Serialize the logical plan to JSON, e.g. to execute it in another process.
+    ///
+    /// In memory `DataFrame`s are serialized with the plan. Plans that contain closures,
+    /// e.g. from `map` or `apply`, cannot be serialized and return an error.
+    #[cfg(feature = "serde-lazy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde-lazy")))]
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(&self.logical_plan).map_err(|e| {
+            PolarsError::ComputeError(format!("could not serialize the query: {}", e).into())
+        })
+    }
+
+    /// Create a `LazyFrame` from a logical plan serialized with [`to_json`](LazyFrame::to_json).
+    #[cfg(feature = "serde-lazy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde-lazy")))]
+    pub fn from_json(json: &str) -> Result<LazyFrame> {
+        let lp = serde_json::from_str::<LogicalPlan>(json).map_err(|e| {
+            PolarsError::ComputeError(format!("could not deserialize the query: {}", e).into())
+        })?;
+        Ok(lp.into())
+    }
+
+    /// Serialize the logical plan to a compact binary format (bincode).
+    ///
+    /// In memory `DataFrame`s are serialized with the plan. Plans that contain closures,
+    /// e.g. from `map` or `apply`, cannot be serialized and return an error.
+    #[cfg(feature = "serde-lazy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde-lazy")))]
+    pub fn to_binary(&self) -> Result<Vec<u8>> {
+        bincode::serialize(&self.logical_plan).map_err(|e| {
+            PolarsError::ComputeError(format!("could not serialize the query: {}", e).into())
+        })
+    }
+
+    /// Create a `LazyFrame` from a logical plan serialized with [`to_binary`](LazyFrame::to_binary).
+    #[cfg(feature = "serde-lazy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde-lazy")))]
+    pub fn from_binary(bytes: &[u8]) -> Result<LazyFrame> {
+        let lp = bincode::deserialize::<LogicalPlan>(bytes).map_err(|e| {
+            PolarsError::ComputeError(format!("could not deserialize the query: {}", e).into())
+        })?;
+        Ok(lp.into())
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// # Example
@@ -600,11 +764,56 @@ impl LazyFrame {
         res
     }
 
+    /// Optimize the query and record the plan after every optimization pass that changed it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<()> {
+    ///     let explain = df.lazy()
+    ///       .filter(col("foo").gt(lit(1)))
+    ///       .select([col("foo")])
+    ///       .explain()?;
+    ///     println!("{}", explain.optimized_plan());
+    ///     println!("{}", explain.diff());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn explain(&self) -> Result<Explain> {
+        let mut expr_arena = Arena::with_capacity(512);
+        let mut lp_arena = Arena::with_capacity(512);
+        let mut steps = vec![];
+        self.clone()
+            .optimize_with_trace(&mut lp_arena, &mut expr_arena, Some(&mut steps))?;
+        Ok(Explain { steps })
+    }
+
     pub fn optimize(
         self,
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
     ) -> Result<Node> {
+        self.optimize_with_trace(lp_arena, expr_arena, None)
+    }
+
+    fn optimize_with_trace(
+        self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        mut trace: Option<&mut Vec<OptimizationStep>>,
+    ) -> Result<Node> {
+        let mut trace_step = |pass: &str,
+                              lp_top: Node,
+                              lp_arena: &Arena<ALogicalPlan>,
+                              expr_arena: &Arena<AExpr>| {
+            if let Some(steps) = trace.as_deref_mut() {
+                record_step(steps, pass, lp_top, lp_arena, expr_arena)
+            }
+        };
+
         // get toggle values
         let predicate_pushdown = self.opt_state.predicate_pushdown;
         let projection_pushdown = self.opt_state.projection_pushdown;
@@ -614,11 +823,18 @@ impl LazyFrame {
         #[cfg(any(feature = "parquet", feature = "csv-file"))]
         let agg_scan_projection = self.opt_state.agg_scan_projection;
         let aggregate_pushdown = self.opt_state.aggregate_pushdown;
+        let common_subplan_elimination = self.opt_state.common_subplan_elimination;
+        let common_subexpr_elimination = self.opt_state.common_subexpr_elimination;
+        let slice_pushdown = self.opt_state.slice_pushdown;
+        let join_reorder = self.opt_state.join_reorder;
+        let file_statistics = self.opt_state.file_statistics;
 
         let logical_plan = self.get_plan_builder().build();
 
         // gradually fill the rules passed to the optimizer
         let mut rules: Vec<Box<dyn OptimizationRule>> = Vec::with_capacity(8);
+        // the names of the rules in the optimizer trace
+        let mut rule_names = Vec::with_capacity(8);
 
         let predicate_pushdown_opt = PredicatePushDown::default();
         let projection_pushdown_opt = ProjectionPushDown {};
@@ -628,6 +844,7 @@ impl LazyFrame {
         let prev_schema = logical_plan.schema().clone();
 
         let mut lp_top = to_alp(logical_plan, expr_arena, lp_arena);
+        trace_step("unoptimized", lp_top, lp_arena, expr_arena);
 
         if projection_pushdown {
             let alp = lp_arena.take(lp_top);
@@ -635,6 +852,7 @@ impl LazyFrame {
                 .optimize(alp, lp_arena, expr_arena)
                 .expect("projection pushdown failed");
             lp_arena.replace(lp_top, alp);
+            trace_step("projection_pushdown", lp_top, lp_arena, expr_arena);
         }
 
         if predicate_pushdown {
@@ -643,18 +861,38 @@ impl LazyFrame {
                 .optimize(alp, lp_arena, expr_arena)
                 .expect("predicate pushdown failed");
             lp_arena.replace(lp_top, alp);
+            trace_step("predicate_pushdown", lp_top, lp_arena, expr_arena);
+        }
+
+        if slice_pushdown {
+            let alp = lp_arena.take(lp_top);
+            let alp = SlicePushDown::default()
+                .optimize(alp, lp_arena, expr_arena)
+                .expect("slice pushdown failed");
+            lp_arena.replace(lp_top, alp);
+            trace_step("slice_pushdown", lp_top, lp_arena, expr_arena);
+        }
+
+        if join_reorder {
+            let mut stats = Statistics::new(file_statistics);
+            reorder_joins(lp_top, lp_arena, expr_arena, &mut stats);
+            trace_step("join_reorder", lp_top, lp_arena, expr_arena);
         }
 
         if type_coercion {
-            rules.push(Box::new(TypeCoercionRule {}))
+            rules.push(Box::new(TypeCoercionRule {}));
+            rule_names.push("type_coercion");
         }
 
         if simplify_expr {
             rules.push(Box::new(SimplifyExprRule {}));
+            rule_names.push("simplify_expr");
             rules.push(Box::new(SimplifyBooleanRule {}));
+            rule_names.push("simplify_boolean");
         }
         if aggregate_pushdown {
-            rules.push(Box::new(AggregatePushdown::new()))
+            rules.push(Box::new(AggregatePushdown::new()));
+            rule_names.push("aggregate_pushdown");
         }
 
         #[cfg(any(feature = "parquet", feature = "csv-file"))]
@@ -666,13 +904,36 @@ impl LazyFrame {
 
             let opt = AggScanProjection { columns };
             rules.push(Box::new(opt));
+            rule_names.push("agg_scan_projection");
         }
 
         rules.push(Box::new(FastProjection {}));
+        rule_names.push("fast_projection");
         rules.push(Box::new(ReplaceDropNulls {}));
+        rule_names.push("replace_drop_nulls");
 
         let opt = StackOptimizer {};
-        lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top);
+        let root = lp_top;
+        lp_top = opt.optimize_loop_traced(
+            &mut rules,
+            expr_arena,
+            lp_arena,
+            root,
+            &mut |rule, lp_arena, expr_arena| {
+                trace_step(rule_names[rule], root, lp_arena, expr_arena)
+            },
+        );
+
+        if common_subexpr_elimination {
+            eliminate_common_subexprs(lp_top, lp_arena, expr_arena);
+            trace_step("common_subexpr_elimination", lp_top, lp_arena, expr_arena);
+        }
+
+        // should run last, as the other optimizations don't know that cached subplans are shared.
+        if common_subplan_elimination {
+            eliminate_common_subplans(lp_top, lp_arena, expr_arena);
+            trace_step("common_subplan_elimination", lp_top, lp_arena, expr_arena);
+        }
 
         // during debug we check if the optimizations have not modified the final schema
         #[cfg(debug_assertions)]
@@ -714,28 +975,64 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn collect(self) -> Result<DataFrame> {
+        let state =
+            ExecutionState::new().with_max_parallel_branches(self.opt_state.max_parallel_branches);
+        self.execute(&state)
+    }
+
+    /// Execute the query like [collect](LazyFrame::collect) and profile the execution.
+    ///
+    /// Returns the result and a [DataFrame](polars_core::frame::DataFrame) with a row per node of
+    /// the physical plan with the columns:
+    /// - `node`: the name of the node.
+    /// - `start` and `end`: the time in microseconds since the start of the query.
+    ///   The time spent in the inputs of a node is not included.
+    /// - `rows_in` and `rows_out`: the number of rows of the inputs and the output.
+    /// - `peak_bytes`: the estimated size of the inputs and the output of the node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<()> {
+    ///     let (_out, profile) = df.lazy()
+    ///       .groupby([col("foo")])
+    ///       .agg([col("bar").sum()])
+    ///       .profile()?;
+    ///     println!("{}", profile);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn profile(self) -> Result<(DataFrame, DataFrame)> {
+        let mut state =
+            ExecutionState::new().with_max_parallel_branches(self.opt_state.max_parallel_branches);
+        let timer = NodeTimer::new();
+        state.node_timer = Some(timer.clone());
+        let out = self.execute(&state)?;
+        Ok((out, timer.finish()?))
+    }
+
+    fn execute(self, state: &ExecutionState) -> Result<DataFrame> {
+        // the string cache is kept alive until the result is produced; concurrent queries
+        // hold their own guard, so they don't turn off each other's cache
         #[cfg(feature = "dtype-categorical")]
-        let use_string_cache = self.opt_state.global_string_cache;
+        let _cache_guard = if self.opt_state.global_string_cache {
+            Some(StringCacheGuard::new())
+        } else {
+            None
+        };
+        let top_k_sort = self.opt_state.slice_pushdown;
         let mut expr_arena = Arena::with_capacity(256);
         let mut lp_arena = Arena::with_capacity(128);
         let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
 
-        // if string cache was already set, we skip this and global settings are respected
-        #[cfg(feature = "dtype-categorical")]
-        if use_string_cache {
-            toggle_string_cache(use_string_cache);
-        }
-        let planner = DefaultPlanner::default();
+        let planner = DefaultPlanner { top_k_sort };
         let mut physical_plan =
             planner.create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
 
-        let state = ExecutionState::new();
-        let out = physical_plan.execute(&state);
-        #[cfg(feature = "dtype-categorical")]
-        if use_string_cache {
-            toggle_string_cache(!use_string_cache);
-        }
-        out
+        physical_plan.execute(state)
     }
 
     /// Filter by some predicate expression.
@@ -905,6 +1202,134 @@ impl LazyFrame {
         self.join(other, vec![left_on], vec![right_on], JoinType::Inner)
     }
 
+    /// Filter the rows of this query to the rows that have a matching key in the other query.
+    /// No columns of the other query are added.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn join_dataframes(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///         ldf
+    ///         .semi_join(other, col("foo"), col("bar"))
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    pub fn semi_join(self, other: LazyFrame, left_on: Expr, right_on: Expr) -> LazyFrame {
+        self.join(other, vec![left_on], vec![right_on], JoinType::Semi)
+    }
+
+    /// Filter the rows of this query to the rows that don't have a matching key in the other query.
+    /// No columns of the other query are added.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn join_dataframes(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///         ldf
+    ///         .anti_join(other, col("foo"), col("bar"))
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    pub fn anti_join(self, other: LazyFrame, left_on: Expr, right_on: Expr) -> LazyFrame {
+        self.join(other, vec![left_on], vec![right_on], JoinType::Anti)
+    }
+
+    /// Join on arbitrary comparison predicates between a column of this query and a column
+    /// of the other query, e.g. `col("ts").gt_eq(col("start"))`. The left operand of every
+    /// predicate refers to this query, the right operand to the other query.
+    ///
+    /// Equality predicates are used as join keys, the others are evaluated with a sort-based
+    /// range join.
+    ///
+    /// Returns an error if a predicate is not a comparison between two columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn join_intervals(events: LazyFrame, intervals: LazyFrame) -> Result<LazyFrame> {
+    ///         events
+    ///         .join_where(intervals, vec![
+    ///             col("ts").gt_eq(col("start")),
+    ///             col("ts").lt(col("end")),
+    ///         ])
+    /// }
+    /// ```
+    #[cfg(feature = "range_join")]
+    pub fn join_where(self, other: LazyFrame, predicates: Vec<Expr>) -> Result<LazyFrame> {
+        let opt_state = self.opt_state;
+        let mut left_on = vec![];
+        let mut right_on = vec![];
+        let mut range_predicates = vec![];
+
+        for predicate in predicates {
+            let (left, op, right) = match &predicate {
+                Expr::BinaryExpr { left, op, right } => match (&**left, &**right) {
+                    (Expr::Column(left), Expr::Column(right)) => (left.clone(), *op, right.clone()),
+                    _ => {
+                        return Err(PolarsError::InvalidOperation(
+                            format!(
+                                "join_where predicates should compare two columns, got: {:?}",
+                                predicate
+                            )
+                            .into(),
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(PolarsError::InvalidOperation(
+                        format!(
+                            "join_where predicates should compare two columns, got: {:?}",
+                            predicate
+                        )
+                        .into(),
+                    ))
+                }
+            };
+            let op = match op {
+                Operator::Eq => {
+                    left_on.push(Expr::Column(left));
+                    right_on.push(Expr::Column(right));
+                    continue;
+                }
+                Operator::Lt => InequalityOperator::Lt,
+                Operator::LtEq => InequalityOperator::LtEq,
+                Operator::Gt => InequalityOperator::Gt,
+                Operator::GtEq => InequalityOperator::GtEq,
+                _ => {
+                    return Err(PolarsError::InvalidOperation(
+                        format!(
+                            "operator {:?} is not supported in join_where, got: {:?}",
+                            op, predicate
+                        )
+                        .into(),
+                    ))
+                }
+            };
+            range_predicates.push(InequalityPredicate::new(&left, op, &right));
+        }
+
+        let lp = self
+            .get_plan_builder()
+            .join(
+                other.logical_plan,
+                left_on,
+                right_on,
+                JoinOptions {
+                    how: JoinType::Inner,
+                    range_predicates,
+                    ..Default::default()
+                },
+            )
+            .build();
+        Ok(LazyFrame::from_logical_plan(lp, opt_state))
+    }
+
     /// Creates the cartesian product from both frames, preserves the order of the left keys.
     #[cfg(feature = "cross_join")]
     pub fn cross_join(self, other: LazyFrame) -> LazyFrame {
@@ -1030,6 +1455,46 @@ impl LazyFrame {
         self.select_local(vec![col("*").var()])
     }
 
+    /// Execute the query and compute summary statistics of the columns.
+    /// [See eager describe](polars_core::frame::DataFrame::describe).
+    #[cfg(feature = "describe")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "describe")))]
+    pub fn describe(self, percentiles: Option<&[f64]>) -> Result<DataFrame> {
+        self.collect()?.describe(percentiles)
+    }
+
+    /// Sample `n` rows. [See eager sample_n](polars_core::frame::DataFrame::sample_n).
+    ///
+    /// To sample a csv file without reading it into memory, see
+    /// [LazyCsvReader::with_reservoir_sample].
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    pub fn sample_n(self, n: usize, with_replacement: bool, seed: u64) -> LazyFrame {
+        self.map(
+            move |df: DataFrame| df.sample_n(n, with_replacement, seed),
+            Some(AllowedOptimizations {
+                predicate_pushdown: false,
+                ..Default::default()
+            }),
+            None,
+        )
+    }
+
+    /// Sample a fraction between 0.0-1.0 of the rows.
+    /// [See eager sample_frac](polars_core::frame::DataFrame::sample_frac).
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    pub fn sample_frac(self, frac: f64, with_replacement: bool, seed: u64) -> LazyFrame {
+        self.map(
+            move |df: DataFrame| df.sample_frac(frac, with_replacement, seed),
+            Some(AllowedOptimizations {
+                predicate_pushdown: false,
+                ..Default::default()
+            }),
+            None,
+        )
+    }
+
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
     pub fn explode(self, columns: Vec<Expr>) -> LazyFrame {
         let opt_state = self.get_opt_state();
@@ -1086,14 +1551,116 @@ impl LazyFrame {
 
     /// Melt the DataFrame from wide to long format
     pub fn melt(self, id_vars: Vec<String>, value_vars: Vec<String>) -> LazyFrame {
+        self.melt_with_args(MeltArgs {
+            id_vars,
+            value_vars,
+            ..Default::default()
+        })
+    }
+
+    /// Melt the DataFrame from wide to long format with custom names for the `variable` and
+    /// `value` columns. The melted columns can also be selected by data type.
+    /// See [DataFrame::melt_with_args](polars_core::frame::DataFrame::melt_with_args).
+    ///
+    /// Collecting the query fails if a column is not found or if no columns are selected to melt.
+    pub fn melt_with_args(self, args: MeltArgs) -> LazyFrame {
         let opt_state = self.get_opt_state();
-        let lp = self
-            .get_plan_builder()
-            .melt(Arc::new(id_vars), Arc::new(value_vars))
-            .build();
+        let lp = self.get_plan_builder().melt(args).build();
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Pivot the DataFrame from long to wide format.
+    ///
+    /// Every row of the output is a distinct combination of the `index` columns and every value
+    /// of the `columns` column becomes a new column. The new columns are computed by applying
+    /// `agg` to the values of a `values` column that belong to the row and the new column.
+    /// The new columns are named after the values of the `columns` column. With multiple
+    /// `values` columns they are named `{value}_{columns}_{column_value}`.
+    ///
+    /// The schema of the output depends on the data. If the `column_values` are not given,
+    /// the query up to the pivot is executed once to determine the distinct values.
+    ///
+    /// Note that `agg` is also applied to the groups without values, e.g. `sum` returns `0`
+    /// for them and `first` returns `null`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.lazy()
+    ///         .pivot(
+    ///             PivotOptions {
+    ///                 index: vec!["foo".into()],
+    ///                 columns: "bar".into(),
+    ///                 values: vec!["N".into()],
+    ///                 ..Default::default()
+    ///             },
+    ///             |values| values.sum(),
+    ///         )?
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn pivot<F>(self, options: PivotOptions, agg: F) -> Result<LazyFrame>
+    where
+        F: Fn(Expr) -> Expr,
+    {
+        // pivot values are matched by their string representation as they become column names
+        let pivot_column = col(&options.columns).cast(DataType::Utf8);
+        let column_values = match options.column_values {
+            Some(column_values) => column_values,
+            None => {
+                let df = self.clone().select([pivot_column.clone()]).collect()?;
+                let ca = df.get_columns()[0].utf8()?;
+                let mut seen = PlHashSet::new();
+                ca.into_iter()
+                    .flatten()
+                    .filter(|v| seen.insert(*v))
+                    .map(|v| v.to_string())
+                    .collect()
+            }
+        };
+        let values = if options.values.is_empty() {
+            self.schema()
+                .fields()
+                .iter()
+                .map(|fld| fld.name())
+                .filter(|name| **name != options.columns && !options.index.contains(name))
+                .cloned()
+                .collect()
+        } else {
+            options.values
+        };
+
+        let mut aggs = Vec::with_capacity(values.len() * column_values.len());
+        for value in &values {
+            for column_value in &column_values {
+                let name = if values.len() == 1 {
+                    column_value.clone()
+                } else {
+                    format!("{}_{}_{}", value, options.columns, column_value)
+                };
+                let pivoted =
+                    col(value).filter(pivot_column.clone().eq(lit(column_value.as_str())));
+                aggs.push(agg(pivoted).alias(&name))
+            }
+        }
+
+        let keys = options
+            .index
+            .iter()
+            .map(|name| col(name))
+            .collect::<Vec<_>>();
+        let gb = if options.maintain_order {
+            self.groupby_stable(keys)
+        } else {
+            self.groupby(keys)
+        };
+        Ok(gb.agg(aggs))
+    }
+
     /// Limit the DataFrame to the first `n` rows. Note if you don't want the rows to be scanned,
     /// use [fetch](LazyFrame::fetch).
     pub fn limit(self, n: usize) -> LazyFrame {
@@ -1247,6 +1814,9 @@ pub struct JoinBuilder {
     suffix: Option<String>,
     asof_by_left: Vec<String>,
     asof_by_right: Vec<String>,
+    key_options: JoinKeyOptions,
+    #[cfg(feature = "asof_join")]
+    asof_options: AsOfOptions,
 }
 impl JoinBuilder {
     fn new(lf: LazyFrame) -> Self {
@@ -1261,6 +1831,9 @@ impl JoinBuilder {
             suffix: None,
             asof_by_left: vec![],
             asof_by_right: vec![],
+            key_options: Default::default(),
+            #[cfg(feature = "asof_join")]
+            asof_options: Default::default(),
         }
     }
 
@@ -1306,6 +1879,29 @@ impl JoinBuilder {
         self
     }
 
+    /// Check the uniqueness of the join keys. The join fails with examples of the
+    /// duplicated keys if the validation does not hold.
+    /// Defaults to [`JoinValidation::ManyToMany`], which does no check.
+    pub fn validate(mut self, validate: JoinValidation) -> Self {
+        self.key_options.validate = validate;
+        self
+    }
+
+    /// Combine the key columns of both tables into a single column in an outer join.
+    /// If `false`, the key columns of the right table are kept as well.
+    /// Defaults to `true`.
+    pub fn coalesce(mut self, coalesce: bool) -> Self {
+        self.key_options.coalesce = coalesce;
+        self
+    }
+
+    /// Let null keys match each other.
+    /// Defaults to `true`.
+    pub fn join_nulls(mut self, join_nulls: bool) -> Self {
+        self.key_options.join_nulls = join_nulls;
+        self
+    }
+
     /// Set the `by` subgrouper of an asof join.
     pub fn asof_by(mut self, left_by: Vec<String>, right_by: Vec<String>) -> Self {
         self.asof_by_left = left_by;
@@ -1313,6 +1909,39 @@ impl JoinBuilder {
         self
     }
 
+    /// Set the search direction of an asof join.
+    /// Defaults to [`AsofStrategy::Backward`].
+    #[cfg(feature = "asof_join")]
+    pub fn asof_strategy(mut self, strategy: AsofStrategy) -> Self {
+        self.asof_options.strategy = strategy;
+        self
+    }
+
+    /// Set the maximum distance between the keys of an asof join,
+    /// expressed in the physical unit of the join key.
+    #[cfg(feature = "asof_join")]
+    pub fn asof_tolerance(mut self, tolerance: AnyValue<'static>) -> Self {
+        self.asof_options.tolerance = Some(tolerance);
+        self
+    }
+
+    /// Set the maximum distance between the keys of an asof join
+    /// as a duration string, e.g. `"5m"` or `"1d12h"`.
+    /// Only valid for `Date` and `Datetime` keys.
+    #[cfg(feature = "asof_join")]
+    pub fn asof_tolerance_str(mut self, tolerance: &str) -> Self {
+        self.asof_options.tolerance_str = Some(tolerance.to_string());
+        self
+    }
+
+    /// Allow an asof join to match on equal keys.
+    /// Defaults to `true`.
+    #[cfg(feature = "asof_join")]
+    pub fn asof_allow_exact_matches(mut self, allow: bool) -> Self {
+        self.asof_options.allow_exact_matches = allow;
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let opt_state = self.lf.opt_state;
@@ -1331,6 +1960,11 @@ impl JoinBuilder {
                     suffix: self.suffix,
                     asof_by_left: self.asof_by_left,
                     asof_by_right: self.asof_by_right,
+                    key_options: self.key_options,
+                    #[cfg(feature = "asof_join")]
+                    asof_options: self.asof_options,
+                    #[cfg(feature = "range_join")]
+                    range_predicates: vec![],
                 },
             )
             .build();
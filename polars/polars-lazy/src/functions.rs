@@ -61,6 +61,26 @@ pub fn pearson_corr(a: Expr, b: Expr) -> Expr {
     map_binary(a, b, function, Some(Field::new(name, DataType::Float32))).alias(name)
 }
 
+/// Compute the rolling covariance between two columns.
+/// [See eager rolling_cov](polars_core::series::Series::rolling_cov).
+#[cfg(feature = "rolling_window")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rolling_window")))]
+pub fn rolling_cov(a: Expr, b: Expr, options: RollingOptions) -> Expr {
+    let name = "rolling_cov";
+    let function = move |a: Series, b: Series| a.rolling_cov(&b, options.clone());
+    map_binary(a, b, function, Some(Field::new(name, DataType::Float64))).alias(name)
+}
+
+/// Compute the rolling pearson correlation between two columns.
+/// [See eager rolling_corr](polars_core::series::Series::rolling_corr).
+#[cfg(feature = "rolling_window")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rolling_window")))]
+pub fn rolling_corr(a: Expr, b: Expr, options: RollingOptions) -> Expr {
+    let name = "rolling_corr";
+    let function = move |a: Series, b: Series| a.rolling_corr(&b, options.clone());
+    map_binary(a, b, function, Some(Field::new(name, DataType::Float64))).alias(name)
+}
+
 /// Compute the spearman rank correlation between two columns.
 #[cfg(feature = "rank")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rank")))]
@@ -95,6 +115,7 @@ pub fn argsort_by<E: AsRef<[Expr]>>(by: E, reverse: &[bool]) -> Expr {
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyFlat,
             input_wildcard_expansion: false,
+            fill_strategy: None,
         },
     }
 }
@@ -114,6 +135,7 @@ pub fn concat_str(s: Vec<Expr>, sep: &str) -> Expr {
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyFlat,
             input_wildcard_expansion: true,
+            fill_strategy: None,
         },
     }
 }
@@ -142,6 +164,7 @@ pub fn concat_lst(s: Vec<Expr>) -> Expr {
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyFlat,
             input_wildcard_expansion: true,
+            fill_strategy: None,
         },
     }
 }
@@ -305,6 +328,7 @@ pub fn datetime(
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyFlat,
             input_wildcard_expansion: true,
+            fill_strategy: None,
         },
     }
     .alias("datetime")
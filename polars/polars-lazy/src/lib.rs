@@ -201,6 +201,8 @@ pub mod logical_plan;
 pub mod physical_plan;
 #[cfg(feature = "compile")]
 pub mod prelude;
+#[cfg(all(feature = "sql", feature = "compile"))]
+pub mod sql;
 #[cfg(test)]
 mod tests;
 #[cfg(feature = "compile")]
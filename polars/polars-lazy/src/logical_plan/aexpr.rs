@@ -27,6 +27,13 @@ pub enum AAggExpr {
     Std(Node),
     Var(Node),
     AggGroups(Node),
+    #[cfg(feature = "approx")]
+    ApproxNUnique(Node),
+    #[cfg(feature = "approx")]
+    ApproxQuantile {
+        expr: Node,
+        quantile: f64,
+    },
 }
 
 // AExpr representation of Nodes which are allocated in an Arena
@@ -183,10 +190,13 @@ impl AExpr {
                     | Operator::Gt
                     | Operator::Eq
                     | Operator::NotEq
-                    | Operator::And
                     | Operator::LtEq
-                    | Operator::GtEq
-                    | Operator::Or => DataType::Boolean,
+                    | Operator::GtEq => DataType::Boolean,
+                    // bitwise operations on integers keep the integer type
+                    Operator::And | Operator::Or if left_type == DataType::Boolean => {
+                        DataType::Boolean
+                    }
+                    Operator::ShiftLeft | Operator::ShiftRight => left_type,
                     _ => get_supertype(&left_type, &right_type)?,
                 };
 
@@ -198,7 +208,11 @@ impl AExpr {
 
                 Ok(Field::new(out_name, expr_type))
             }
-            Not(_) => Ok(Field::new("not", DataType::Boolean)),
+            // boolean negation or bitwise not of integers
+            Not(expr) => {
+                let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                Ok(Field::new("not", field.data_type().clone()))
+            }
             IsNull(_) => Ok(Field::new("is_null", DataType::Boolean)),
             IsNotNull(_) => Ok(Field::new("is_not_null", DataType::Boolean)),
             Sort { expr, .. } => arena.get(*expr).to_field(schema, ctxt, arena),
@@ -314,6 +328,29 @@ impl AExpr {
                         field.coerce(DataType::Float64);
                         field
                     }
+                    #[cfg(feature = "approx")]
+                    ApproxNUnique(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field = Field::new(field.name(), DataType::UInt32);
+                        match ctxt {
+                            Context::Default => field,
+                            Context::Aggregation => {
+                                let new_name =
+                                    fmt_groupby_column(field.name(), GroupByMethod::ApproxNUnique);
+                                rename_field(&field, &new_name)
+                            }
+                        }
+                    }
+                    #[cfg(feature = "approx")]
+                    ApproxQuantile { expr, quantile } => {
+                        let mut field = field_by_context(
+                            arena.get(*expr).to_field(schema, ctxt, arena)?,
+                            ctxt,
+                            GroupByMethod::ApproxQuantile(*quantile),
+                        );
+                        field.coerce(DataType::Float64);
+                        field
+                    }
                 };
                 Ok(field)
             }
@@ -19,8 +19,7 @@ use std::sync::Arc;
 pub enum ALogicalPlan {
     Melt {
         input: Node,
-        id_vars: Arc<Vec<String>>,
-        value_vars: Arc<Vec<String>>,
+        args: Arc<MeltArgs>,
         schema: SchemaRef,
     },
     Slice {
@@ -91,6 +90,7 @@ pub enum ALogicalPlan {
     },
     Cache {
         input: Node,
+        id: usize,
     },
     Aggregate {
         input: Node,
@@ -149,7 +149,7 @@ impl ALogicalPlan {
         use ALogicalPlan::*;
         match self {
             Union { inputs, .. } => arena.get(inputs[0]).schema(arena),
-            Cache { input } => arena.get(*input).schema(arena),
+            Cache { input, .. } => arena.get(*input).schema(arena),
             Sort { input, .. } => arena.get(*input).schema(arena),
             Explode { input, .. } => arena.get(*input).schema(arena),
             #[cfg(feature = "parquet")]
@@ -270,15 +270,9 @@ impl ALogicalPlan {
 
         match self {
             Union { .. } => Union { inputs },
-            Melt {
-                id_vars,
-                value_vars,
-                schema,
-                ..
-            } => Melt {
+            Melt { args, schema, .. } => Melt {
                 input: inputs[0],
-                id_vars: id_vars.clone(),
-                value_vars: value_vars.clone(),
+                args: args.clone(),
                 schema: schema.clone(),
             },
             Slice { offset, len, .. } => Slice {
@@ -340,7 +334,10 @@ impl ALogicalPlan {
                 input: inputs[0],
                 columns: columns.clone(),
             },
-            Cache { .. } => Cache { input: inputs[0] },
+            Cache { id, .. } => Cache {
+                input: inputs[0],
+                id: *id,
+            },
             Distinct {
                 maintain_order,
                 subset,
@@ -616,13 +613,12 @@ impl<'a> ALogicalPlanBuilder<'a> {
         }
     }
 
-    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.schema());
+    pub fn melt(self, args: Arc<MeltArgs>) -> Self {
+        let schema = det_melt_schema(&args, self.schema());
 
         let lp = ALogicalPlan::Melt {
             input: self.root,
-            id_vars,
-            value_vars,
+            args,
             schema,
         };
         let node = self.lp_arena.add(lp);
@@ -746,6 +742,21 @@ impl<'a> ALogicalPlanBuilder<'a> {
         options: JoinOptions,
     ) -> Self {
         let schema_left = self.schema();
+
+        // semi and anti joins only filter the left table
+        #[cfg(feature = "semi_anti_join")]
+        if matches!(options.how, JoinType::Semi | JoinType::Anti) {
+            let lp = ALogicalPlan::Join {
+                input_left: self.root,
+                input_right: other,
+                schema: Arc::new(schema_left.clone()),
+                left_on,
+                right_on,
+                options,
+            };
+            let root = self.lp_arena.add(lp);
+            return Self::new(root, self.expr_arena, self.lp_arena);
+        }
         let schema_right = self.lp_arena.get(other).schema(self.lp_arena);
 
         // column names of left table
@@ -770,9 +781,12 @@ impl<'a> ALogicalPlanBuilder<'a> {
             })
             .collect();
 
+        // an outer join without coalescing keeps the key columns of the right table
+        let keep_right_keys = options.how == JoinType::Outer && !options.key_options.coalesce;
+
         for f in schema_right.fields() {
             let name = f.name();
-            if !right_names.contains(name.as_str()) {
+            if keep_right_keys || !right_names.contains(name.as_str()) {
                 if names.contains(name.as_str()) {
                     let new_name = format!("{}_right", name);
                     let field = Field::new(&new_name, f.data_type().clone());
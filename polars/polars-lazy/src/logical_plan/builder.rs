@@ -1,3 +1,4 @@
+use crate::logical_plan::next_cache_id;
 use crate::logical_plan::projection::rewrite_projections;
 use crate::prelude::*;
 use crate::utils;
@@ -100,7 +101,14 @@ impl LogicalPlanBuilder {
         quote_char: Option<u8>,
         null_values: Option<NullValues>,
         infer_schema_length: Option<usize>,
+        reservoir_sample: Option<(usize, u64)>,
     ) -> Result<Self> {
+        #[cfg(not(feature = "random"))]
+        if reservoir_sample.is_some() {
+            return Err(PolarsError::InvalidOperation(
+                "reservoir sampling requires the 'random' feature".into(),
+            ));
+        }
         let path = path.into();
         let mut file = std::fs::File::open(&path)?;
         let mut magic_nr = [0u8; 2];
@@ -142,6 +150,7 @@ impl LogicalPlanBuilder {
                 comment_char,
                 quote_char,
                 null_values,
+                reservoir_sample,
             },
             predicate: None,
             aggregate: vec![],
@@ -152,6 +161,7 @@ impl LogicalPlanBuilder {
     pub fn cache(self) -> Self {
         LogicalPlan::Cache {
             input: Box::new(self.0),
+            id: next_cache_id(),
         }
         .into()
     }
@@ -334,12 +344,12 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.0.schema());
+    pub fn melt(self, args: MeltArgs) -> Self {
+        let args = Arc::new(args.resolve(self.0.schema()));
+        let schema = det_melt_schema(&args, self.0.schema());
         LogicalPlan::Melt {
             input: Box::new(self.0),
-            id_vars,
-            value_vars,
+            args,
             schema,
         }
         .into()
@@ -373,6 +383,21 @@ impl LogicalPlanBuilder {
         let schema_left = self.0.schema();
         let schema_right = other.schema();
 
+        // semi and anti joins only filter the left table
+        #[cfg(feature = "semi_anti_join")]
+        if matches!(options.how, JoinType::Semi | JoinType::Anti) {
+            let schema = schema_left.clone();
+            return LogicalPlan::Join {
+                input_left: Box::new(self.0),
+                input_right: Box::new(other),
+                schema,
+                left_on,
+                right_on,
+                options,
+            }
+            .into();
+        }
+
         // column names of left table
         let mut names: HashSet<&String, RandomState> = HashSet::default();
         // fields of new schema
@@ -388,10 +413,13 @@ impl LogicalPlanBuilder {
             .map(|e| utils::output_name(e).expect("could not find name"))
             .collect();
 
+        // an outer join without coalescing keeps the key columns of the right table
+        let keep_right_keys = options.how == JoinType::Outer && !options.key_options.coalesce;
+
         for f in schema_right.fields() {
             let name = f.name();
 
-            if !right_names.iter().any(|s| s.as_ref() == name) {
+            if keep_right_keys || !right_names.iter().any(|s| s.as_ref() == name) {
                 if names.contains(name) {
                     let new_name = format!("{}_right", name);
                     let field = Field::new(&new_name, f.data_type().clone());
@@ -434,23 +462,40 @@ impl LogicalPlanBuilder {
     }
 }
 
-pub(crate) fn det_melt_schema(value_vars: &[String], input_schema: &Schema) -> SchemaRef {
-    let mut fields = input_schema
-        .fields()
+/// Check that the columns of the `args` exist in the `input_schema` and that there is at least one
+/// column to melt.
+pub(crate) fn check_melt_args(args: &MeltArgs, input_schema: &Schema) -> Result<()> {
+    for name in args.id_vars.iter().chain(args.value_vars.iter()) {
+        input_schema.field_with_name(name)?;
+    }
+    if args.value_vars.is_empty() {
+        return Err(PolarsError::NotFound(
+            "no columns to melt: the value columns selection is empty".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Invalid arguments are reported by [`check_melt_args`] when the query is executed, so columns
+/// that are not found are left out of the schema.
+pub(crate) fn det_melt_schema(args: &MeltArgs, input_schema: &Schema) -> SchemaRef {
+    let mut fields = args
+        .id_vars
         .iter()
-        .filter(|field| !value_vars.contains(field.name()))
-        .cloned()
+        .filter_map(|name| input_schema.field_with_name(name).ok().cloned())
         .collect::<Vec<_>>();
 
     fields.reserve(2);
 
-    let value_dtype = input_schema
-        .field_with_name(&value_vars[0])
-        .expect("field not found")
-        .data_type();
+    let value_dtype = args
+        .value_vars
+        .first()
+        .and_then(|name| input_schema.field_with_name(name).ok())
+        .map(|fld| fld.data_type().clone())
+        .unwrap_or(DataType::Null);
 
-    fields.push(Field::new("variable", DataType::Utf8));
-    fields.push(Field::new("value", value_dtype.clone()));
+    fields.push(Field::new(args.variable_name(), DataType::Utf8));
+    fields.push(Field::new(args.value_name(), value_dtype));
 
     Arc::new(Schema::new(fields))
 }
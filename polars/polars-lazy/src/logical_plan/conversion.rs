@@ -77,6 +77,13 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
                 AggExpr::Sum(expr) => AAggExpr::Sum(to_aexpr(*expr, arena)),
                 AggExpr::Std(expr) => AAggExpr::Std(to_aexpr(*expr, arena)),
                 AggExpr::Var(expr) => AAggExpr::Var(to_aexpr(*expr, arena)),
+                #[cfg(feature = "approx")]
+                AggExpr::ApproxNUnique(expr) => AAggExpr::ApproxNUnique(to_aexpr(*expr, arena)),
+                #[cfg(feature = "approx")]
+                AggExpr::ApproxQuantile { expr, quantile } => AAggExpr::ApproxQuantile {
+                    expr: to_aexpr(*expr, arena),
+                    quantile,
+                },
                 AggExpr::AggGroups(expr) => AAggExpr::AggGroups(to_aexpr(*expr, arena)),
             };
             AExpr::Agg(a_agg)
@@ -178,15 +185,13 @@ pub(crate) fn to_alp(
         }
         LogicalPlan::Melt {
             input,
-            id_vars,
-            value_vars,
+            args,
             schema,
         } => {
             let input = to_alp(*input, expr_arena, lp_arena);
             ALogicalPlan::Melt {
                 input,
-                id_vars,
-                value_vars,
+                args,
                 schema,
             }
         }
@@ -302,9 +307,9 @@ pub(crate) fn to_alp(
             let input = to_alp(*input, expr_arena, lp_arena);
             ALogicalPlan::Explode { input, columns }
         }
-        LogicalPlan::Cache { input } => {
+        LogicalPlan::Cache { input, id } => {
             let input = to_alp(*input, expr_arena, lp_arena);
-            ALogicalPlan::Cache { input }
+            ALogicalPlan::Cache { input, id }
         }
         LogicalPlan::Aggregate {
             input,
@@ -547,6 +552,20 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::Var(Box::new(exp)).into()
             }
+            #[cfg(feature = "approx")]
+            AAggExpr::ApproxNUnique(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::ApproxNUnique(Box::new(exp)).into()
+            }
+            #[cfg(feature = "approx")]
+            AAggExpr::ApproxQuantile { expr, quantile } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::ApproxQuantile {
+                    expr: Box::new(exp),
+                    quantile,
+                }
+                .into()
+            }
             AAggExpr::AggGroups(expr) => {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::AggGroups(Box::new(exp)).into()
@@ -765,9 +784,9 @@ pub(crate) fn node_to_lp(
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
             LogicalPlan::Explode { input, columns }
         }
-        ALogicalPlan::Cache { input } => {
+        ALogicalPlan::Cache { input, id } => {
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
-            LogicalPlan::Cache { input }
+            LogicalPlan::Cache { input, id }
         }
         ALogicalPlan::Aggregate {
             input,
@@ -837,15 +856,13 @@ pub(crate) fn node_to_lp(
         }
         ALogicalPlan::Melt {
             input,
-            id_vars,
-            value_vars,
+            args,
             schema,
         } => {
             let input = node_to_lp(input, expr_arena, lp_arena);
             LogicalPlan::Melt {
                 input: Box::new(input),
-                id_vars,
-                value_vars,
+                args,
                 schema,
             }
         }
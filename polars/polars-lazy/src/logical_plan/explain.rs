@@ -0,0 +1,300 @@
+use crate::prelude::*;
+use polars_core::prelude::*;
+use std::fmt;
+use std::fmt::Write;
+
+/// The plan after an optimization pass.
+#[derive(Clone, Debug)]
+pub struct OptimizationStep {
+    /// Name of the optimization pass, e.g. `predicate_pushdown` or `simplify_expr`.
+    pub pass: String,
+    /// The rendered plan after the pass.
+    pub plan: String,
+}
+
+/// The plans of a query after each optimization pass that changed it.
+///
+/// Every node of a plan is rendered on its own line with its output schema and the
+/// predicates and projections that were pushed down into it. The inputs of a node are
+/// indented below it.
+#[derive(Clone, Debug)]
+pub struct Explain {
+    /// The first step is the unoptimized plan, the last step the plan that is executed.
+    pub steps: Vec<OptimizationStep>,
+}
+
+impl Explain {
+    /// The plan that is executed.
+    pub fn optimized_plan(&self) -> &str {
+        &self.steps.last().unwrap().plan
+    }
+
+    /// The passes that changed the plan.
+    pub fn passes(&self) -> Vec<&str> {
+        self.steps.iter().skip(1).map(|s| s.pass.as_str()).collect()
+    }
+
+    /// Render the changes of every optimization pass as a line diff with the plan before
+    /// the pass. Removed lines start with `-`, added lines with `+`.
+    pub fn diff(&self) -> String {
+        let mut out = String::new();
+        for window in self.steps.windows(2) {
+            writeln!(out, "=== {} ===", window[1].pass).unwrap();
+            let before = window[0].plan.lines().collect::<Vec<_>>();
+            let after = window[1].plan.lines().collect::<Vec<_>>();
+            for line in line_diff(&before, &after) {
+                writeln!(out, "{}", line).unwrap();
+            }
+        }
+        out
+    }
+}
+
+impl fmt::Display for Explain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "=== {} ===", step.pass)?;
+            write!(f, "{}", step.plan)?;
+        }
+        Ok(())
+    }
+}
+
+/// Diff of two sequences of lines by their longest common subsequence.
+fn line_diff(before: &[&str], after: &[&str]) -> Vec<String> {
+    let (n, m) = (before.len(), after.len());
+    // lcs[i][j] is the length of the longest common subsequence of before[i..] and after[j..]
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if before[i] == after[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                std::cmp::max(lcs[i + 1][j], lcs[i][j + 1])
+            }
+        }
+    }
+
+    let mut out = Vec::with_capacity(std::cmp::max(n, m));
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && before[i] == after[j] {
+            out.push(format!("  {}", before[i]));
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            out.push(format!("+ {}", after[j]));
+            j += 1;
+        } else {
+            out.push(format!("- {}", before[i]));
+            i += 1;
+        }
+    }
+    out
+}
+
+fn fmt_exprs(nodes: &[Node], expr_arena: &Arena<AExpr>) -> String {
+    let exprs = nodes
+        .iter()
+        .map(|node| format!("{:?}", node_to_exp(*node, expr_arena)))
+        .collect::<Vec<_>>();
+    format!("[{}]", exprs.join(", "))
+}
+
+fn fmt_schema(schema: &Schema) -> String {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|f| format!("{}: {:?}", f.name(), f.data_type()))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", fields.join(", "))
+}
+
+#[cfg(any(feature = "csv-file", feature = "ipc", feature = "parquet"))]
+fn fmt_scan(
+    kind: &str,
+    path: &std::path::Path,
+    with_columns: &Option<Vec<String>>,
+    predicate: &Option<Node>,
+    n_rows: Option<usize>,
+    expr_arena: &Arena<AExpr>,
+) -> String {
+    let mut s = format!("{} SCAN {}", kind, path.to_string_lossy());
+    if let Some(columns) = with_columns {
+        write!(s, "; PROJECT {:?}", columns).unwrap();
+    }
+    if let Some(predicate) = predicate {
+        write!(s, "; SELECTION {:?}", node_to_exp(*predicate, expr_arena)).unwrap();
+    }
+    if let Some(n_rows) = n_rows {
+        write!(s, "; N_ROWS {}", n_rows).unwrap();
+    }
+    s
+}
+
+/// Render the plan below `root`, one node per line.
+pub(crate) fn render_plan(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> String {
+    let mut out = String::new();
+    render_node(root, 0, lp_arena, expr_arena, &mut out);
+    out
+}
+
+fn render_node(
+    node: Node,
+    depth: usize,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    out: &mut String,
+) {
+    use ALogicalPlan::*;
+    let lp = lp_arena.get(node);
+    let description = match lp {
+        Melt { args, .. } => format!(
+            "MELT id_vars: {:?}; value_vars: {:?}",
+            args.id_vars, args.value_vars
+        ),
+        Slice { offset, len, .. } => format!("SLICE offset: {}; len: {}", offset, len),
+        Selection { predicate, .. } => {
+            format!("FILTER {:?}", node_to_exp(*predicate, expr_arena))
+        }
+        #[cfg(feature = "csv-file")]
+        CsvScan {
+            path,
+            options,
+            predicate,
+            ..
+        } => {
+            let mut s = fmt_scan(
+                "CSV",
+                path,
+                &options.with_columns,
+                predicate,
+                options.n_rows,
+                expr_arena,
+            );
+            if let Some((n, seed)) = options.reservoir_sample {
+                write!(s, "; SAMPLE {} (seed: {})", n, seed).unwrap();
+            }
+            s
+        }
+        #[cfg(feature = "ipc")]
+        IpcScan {
+            path,
+            options,
+            predicate,
+            ..
+        } => fmt_scan(
+            "IPC",
+            path,
+            &options.with_columns,
+            predicate,
+            options.n_rows,
+            expr_arena,
+        ),
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            path,
+            options,
+            predicate,
+            ..
+        } => fmt_scan(
+            "PARQUET",
+            path,
+            &options.with_columns,
+            predicate,
+            options.n_rows,
+            expr_arena,
+        ),
+        DataFrameScan {
+            projection,
+            selection,
+            ..
+        } => {
+            let mut s = "TABLE".to_string();
+            if let Some(projection) = projection {
+                write!(s, "; PROJECT {}", fmt_exprs(projection, expr_arena)).unwrap();
+            }
+            if let Some(selection) = selection {
+                write!(s, "; SELECTION {:?}", node_to_exp(*selection, expr_arena)).unwrap();
+            }
+            s
+        }
+        Projection { expr, .. } => format!("SELECT {}", fmt_exprs(expr, expr_arena)),
+        LocalProjection { expr, .. } => {
+            format!("LOCAL SELECT {}", fmt_exprs(expr, expr_arena))
+        }
+        Sort {
+            by_column, reverse, ..
+        } => format!(
+            "SORT BY {}; reverse: {:?}",
+            fmt_exprs(by_column, expr_arena),
+            reverse
+        ),
+        Explode { columns, .. } => format!("EXPLODE {:?}", columns),
+        Cache { id, .. } => format!("CACHE id: {}", id),
+        Aggregate {
+            keys, aggs, apply, ..
+        } => {
+            let aggs = if apply.is_some() {
+                "APPLY".to_string()
+            } else {
+                fmt_exprs(aggs, expr_arena)
+            };
+            format!("AGGREGATE {} BY {}", aggs, fmt_exprs(keys, expr_arena))
+        }
+        Join {
+            left_on,
+            right_on,
+            options,
+            ..
+        } => format!(
+            "{:?} JOIN left_on: {}; right_on: {}",
+            options.how,
+            fmt_exprs(left_on, expr_arena),
+            fmt_exprs(right_on, expr_arena)
+        ),
+        HStack { exprs, .. } => format!("WITH COLUMNS {}", fmt_exprs(exprs, expr_arena)),
+        Distinct { subset, .. } => format!("DISTINCT subset: {:?}", subset),
+        Udf { .. } => "UDF".to_string(),
+        Union { .. } => "UNION".to_string(),
+    };
+    let schema = lp.schema(lp_arena);
+    writeln!(
+        out,
+        "{}{} {}",
+        "  ".repeat(depth),
+        description,
+        fmt_schema(schema)
+    )
+    .unwrap();
+
+    for input in lp.get_inputs() {
+        render_node(input, depth + 1, lp_arena, expr_arena, out);
+    }
+}
+
+/// Add the plan below `root` as a step of `pass` if the pass changed the plan.
+/// Consecutive changes by the same pass are merged into one step.
+pub(crate) fn record_step(
+    steps: &mut Vec<OptimizationStep>,
+    pass: &str,
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) {
+    let plan = render_plan(root, lp_arena, expr_arena);
+    // the first step is the unoptimized plan
+    let n_steps = steps.len();
+    match steps.last_mut() {
+        Some(last) if last.plan == plan => {}
+        Some(last) if last.pass == pass && n_steps > 1 => last.plan = plan,
+        _ => steps.push(OptimizationStep {
+            pass: pass.to_string(),
+            plan,
+        }),
+    }
+}
@@ -8,7 +8,7 @@ impl fmt::Debug for LogicalPlan {
         use LogicalPlan::*;
         match self {
             Union { inputs, .. } => write!(f, "UNION {:?}", inputs),
-            Cache { input } => write!(f, "CACHE {:?}", input),
+            Cache { input, .. } => write!(f, "CACHE {:?}", input),
             #[cfg(feature = "parquet")]
             ParquetScan {
                 path,
@@ -186,7 +186,7 @@ impl LogicalPlan {
                 }
                 Ok(())
             }
-            Cache { input } => {
+            Cache { input, .. } => {
                 let current_node = format!("CACHE [{:?}]", (branch, id));
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
                 input.dot(acc_str, (branch, id + 1), &current_node)
@@ -46,6 +46,10 @@ macro_rules! push_expr {
                     AggGroups(e) => $push(e),
                     Std(e) => $push(e),
                     Var(e) => $push(e),
+                    #[cfg(feature = "approx")]
+                    ApproxNUnique(e) => $push(e),
+                    #[cfg(feature = "approx")]
+                    ApproxQuantile { expr, .. } => $push(expr),
                 }
             }
             Ternary {
@@ -203,6 +207,10 @@ impl AExpr {
                     AggGroups(e) => push(e),
                     Std(e) => push(e),
                     Var(e) => push(e),
+                    #[cfg(feature = "approx")]
+                    ApproxNUnique(e) => push(e),
+                    #[cfg(feature = "approx")]
+                    ApproxQuantile { expr, .. } => push(expr),
                 }
             }
             Ternary {
@@ -3,10 +3,15 @@ use std::path::PathBuf;
 use std::{
     cell::Cell,
     fmt::{Debug, Write},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
 use polars_core::prelude::*;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 
 use crate::logical_plan::LogicalPlan::DataFrameScan;
 use crate::prelude::*;
@@ -17,23 +22,35 @@ pub(crate) mod alp;
 mod apply;
 mod builder;
 pub(crate) mod conversion;
+mod explain;
 mod format;
 pub(crate) mod iterator;
 mod lit;
 pub(crate) mod optimizer;
 mod projection;
 mod scans;
+#[cfg(feature = "serde-lazy")]
+mod serialize;
 
 use polars_core::frame::groupby::DynamicGroupOptions;
 
 pub(crate) use apply::*;
 pub(crate) use builder::*;
+pub(crate) use explain::{record_step, render_plan};
+pub use explain::{Explain, OptimizationStep};
 pub use lit::*;
 pub(crate) use scans::*;
 
 // Will be set/ unset in the fetch operation to communicate overwriting the number of rows to scan.
 thread_local! {pub(crate) static FETCH_ROWS: Cell<Option<usize>> = Cell::new(None)}
 
+static CACHE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Get a new unique id for a `Cache` node.
+pub(crate) fn next_cache_id() -> usize {
+    CACHE_ID.fetch_add(1, Ordering::Relaxed)
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum Context {
     /// Any operation that is done on groups
@@ -44,6 +61,7 @@ pub enum Context {
 
 // https://stackoverflow.com/questions/1031076/what-are-projection-and-selection
 #[derive(Clone)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum LogicalPlan {
     /// Filter on a boolean mask
     Selection {
@@ -53,6 +71,8 @@ pub enum LogicalPlan {
     /// Cache the input at this point in the LP
     Cache {
         input: Box<LogicalPlan>,
+        /// Caches with the same id share their result
+        id: usize,
     },
     /// Scan a CSV file
     #[cfg(feature = "csv-file")]
@@ -156,8 +176,7 @@ pub enum LogicalPlan {
     /// A Melt operation
     Melt {
         input: Box<LogicalPlan>,
-        id_vars: Arc<Vec<String>>,
-        value_vars: Arc<Vec<String>>,
+        args: Arc<MeltArgs>,
         schema: SchemaRef,
     },
     /// A User Defined Function
@@ -217,7 +236,7 @@ impl LogicalPlan {
         use LogicalPlan::*;
         match self {
             Union { inputs, .. } => inputs[0].schema(),
-            Cache { input } => input.schema(),
+            Cache { input, .. } => input.schema(),
             Sort { input, .. } => input.schema(),
             Explode { input, .. } => input.schema(),
             #[cfg(feature = "parquet")]
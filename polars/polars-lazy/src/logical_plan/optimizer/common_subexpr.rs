@@ -0,0 +1,272 @@
+use crate::logical_plan::iterator::{ArenaExprIter, ArenaLpIter};
+use crate::logical_plan::optimizer::common_subplan::aexpr_equal;
+use crate::logical_plan::Context;
+use crate::prelude::*;
+use crate::utils::aexpr_is_elementwise;
+use polars_core::datatypes::PlHashMap;
+use polars_core::prelude::*;
+use std::sync::Arc;
+
+const CSE_PREFIX: &str = "__POLARS_CSE_";
+
+/// Only subexpressions that operate elementwise are extracted. Those give the same result
+/// on the whole `DataFrame` as on the groups of an aggregation, and always have the
+/// length of the input, so they can be added as a column.
+fn is_candidate(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    !matches!(
+        expr_arena.get(node),
+        AExpr::Column(_) | AExpr::Literal(_) | AExpr::Alias(_, _)
+    ) && aexpr_is_elementwise(node, expr_arena)
+        && expr_arena
+            .iter(node)
+            .any(|(_, e)| matches!(e, AExpr::Column(_)))
+}
+
+/// Replace the subexpressions that occur more than once in `exprs` by a temporary column.
+///
+/// Returns the expressions that compute the temporary columns and their fields, or `None`
+/// if there are no common subexpressions. Every rewritten expression keeps its output name.
+fn extract_common_subexprs(
+    exprs: &mut [Node],
+    input_schema: &Schema,
+    ctxt: Context,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<(Vec<Node>, Vec<Field>)> {
+    // group the candidates by equality and count the occurrences
+    let mut classes: Vec<(Node, usize)> = vec![];
+    let mut class_of = PlHashMap::with_capacity(16);
+    for e in exprs.iter() {
+        for (node, _) in (&*expr_arena).iter(*e) {
+            if !is_candidate(node, expr_arena) {
+                continue;
+            }
+            match classes
+                .iter()
+                .position(|(repr, _)| aexpr_equal(*repr, node, expr_arena))
+            {
+                Some(idx) => {
+                    classes[idx].1 += 1;
+                    class_of.insert(node, idx);
+                }
+                None => {
+                    class_of.insert(node, classes.len());
+                    classes.push((node, 1));
+                }
+            }
+        }
+    }
+    if classes.iter().all(|(_, count)| *count < 2) {
+        return None;
+    }
+
+    let names = exprs
+        .iter()
+        .map(|e| {
+            expr_arena
+                .get(*e)
+                .to_field(input_schema, ctxt, expr_arena)
+                .map(|field| field.name().clone())
+        })
+        .collect::<Result<Vec<_>>>()
+        .ok()?;
+    let dtypes = classes
+        .iter()
+        .map(|(repr, count)| {
+            if *count > 1 {
+                expr_arena
+                    .get(*repr)
+                    .to_field(input_schema, Context::Default, expr_arena)
+                    .map(|field| Some(field.data_type().clone()))
+            } else {
+                Ok(None)
+            }
+        })
+        .collect::<Result<Vec<_>>>()
+        .ok()?;
+
+    // Walk the expressions top down, so that the largest common subexpressions are
+    // replaced first.
+    let mut temp_names: Vec<Option<Arc<str>>> = vec![None; classes.len()];
+    let mut temps = vec![];
+    let mut temp_fields = vec![];
+    let mut stack = Vec::with_capacity(16);
+    for (e, name) in exprs.iter_mut().zip(names) {
+        let mut rewritten = false;
+        stack.push(*e);
+        while let Some(node) = stack.pop() {
+            match class_of.get(&node) {
+                Some(&idx) if classes[idx].1 > 1 => {
+                    let temp_name = match &temp_names[idx] {
+                        Some(temp_name) => temp_name.clone(),
+                        None => {
+                            let temp_name: Arc<str> =
+                                Arc::from(format!("{}{}", CSE_PREFIX, temps.len()));
+                            let dtype = dtypes[idx].clone().unwrap();
+                            temp_fields.push(Field::new(&temp_name, dtype));
+
+                            let subexpr = expr_arena.get(node).clone();
+                            let computed = expr_arena.add(subexpr);
+                            temps.push(expr_arena.add(AExpr::Alias(computed, temp_name.clone())));
+                            temp_names[idx] = Some(temp_name.clone());
+                            temp_name
+                        }
+                    };
+                    expr_arena.replace(node, AExpr::Column(temp_name));
+                    rewritten = true;
+                }
+                _ => expr_arena.get(node).nodes(&mut stack),
+            }
+        }
+
+        if rewritten && !matches!(expr_arena.get(*e), AExpr::Alias(_, _)) {
+            *e = expr_arena.add(AExpr::Alias(*e, Arc::from(name.as_str())));
+        }
+    }
+    Some((temps, temp_fields))
+}
+
+fn schema_with_fields(schema: &Schema, fields: &[Field]) -> Schema {
+    let mut new_fields = schema.fields().clone();
+    new_fields.extend_from_slice(fields);
+    Schema::new(new_fields)
+}
+
+/// Compute the subexpressions that occur more than once in a `Projection`, `HStack` or
+/// `Aggregate` node only once. They are computed into temporary columns by an `HStack`
+/// on the input of the node, and the temporary columns are removed from the output.
+pub(crate) fn eliminate_common_subexprs(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    let nodes = (&*lp_arena)
+        .iter(root)
+        .map(|(node, _)| node)
+        .collect::<Vec<_>>();
+
+    for node in nodes {
+        use ALogicalPlan::*;
+        let lp = lp_arena.take(node);
+        let new_lp = match lp {
+            Projection {
+                input,
+                mut expr,
+                schema,
+            } => {
+                let input_schema = lp_arena.get(input).schema(lp_arena).clone();
+                match extract_common_subexprs(
+                    &mut expr,
+                    &input_schema,
+                    Context::Default,
+                    expr_arena,
+                ) {
+                    Some((temps, temp_fields)) => {
+                        let input = lp_arena.add(HStack {
+                            input,
+                            exprs: temps,
+                            schema: Arc::new(schema_with_fields(&input_schema, &temp_fields)),
+                        });
+                        Projection {
+                            input,
+                            expr,
+                            schema,
+                        }
+                    }
+                    None => Projection {
+                        input,
+                        expr,
+                        schema,
+                    },
+                }
+            }
+            HStack {
+                input,
+                mut exprs,
+                schema,
+            } => {
+                let input_schema = lp_arena.get(input).schema(lp_arena).clone();
+                match extract_common_subexprs(
+                    &mut exprs,
+                    &input_schema,
+                    Context::Default,
+                    expr_arena,
+                ) {
+                    Some((temps, temp_fields)) => {
+                        let temps_schema = schema_with_fields(&input_schema, &temp_fields);
+                        // the columns in the output are replaced in place, new columns come last
+                        let mut fields = temps_schema.fields().clone();
+                        for field in schema.fields() {
+                            match temps_schema.index_of(field.name()) {
+                                Ok(idx) => fields[idx] = field.clone(),
+                                Err(_) => fields.push(field.clone()),
+                            }
+                        }
+                        let input = lp_arena.add(HStack {
+                            input,
+                            exprs: temps,
+                            schema: Arc::new(temps_schema),
+                        });
+                        let input = lp_arena.add(HStack {
+                            input,
+                            exprs,
+                            schema: Arc::new(Schema::new(fields)),
+                        });
+                        // remove the temporary columns
+                        let expr = schema
+                            .fields()
+                            .iter()
+                            .map(|field| {
+                                expr_arena.add(AExpr::Column(Arc::from(field.name().as_str())))
+                            })
+                            .collect();
+                        Projection {
+                            input,
+                            expr,
+                            schema,
+                        }
+                    }
+                    None => HStack {
+                        input,
+                        exprs,
+                        schema,
+                    },
+                }
+            }
+            Aggregate {
+                input,
+                keys,
+                mut aggs,
+                schema,
+                apply: None,
+                maintain_order,
+                dynamic_options,
+            } => {
+                let input_schema = lp_arena.get(input).schema(lp_arena).clone();
+                let input = match extract_common_subexprs(
+                    &mut aggs,
+                    &input_schema,
+                    Context::Aggregation,
+                    expr_arena,
+                ) {
+                    Some((temps, temp_fields)) => lp_arena.add(HStack {
+                        input,
+                        exprs: temps,
+                        schema: Arc::new(schema_with_fields(&input_schema, &temp_fields)),
+                    }),
+                    None => input,
+                };
+                Aggregate {
+                    input,
+                    keys,
+                    aggs,
+                    schema,
+                    apply: None,
+                    maintain_order,
+                    dynamic_options,
+                }
+            }
+            lp => lp,
+        };
+        lp_arena.replace(node, new_lp);
+    }
+}
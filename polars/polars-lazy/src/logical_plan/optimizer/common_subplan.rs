@@ -0,0 +1,529 @@
+use crate::logical_plan::next_cache_id;
+use crate::prelude::*;
+use polars_core::datatypes::PlHashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Check if two `Arc`s point to the same allocation. Only the data pointers are compared,
+/// as the vtables of the same trait object may differ between codegen units.
+fn arc_ptr_eq<T: ?Sized>(a: &Arc<T>, b: &Arc<T>) -> bool {
+    Arc::as_ptr(a) as *const u8 == Arc::as_ptr(b) as *const u8
+}
+
+fn debug_eq<T: std::fmt::Debug>(a: &T, b: &T) -> bool {
+    format!("{:?}", a) == format!("{:?}", b)
+}
+
+/// Check if the expressions in both nodes are structurally equal.
+///
+/// Unlike `AExpr::eq` this also compares the payload of every node, e.g. the kind of
+/// aggregation and the identity of user defined functions.
+pub(crate) fn aexpr_equal(left: Node, right: Node, expr_arena: &Arena<AExpr>) -> bool {
+    use AExpr::*;
+    let same_node = match (expr_arena.get(left), expr_arena.get(right)) {
+        (Alias(_, l), Alias(_, r)) => l == r,
+        (Column(l), Column(r)) => l == r,
+        (Literal(l), Literal(r)) => l == r,
+        (BinaryExpr { op: l, .. }, BinaryExpr { op: r, .. }) => l == r,
+        (
+            Cast {
+                data_type: dtype_l,
+                strict: strict_l,
+                ..
+            },
+            Cast {
+                data_type: dtype_r,
+                strict: strict_r,
+                ..
+            },
+        ) => dtype_l == dtype_r && strict_l == strict_r,
+        (Sort { options: l, .. }, Sort { options: r, .. }) => l == r,
+        (SortBy { reverse: l, .. }, SortBy { reverse: r, .. }) => l == r,
+        (Agg(l), Agg(r)) => match (l, r) {
+            (
+                AAggExpr::Quantile {
+                    quantile: q_l,
+                    interpol: i_l,
+                    ..
+                },
+                AAggExpr::Quantile {
+                    quantile: q_r,
+                    interpol: i_r,
+                    ..
+                },
+            ) => q_l == q_r && i_l == i_r,
+            #[cfg(feature = "approx")]
+            (
+                AAggExpr::ApproxQuantile { quantile: q_l, .. },
+                AAggExpr::ApproxQuantile { quantile: q_r, .. },
+            ) => q_l == q_r,
+            (l, r) => std::mem::discriminant(l) == std::mem::discriminant(r),
+        },
+        (
+            Function {
+                function: f_l,
+                output_type: o_l,
+                options: opt_l,
+                ..
+            },
+            Function {
+                function: f_r,
+                output_type: o_r,
+                options: opt_r,
+                ..
+            },
+        ) => arc_ptr_eq(&**f_l, &**f_r) && arc_ptr_eq(&**o_l, &**o_r) && opt_l == opt_r,
+        (Shift { periods: l, .. }, Shift { periods: r, .. }) => l == r,
+        (
+            Window {
+                order_by: order_l,
+                options: l,
+                ..
+            },
+            Window {
+                order_by: order_r,
+                options: r,
+                ..
+            },
+        ) => order_l.is_some() == order_r.is_some() && l == r,
+        (
+            Slice {
+                offset: offset_l,
+                length: length_l,
+                ..
+            },
+            Slice {
+                offset: offset_r,
+                length: length_r,
+                ..
+            },
+        ) => offset_l == offset_r && length_l == length_r,
+        (
+            BinaryFunction {
+                function: f_l,
+                output_field: o_l,
+                ..
+            },
+            BinaryFunction {
+                function: f_r,
+                output_field: o_r,
+                ..
+            },
+        ) => arc_ptr_eq(&**f_l, &**f_r) && arc_ptr_eq(&**o_l, &**o_r),
+        (l, r) => std::mem::discriminant(l) == std::mem::discriminant(r),
+    };
+    if !same_node {
+        return false;
+    }
+
+    let mut inputs_left = Vec::with_capacity(4);
+    let mut inputs_right = Vec::with_capacity(4);
+    expr_arena.get(left).nodes(&mut inputs_left);
+    expr_arena.get(right).nodes(&mut inputs_right);
+    inputs_left.len() == inputs_right.len()
+        && inputs_left
+            .into_iter()
+            .zip(inputs_right)
+            .all(|(l, r)| aexpr_equal(l, r, expr_arena))
+}
+
+fn aexprs_equal(left: &[Node], right: &[Node], expr_arena: &Arena<AExpr>) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .all(|(l, r)| aexpr_equal(*l, *r, expr_arena))
+}
+
+fn opt_aexpr_equal(left: Option<Node>, right: Option<Node>, expr_arena: &Arena<AExpr>) -> bool {
+    match (left, right) {
+        (Some(l), Some(r)) => aexpr_equal(l, r, expr_arena),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Check if the logical plans in both nodes are structurally equal, i.e. if they would
+/// produce the same result.
+pub(crate) fn alp_equal(
+    left: Node,
+    right: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    use ALogicalPlan::*;
+    let same_node = match (lp_arena.get(left), lp_arena.get(right)) {
+        (Melt { args: args_l, .. }, Melt { args: args_r, .. }) => args_l == args_r,
+        (
+            Slice {
+                offset: offset_l,
+                len: len_l,
+                ..
+            },
+            Slice {
+                offset: offset_r,
+                len: len_r,
+                ..
+            },
+        ) => offset_l == offset_r && len_l == len_r,
+        (Selection { predicate: l, .. }, Selection { predicate: r, .. }) => {
+            aexpr_equal(*l, *r, expr_arena)
+        }
+        #[cfg(feature = "csv-file")]
+        (
+            CsvScan {
+                path: path_l,
+                schema: schema_l,
+                output_schema: output_l,
+                options: options_l,
+                predicate: predicate_l,
+                aggregate: aggregate_l,
+            },
+            CsvScan {
+                path: path_r,
+                schema: schema_r,
+                output_schema: output_r,
+                options: options_r,
+                predicate: predicate_r,
+                aggregate: aggregate_r,
+            },
+        ) => {
+            path_l == path_r
+                && schema_l == schema_r
+                && output_l == output_r
+                && debug_eq(options_l, options_r)
+                && opt_aexpr_equal(*predicate_l, *predicate_r, expr_arena)
+                && aexprs_equal(aggregate_l, aggregate_r, expr_arena)
+        }
+        #[cfg(feature = "ipc")]
+        (
+            IpcScan {
+                path: path_l,
+                schema: schema_l,
+                output_schema: output_l,
+                options: options_l,
+                predicate: predicate_l,
+                aggregate: aggregate_l,
+            },
+            IpcScan {
+                path: path_r,
+                schema: schema_r,
+                output_schema: output_r,
+                options: options_r,
+                predicate: predicate_r,
+                aggregate: aggregate_r,
+            },
+        ) => {
+            path_l == path_r
+                && schema_l == schema_r
+                && output_l == output_r
+                && debug_eq(options_l, options_r)
+                && opt_aexpr_equal(*predicate_l, *predicate_r, expr_arena)
+                && aexprs_equal(aggregate_l, aggregate_r, expr_arena)
+        }
+        #[cfg(feature = "parquet")]
+        (
+            ParquetScan {
+                path: path_l,
+                schema: schema_l,
+                output_schema: output_l,
+                options: options_l,
+                predicate: predicate_l,
+                aggregate: aggregate_l,
+            },
+            ParquetScan {
+                path: path_r,
+                schema: schema_r,
+                output_schema: output_r,
+                options: options_r,
+                predicate: predicate_r,
+                aggregate: aggregate_r,
+            },
+        ) => {
+            path_l == path_r
+                && schema_l == schema_r
+                && output_l == output_r
+                && debug_eq(options_l, options_r)
+                && opt_aexpr_equal(*predicate_l, *predicate_r, expr_arena)
+                && aexprs_equal(aggregate_l, aggregate_r, expr_arena)
+        }
+        (
+            DataFrameScan {
+                df: df_l,
+                schema: schema_l,
+                projection: projection_l,
+                selection: selection_l,
+            },
+            DataFrameScan {
+                df: df_r,
+                schema: schema_r,
+                projection: projection_r,
+                selection: selection_r,
+            },
+        ) => {
+            (Arc::ptr_eq(df_l, df_r) || (df_l.height() == df_r.height() && df_l.ptr_equal(df_r)))
+                && schema_l == schema_r
+                && match (projection_l, projection_r) {
+                    (Some(l), Some(r)) => aexprs_equal(l, r, expr_arena),
+                    (None, None) => true,
+                    _ => false,
+                }
+                && opt_aexpr_equal(*selection_l, *selection_r, expr_arena)
+        }
+        (
+            Projection {
+                expr: expr_l,
+                schema: schema_l,
+                ..
+            },
+            Projection {
+                expr: expr_r,
+                schema: schema_r,
+                ..
+            },
+        )
+        | (
+            LocalProjection {
+                expr: expr_l,
+                schema: schema_l,
+                ..
+            },
+            LocalProjection {
+                expr: expr_r,
+                schema: schema_r,
+                ..
+            },
+        )
+        | (
+            HStack {
+                exprs: expr_l,
+                schema: schema_l,
+                ..
+            },
+            HStack {
+                exprs: expr_r,
+                schema: schema_r,
+                ..
+            },
+        ) => schema_l == schema_r && aexprs_equal(expr_l, expr_r, expr_arena),
+        (
+            Sort {
+                by_column: by_l,
+                reverse: reverse_l,
+                ..
+            },
+            Sort {
+                by_column: by_r,
+                reverse: reverse_r,
+                ..
+            },
+        ) => reverse_l == reverse_r && aexprs_equal(by_l, by_r, expr_arena),
+        (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
+        (Cache { id: l, .. }, Cache { id: r, .. }) => l == r,
+        (
+            Aggregate {
+                keys: keys_l,
+                aggs: aggs_l,
+                schema: schema_l,
+                apply: apply_l,
+                maintain_order: maintain_order_l,
+                dynamic_options: dynamic_l,
+                ..
+            },
+            Aggregate {
+                keys: keys_r,
+                aggs: aggs_r,
+                schema: schema_r,
+                apply: apply_r,
+                maintain_order: maintain_order_r,
+                dynamic_options: dynamic_r,
+                ..
+            },
+        ) => {
+            schema_l == schema_r
+                && maintain_order_l == maintain_order_r
+                && match (apply_l, apply_r) {
+                    (Some(l), Some(r)) => arc_ptr_eq(l, r),
+                    (None, None) => true,
+                    _ => false,
+                }
+                && debug_eq(dynamic_l, dynamic_r)
+                && aexprs_equal(keys_l, keys_r, expr_arena)
+                && aexprs_equal(aggs_l, aggs_r, expr_arena)
+        }
+        (
+            Join {
+                schema: schema_l,
+                left_on: left_on_l,
+                right_on: right_on_l,
+                options: options_l,
+                ..
+            },
+            Join {
+                schema: schema_r,
+                left_on: left_on_r,
+                right_on: right_on_r,
+                options: options_r,
+                ..
+            },
+        ) => {
+            schema_l == schema_r
+                && debug_eq(options_l, options_r)
+                && aexprs_equal(left_on_l, left_on_r, expr_arena)
+                && aexprs_equal(right_on_l, right_on_r, expr_arena)
+        }
+        (
+            Distinct {
+                maintain_order: maintain_order_l,
+                subset: subset_l,
+                ..
+            },
+            Distinct {
+                maintain_order: maintain_order_r,
+                subset: subset_r,
+                ..
+            },
+        ) => maintain_order_l == maintain_order_r && subset_l == subset_r,
+        (
+            Udf {
+                function: function_l,
+                predicate_pd: predicate_pd_l,
+                projection_pd: projection_pd_l,
+                schema: schema_l,
+                ..
+            },
+            Udf {
+                function: function_r,
+                predicate_pd: predicate_pd_r,
+                projection_pd: projection_pd_r,
+                schema: schema_r,
+                ..
+            },
+        ) => {
+            arc_ptr_eq(function_l, function_r)
+                && predicate_pd_l == predicate_pd_r
+                && projection_pd_l == projection_pd_r
+                && schema_l == schema_r
+        }
+        (Union { .. }, Union { .. }) => true,
+        _ => false,
+    };
+    if !same_node {
+        return false;
+    }
+
+    let inputs_left = lp_arena.get(left).get_inputs();
+    let inputs_right = lp_arena.get(right).get_inputs();
+    inputs_left.len() == inputs_right.len()
+        && inputs_left
+            .into_iter()
+            .zip(inputs_right)
+            .all(|(l, r)| alp_equal(l, r, lp_arena, expr_arena))
+}
+
+/// Hash the structure of the plan. Equal plans have equal hashes, but the hash ignores
+/// most of the payload, so plans with equal hashes still have to be compared.
+fn hash_subplan(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    hashes: &mut PlHashMap<Node, u64>,
+) -> u64 {
+    let lp = lp_arena.get(node);
+    let mut hasher = DefaultHasher::new();
+    std::mem::discriminant(lp).hash(&mut hasher);
+    for field in lp.schema(lp_arena).fields() {
+        field.name().hash(&mut hasher);
+    }
+    for input in lp.get_inputs() {
+        hash_subplan(input, lp_arena, hashes).hash(&mut hasher);
+    }
+    let hash = hasher.finish();
+    hashes.insert(node, hash);
+    hash
+}
+
+/// Caching these nodes doesn't save any work.
+fn is_trivial(lp: &ALogicalPlan) -> bool {
+    matches!(
+        lp,
+        ALogicalPlan::Cache { .. }
+            | ALogicalPlan::DataFrameScan {
+                projection: None,
+                selection: None,
+                ..
+            }
+    )
+}
+
+/// Find the subplans that occur more than once in the plan and assign a cache id to
+/// every occurrence.
+fn find_common_subplans(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> PlHashMap<Node, usize> {
+    let mut hashes = PlHashMap::with_capacity(64);
+    hash_subplan(root, lp_arena, &mut hashes);
+
+    let mut by_hash: PlHashMap<u64, Vec<Node>> = PlHashMap::with_capacity(hashes.len());
+    for (node, hash) in &hashes {
+        by_hash.entry(*hash).or_insert_with(Vec::new).push(*node);
+    }
+
+    // Walk the plan top down, so that we find the largest common subplans first.
+    // Once a subplan is cached, its inputs don't need to be visited.
+    let mut cache_ids = PlHashMap::with_capacity(16);
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if cache_ids.contains_key(&node) {
+            continue;
+        }
+        let lp = lp_arena.get(node);
+        if is_trivial(lp) {
+            continue;
+        }
+        let duplicates = by_hash[&hashes[&node]]
+            .iter()
+            .copied()
+            .filter(|other| {
+                *other != node
+                    && !cache_ids.contains_key(other)
+                    && alp_equal(node, *other, lp_arena, expr_arena)
+            })
+            .collect::<Vec<_>>();
+
+        if duplicates.is_empty() {
+            lp.copy_inputs(&mut stack);
+        } else {
+            let id = next_cache_id();
+            cache_ids.insert(node, id);
+            for other in duplicates {
+                cache_ids.insert(other, id);
+            }
+        }
+    }
+    cache_ids
+}
+
+/// Wrap every occurrence of a subplan that occurs more than once in a `Cache` node with
+/// the same id. The subplan is then only executed once and the other occurrences are
+/// served from the cache of the `ExecutionState`.
+///
+/// This should run after the other optimizations, as those may rewrite the occurrences
+/// differently (e.g. push down different projections).
+pub(crate) fn eliminate_common_subplans(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) {
+    let cache_ids = find_common_subplans(root, lp_arena, expr_arena);
+
+    // Move the subplan to a new node and put the cache in its place, so that the parents
+    // don't have to be updated.
+    for (node, id) in cache_ids {
+        let lp = lp_arena.take(node);
+        let input = lp_arena.add(lp);
+        lp_arena.replace(node, ALogicalPlan::Cache { input, id });
+    }
+}
@@ -0,0 +1,519 @@
+use crate::logical_plan::alp::ALogicalPlanBuilder;
+use crate::prelude::*;
+use polars_core::datatypes::PlHashMap;
+use polars_core::prelude::*;
+use std::cmp::Ordering;
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Fraction of the rows that is assumed to pass a filter.
+const FILTER_SELECTIVITY: f64 = 0.5;
+/// Maximum number of values that are used to estimate the number of distinct values.
+const DISTINCT_SAMPLE_SIZE: usize = 10_000;
+
+/// The statistics that are gathered during a single run of the optimizer.
+pub(crate) struct Statistics {
+    /// Read the number of rows of scanned files from the files
+    read_files: bool,
+    /// The number of rows of the files that are read, so that every file is read once
+    #[cfg(any(feature = "parquet", feature = "csv-file"))]
+    file_rows: PlHashMap<PathBuf, Option<f64>>,
+}
+
+impl Statistics {
+    pub(crate) fn new(read_files: bool) -> Self {
+        Statistics {
+            read_files,
+            #[cfg(any(feature = "parquet", feature = "csv-file"))]
+            file_rows: PlHashMap::new(),
+        }
+    }
+
+    /// Get the number of rows of the file at `path`, or `estimate` them if reading files
+    /// is allowed.
+    #[cfg(any(feature = "parquet", feature = "csv-file"))]
+    fn file_rows<F>(&mut self, path: &Path, estimate: F) -> Option<f64>
+    where
+        F: FnOnce(&Path) -> Option<f64>,
+    {
+        if !self.read_files {
+            return None;
+        }
+        if let Some(rows) = self.file_rows.get(path) {
+            return *rows;
+        }
+        let rows = estimate(path);
+        self.file_rows.insert(path.to_path_buf(), rows);
+        rows
+    }
+}
+
+/// Compare two estimates of a number of rows. NaN is larger than any other estimate.
+fn cmp_rows(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+/// Estimate the number of rows `node` produces.
+pub(crate) fn estimate_rows(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    stats: &mut Statistics,
+) -> Option<f64> {
+    use ALogicalPlan::*;
+    let rows = match lp_arena.get(node) {
+        DataFrameScan { df, selection, .. } => {
+            let rows = df.height() as f64;
+            match selection {
+                Some(_) => rows * FILTER_SELECTIVITY,
+                None => rows,
+            }
+        }
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            path,
+            predicate,
+            options,
+            ..
+        } => {
+            use polars_io::SerReader as _;
+            let mut rows = stats.file_rows(path, |path| {
+                let file = std::fs::File::open(path).ok()?;
+                let rows = polars_io::parquet::ParquetReader::new(file)
+                    .num_rows()
+                    .ok()?;
+                Some(rows as f64)
+            })?;
+            if let Some(n_rows) = options.n_rows {
+                rows = rows.min(n_rows as f64)
+            }
+            match predicate {
+                Some(_) => rows * FILTER_SELECTIVITY,
+                None => rows,
+            }
+        }
+        #[cfg(feature = "csv-file")]
+        CsvScan {
+            path,
+            predicate,
+            options,
+            ..
+        } => {
+            let mut rows = stats.file_rows(path, estimate_csv_lines)?;
+            rows -= options.skip_rows as f64;
+            if options.has_header {
+                rows -= 1.0;
+            }
+            rows = rows.max(0.0);
+            if let Some(n_rows) = options.n_rows {
+                rows = rows.min(n_rows as f64)
+            }
+            match predicate {
+                Some(_) => rows * FILTER_SELECTIVITY,
+                None => rows,
+            }
+        }
+        Selection { input, .. } => estimate_rows(*input, lp_arena, stats)? * FILTER_SELECTIVITY,
+        Slice { input, len, .. } => estimate_rows(*input, lp_arena, stats)?.min(*len as f64),
+        Projection { input, .. }
+        | LocalProjection { input, .. }
+        | HStack { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. }
+        | Distinct { input, .. } => estimate_rows(*input, lp_arena, stats)?,
+        Union { inputs } => inputs
+            .iter()
+            .map(|input| estimate_rows(*input, lp_arena, stats))
+            .sum::<Option<f64>>()?,
+        _ => return None,
+    };
+    // a nonsensical estimate is no estimate
+    if rows.is_finite() {
+        Some(rows)
+    } else {
+        None
+    }
+}
+
+/// Estimate the number of lines of a csv file from the size of the file and the length of
+/// the lines at the start of the file.
+#[cfg(feature = "csv-file")]
+fn estimate_csv_lines(path: &Path) -> Option<f64> {
+    use std::io::Read;
+    const SAMPLE_BYTES: u64 = 64 * 1024;
+
+    let file = std::fs::File::open(path).ok()?;
+    let file_size = file.metadata().ok()?.len();
+    let mut sample = Vec::with_capacity(SAMPLE_BYTES as usize);
+    file.take(SAMPLE_BYTES).read_to_end(&mut sample).ok()?;
+    let n_lines = sample.iter().filter(|b| **b == b'\n').count().max(1);
+
+    Some(file_size as f64 / sample.len().max(1) as f64 * n_lines as f64)
+}
+
+/// Estimate the number of distinct values of `column` in the output of `node`.
+pub(crate) fn estimate_distinct(
+    node: Node,
+    column: &str,
+    lp_arena: &Arena<ALogicalPlan>,
+    stats: &mut Statistics,
+) -> Option<f64> {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        DataFrameScan { df, .. } => {
+            let s = df.column(column).ok()?;
+            let n_distinct = if s.len() <= DISTINCT_SAMPLE_SIZE {
+                s.n_unique().ok()? as f64
+            } else {
+                // take an evenly spaced sample
+                let step = s.len() / DISTINCT_SAMPLE_SIZE;
+                let idx: NoNull<UInt32Chunked> = (0..DISTINCT_SAMPLE_SIZE)
+                    .map(|i| (i * step) as u32)
+                    .collect();
+                let sample = s.take(&idx.into_inner()).ok()?;
+                let ratio = sample.n_unique().ok()? as f64 / DISTINCT_SAMPLE_SIZE as f64;
+                // a column with mostly unique values in the sample is likely a key, otherwise
+                // the sample likely contains most of the values of the column.
+                if ratio > 0.9 {
+                    ratio * s.len() as f64
+                } else {
+                    ratio * DISTINCT_SAMPLE_SIZE as f64
+                }
+            };
+            Some(n_distinct.min(estimate_rows(node, lp_arena, stats)?))
+        }
+        Selection { input, .. }
+        | Slice { input, .. }
+        | Projection { input, .. }
+        | LocalProjection { input, .. }
+        | HStack { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. }
+        | Distinct { input, .. } => {
+            let n_distinct = estimate_distinct(*input, column, lp_arena, stats)?;
+            Some(n_distinct.min(estimate_rows(node, lp_arena, stats)?))
+        }
+        _ => None,
+    }
+}
+
+/// The estimated size of a (joined) relation and the number of distinct values of its keys.
+#[derive(Clone)]
+struct Relation {
+    rows: f64,
+    distinct: PlHashMap<Arc<str>, f64>,
+}
+
+impl Relation {
+    /// Estimate the size of the natural join of two relations, assuming that the keys are
+    /// independent and that all values of the key with fewer distinct values have a match.
+    fn join(&self, other: &Relation) -> Relation {
+        let mut rows = self.rows * other.rows;
+        let mut distinct = self.distinct.clone();
+        for (key, n_other) in &other.distinct {
+            match distinct.get_mut(key) {
+                Some(n_self) => {
+                    rows /= n_self.max(*n_other).max(1.0);
+                    *n_self = n_self.min(*n_other);
+                }
+                None => {
+                    distinct.insert(key.clone(), *n_other);
+                }
+            }
+        }
+        for n in distinct.values_mut() {
+            *n = n.min(rows);
+        }
+        Relation { rows, distinct }
+    }
+
+    fn shares_key(&self, other: &Relation) -> bool {
+        other.distinct.keys().any(|k| self.distinct.contains_key(k))
+    }
+}
+
+fn columns(names: &[Arc<str>], expr_arena: &mut Arena<AExpr>) -> Vec<Node> {
+    names
+        .iter()
+        .map(|name| expr_arena.add(AExpr::Column(name.clone())))
+        .collect()
+}
+
+fn column_names(nodes: &[Node], expr_arena: &Arena<AExpr>) -> Option<Vec<Arc<str>>> {
+    nodes
+        .iter()
+        .map(|node| match expr_arena.get(*node) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// An inner join that can be reordered with other inner joins. Its keys are columns with the
+/// same names in both tables, and these are the only column names the tables share. Such
+/// joins are natural joins, which can be executed in any order.
+fn reorderable_keys<'a>(
+    node: Node,
+    lp_arena: &'a Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(Vec<Arc<str>>, &'a JoinOptions)> {
+    if let ALogicalPlan::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    {
+        #[cfg(feature = "range_join")]
+        if !options.range_predicates.is_empty() {
+            return None;
+        }
+        if options.how != JoinType::Inner
+            || options.key_options.validate != JoinValidation::ManyToMany
+        {
+            return None;
+        }
+        let keys = column_names(left_on, expr_arena)?;
+        if keys != column_names(right_on, expr_arena)? {
+            return None;
+        }
+        let schema_right = lp_arena.get(*input_right).schema(lp_arena);
+        let shared = lp_arena
+            .get(*input_left)
+            .schema(lp_arena)
+            .fields()
+            .iter()
+            .filter(|f| schema_right.index_of(f.name()).is_ok())
+            .count();
+        let all_keys_shared = keys.iter().all(|k| schema_right.index_of(k).is_ok());
+        if shared == keys.len() && all_keys_shared {
+            return Some((keys, options));
+        }
+    }
+    None
+}
+
+/// Joins behave the same if they have the same options. Only such joins form a chain, as the
+/// reordered joins all get the options of the top of the chain.
+fn same_options(a: &JoinOptions, b: &JoinOptions) -> bool {
+    a.how == b.how
+        && a.suffix == b.suffix
+        && a.key_options == b.key_options
+        && a.allow_parallel == b.allow_parallel
+        && a.force_parallel == b.force_parallel
+}
+
+/// Collect the inputs of a chain of reorderable joins with `options` and all the join keys.
+fn collect_chain(
+    node: Node,
+    options: &JoinOptions,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    leaves: &mut Vec<Node>,
+    keys: &mut Vec<Arc<str>>,
+) {
+    match reorderable_keys(node, lp_arena, expr_arena) {
+        Some((join_keys, join_options)) if same_options(join_options, options) => {
+            for key in join_keys {
+                if !keys.contains(&key) {
+                    keys.push(key)
+                }
+            }
+            for input in lp_arena.get(node).get_inputs() {
+                collect_chain(input, options, lp_arena, expr_arena, leaves, keys);
+            }
+        }
+        _ => leaves.push(node),
+    }
+}
+
+/// Sum of the estimated sizes of the intermediate results of the joins below `node` as
+/// they are written, and the estimated output of `node`.
+fn original_cost(
+    node: Node,
+    relations: &PlHashMap<Node, Relation>,
+    lp_arena: &Arena<ALogicalPlan>,
+) -> (f64, Relation) {
+    match relations.get(&node) {
+        Some(relation) => (0.0, relation.clone()),
+        None => {
+            let inputs = lp_arena.get(node).get_inputs();
+            let (cost_left, left) = original_cost(inputs[0], relations, lp_arena);
+            let (cost_right, right) = original_cost(inputs[1], relations, lp_arena);
+            let joined = left.join(&right);
+            (cost_left + cost_right + joined.rows, joined)
+        }
+    }
+}
+
+/// Greedily determine a join order that keeps the intermediate results small. Every join must
+/// share a key with the already joined relations, so that no cross joins are introduced.
+fn greedy_order(
+    leaves: &[Node],
+    relations: &PlHashMap<Node, Relation>,
+) -> Option<(Vec<Node>, f64)> {
+    let mut remaining = leaves.to_vec();
+
+    // start with the smallest join of two relations
+    let mut best: Option<(usize, usize, Relation)> = None;
+    for i in 0..remaining.len() {
+        for j in i + 1..remaining.len() {
+            let (a, b) = (&relations[&remaining[i]], &relations[&remaining[j]]);
+            if !a.shares_key(b) {
+                continue;
+            }
+            let joined = a.join(b);
+            if best.as_ref().map_or(true, |(_, _, r)| joined.rows < r.rows) {
+                // the larger relation is probed, the smaller one is used to build the hash table
+                if a.rows >= b.rows {
+                    best = Some((i, j, joined))
+                } else {
+                    best = Some((j, i, joined))
+                }
+            }
+        }
+    }
+    let (i, j, mut current) = best?;
+    let mut order = vec![remaining[i], remaining[j]];
+    remaining.retain(|node| !order.contains(node));
+    let mut cost = current.rows;
+
+    while !remaining.is_empty() {
+        let (idx, joined) = remaining
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| current.shares_key(&relations[node]))
+            .map(|(idx, node)| (idx, current.join(&relations[node])))
+            .min_by(|(_, a), (_, b)| cmp_rows(a.rows, b.rows))?;
+        order.push(remaining.remove(idx));
+        current = joined;
+        cost += current.rows;
+    }
+    Some((order, cost))
+}
+
+/// Reorder the chain of joins with `root` as top if the estimates indicate that this results
+/// in smaller intermediate results.
+fn reorder_chain(
+    root: Node,
+    options: JoinOptions,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    stats: &mut Statistics,
+) -> bool {
+    let mut leaves = vec![];
+    let mut keys = vec![];
+    collect_chain(root, &options, lp_arena, expr_arena, &mut leaves, &mut keys);
+    if leaves.len() < 3 {
+        return false;
+    }
+
+    let mut relations = PlHashMap::with_capacity(leaves.len());
+    for leaf in &leaves {
+        let rows = match estimate_rows(*leaf, lp_arena, stats) {
+            Some(rows) => rows,
+            None => return false,
+        };
+        let schema = lp_arena.get(*leaf).schema(lp_arena);
+        let distinct = keys
+            .iter()
+            .filter(|key| schema.index_of(key).is_ok())
+            .map(|key| {
+                // without an estimate we assume that the key is unique
+                let n = estimate_distinct(*leaf, key, lp_arena, stats).unwrap_or(rows);
+                (key.clone(), n)
+            })
+            .collect();
+        relations.insert(*leaf, Relation { rows, distinct });
+    }
+
+    // the output of the last join is the same for every order
+    let (cost, output) = original_cost(root, &relations, lp_arena);
+    let cost = cost - output.rows;
+    let (order, new_cost) = match greedy_order(&leaves, &relations) {
+        Some(order) => order,
+        None => return false,
+    };
+    let new_cost = new_cost - output.rows;
+    if new_cost >= cost {
+        return false;
+    }
+
+    let schema = match lp_arena.get(root) {
+        ALogicalPlan::Join { schema, .. } => schema.clone(),
+        _ => unreachable!(),
+    };
+    // the keys of a join are the columns that the joined tables share
+    let mut joined = lp_arena.get(order[0]).schema(lp_arena).as_ref().clone();
+    let mut join_keys = Vec::with_capacity(order.len() - 1);
+    for leaf in &order[1..] {
+        let leaf_schema = lp_arena.get(*leaf).schema(lp_arena);
+        let mut keys = vec![];
+        let mut fields = joined.fields().clone();
+        for f in leaf_schema.fields() {
+            if joined.index_of(f.name()).is_ok() {
+                keys.push(Arc::from(f.name().as_str()))
+            } else {
+                fields.push(f.clone())
+            }
+        }
+        joined = Schema::new(fields);
+        join_keys.push(keys);
+    }
+    let join_exprs = join_keys
+        .iter()
+        .map(|keys| (columns(keys, expr_arena), columns(keys, expr_arena)))
+        .collect::<Vec<_>>();
+    // restore the column order of the original join
+    let output_names = schema
+        .fields()
+        .iter()
+        .map(|f| Arc::from(f.name().as_str()))
+        .collect::<Vec<_>>();
+    let output = columns(&output_names, expr_arena);
+
+    let mut builder = ALogicalPlanBuilder::new(order[0], expr_arena, lp_arena);
+    for (leaf, (left_on, right_on)) in order[1..].iter().zip(join_exprs) {
+        builder = builder.join(*leaf, left_on, right_on, options.clone());
+    }
+    let lp = builder.project(output).build();
+    lp_arena.replace(root, lp);
+    true
+}
+
+/// Reorder chains of inner joins based on estimates of the sizes of the joined tables and
+/// the number of distinct values of the join keys.
+pub(crate) fn reorder_joins(
+    node: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    stats: &mut Statistics,
+) {
+    let options = reorderable_keys(node, lp_arena, expr_arena).map(|(_, options)| options.clone());
+    match options {
+        Some(options) => {
+            let mut leaves = vec![];
+            collect_chain(
+                node,
+                &options,
+                lp_arena,
+                expr_arena,
+                &mut leaves,
+                &mut vec![],
+            );
+            // chains below the inputs of this chain are reordered on their own
+            for leaf in &leaves {
+                reorder_joins(*leaf, lp_arena, expr_arena, stats);
+            }
+            reorder_chain(node, options, lp_arena, expr_arena, stats);
+        }
+        None => {
+            for input in lp_arena.get(node).get_inputs() {
+                reorder_joins(input, lp_arena, expr_arena, stats);
+            }
+        }
+    }
+}
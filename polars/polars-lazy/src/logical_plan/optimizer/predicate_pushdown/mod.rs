@@ -137,16 +137,15 @@ impl PredicatePushDown {
 
             Melt {
                 input,
-                id_vars,
-                value_vars,
+                args,
                 schema,
             } => {
                 // predicates that will be done at this level
                 let condition = |name: Arc<str>| {
                     let name = &*name;
-                    name == "variable"
-                        || name == "value"
-                        || value_vars.iter().any(|s| s.as_str() == name)
+                    name == args.variable_name()
+                        || name == args.value_name()
+                        || args.value_vars.iter().any(|s| s.as_str() == name)
                 };
                 let local_predicates =
                     transfer_to_local(expr_arena, &mut acc_predicates, condition);
@@ -155,8 +154,7 @@ impl PredicatePushDown {
 
                 let lp = ALogicalPlan::Melt {
                     input,
-                    id_vars,
-                    value_vars,
+                    args,
                     schema,
                 };
                 Ok(self.apply_predicate(lp, local_predicates, lp_arena, expr_arena))
@@ -229,6 +227,18 @@ impl PredicatePushDown {
                 predicate,
                 aggregate,
             } => {
+                // filtering the sample is not the same as sampling the filtered rows
+                if options.reservoir_sample.is_some() {
+                    let lp = CsvScan {
+                        path,
+                        schema,
+                        output_schema,
+                        options,
+                        predicate,
+                        aggregate,
+                    };
+                    return Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena));
+                }
                 let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
 
                 let lp = CsvScan {
@@ -320,6 +330,13 @@ impl PredicatePushDown {
                 let mut pushdown_right = optimizer::init_hashmap();
                 let mut local_predicates = Vec::with_capacity(acc_predicates.len());
 
+                // the output of semi and anti joins only consists of the left table.
+                // predicates should never filter the right table as that changes the matches.
+                #[cfg(feature = "semi_anti_join")]
+                let right_filter_allowed = !matches!(options.how, JoinType::Semi | JoinType::Anti);
+                #[cfg(not(feature = "semi_anti_join"))]
+                let right_filter_allowed = true;
+
                 for (_, predicate) in acc_predicates {
                     // unique and duplicated can be caused by joins
                     let matches =
@@ -343,7 +360,8 @@ impl PredicatePushDown {
                         );
                         filter_left = true;
                     }
-                    if check_input_node(predicate, schema_right, expr_arena) {
+                    if right_filter_allowed && check_input_node(predicate, schema_right, expr_arena)
+                    {
                         let name = get_insertion_name(expr_arena, predicate, schema_right);
                         insert_and_combine_predicate(
                             &mut pushdown_right,
@@ -464,13 +482,16 @@ impl PredicatePushDown {
 
                     return Ok(self.apply_predicate(lp, local_predicates, lp_arena, expr_arena));
                 }
-                Ok(Udf {
+                // the predicates cannot pass the function, so they are applied on its output
+                self.pushdown_and_assign(input, optimizer::init_hashmap(), lp_arena, expr_arena)?;
+                let lp = Udf {
                     input,
                     function,
                     predicate_pd,
                     projection_pd,
                     schema,
-                })
+                };
+                Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena))
             }
             lp @ Slice { .. } | lp @ Cache { .. } | lp @ Union { .. } | lp @ Sort { .. } => {
                 let inputs = lp.get_inputs();
@@ -511,10 +511,26 @@ impl ProjectionPushDown {
             }
             Melt {
                 input,
-                id_vars,
-                value_vars,
-                ..
+                args,
+                schema,
             } => {
+                // the physical planner reports the invalid arguments, so we don't prune the input
+                if check_melt_args(&args, lp_arena.get(input).schema(lp_arena)).is_err() {
+                    self.pushdown_and_assign(
+                        input,
+                        init_vec(),
+                        init_set(),
+                        projections_seen,
+                        lp_arena,
+                        expr_arena,
+                    )?;
+                    return Ok(Melt {
+                        input,
+                        args,
+                        schema,
+                    });
+                }
+
                 let (mut acc_projections, mut local_projections, names) = split_acc_projections(
                     acc_projections,
                     lp_arena.get(input).schema(lp_arena),
@@ -526,7 +542,7 @@ impl ProjectionPushDown {
                 }
 
                 // make sure that the requested columns are projected
-                id_vars.iter().for_each(|name| {
+                args.id_vars.iter().for_each(|name| {
                     add_str_to_accumulated(
                         name,
                         &mut acc_projections,
@@ -534,7 +550,7 @@ impl ProjectionPushDown {
                         expr_arena,
                     )
                 });
-                value_vars.iter().for_each(|name| {
+                args.value_vars.iter().for_each(|name| {
                     add_str_to_accumulated(
                         name,
                         &mut acc_projections,
@@ -552,8 +568,7 @@ impl ProjectionPushDown {
                     expr_arena,
                 )?;
 
-                let builder =
-                    ALogicalPlanBuilder::new(input, expr_arena, lp_arena).melt(id_vars, value_vars);
+                let builder = ALogicalPlanBuilder::new(input, expr_arena, lp_arena).melt(args);
                 Ok(self.finish_node(local_projections, builder))
             }
             Aggregate {
@@ -625,6 +640,67 @@ impl ProjectionPushDown {
                     Ok(builder.build())
                 }
             }
+            #[cfg(feature = "semi_anti_join")]
+            Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                options,
+                ..
+            } if matches!(options.how, JoinType::Semi | JoinType::Anti) => {
+                // semi and anti joins only filter the left table.
+                // The right table only needs to provide the join keys.
+                let mut pushdown_right = init_vec();
+                let mut names_right = init_set();
+                for e in &right_on {
+                    add_expr_to_accumulated(*e, &mut pushdown_right, &mut names_right, expr_arena);
+                }
+
+                // The join keys of the left table are needed to execute the join,
+                // if they are not projected we need to drop them after the join.
+                let mut local_projection = init_vec();
+                if !acc_projections.is_empty() {
+                    let n_projections = acc_projections.len();
+                    local_projection.extend_from_slice(&acc_projections);
+                    for e in &left_on {
+                        add_expr_to_accumulated(
+                            *e,
+                            &mut acc_projections,
+                            &mut projected_names,
+                            expr_arena,
+                        );
+                    }
+                    if acc_projections.len() == n_projections {
+                        local_projection.clear();
+                    }
+                }
+
+                self.pushdown_and_assign(
+                    input_left,
+                    acc_projections,
+                    projected_names,
+                    projections_seen,
+                    lp_arena,
+                    expr_arena,
+                )?;
+                self.pushdown_and_assign(
+                    input_right,
+                    pushdown_right,
+                    names_right,
+                    projections_seen,
+                    lp_arena,
+                    expr_arena,
+                )?;
+
+                let builder = ALogicalPlanBuilder::new(input_left, expr_arena, lp_arena).join(
+                    input_right,
+                    left_on,
+                    right_on,
+                    options,
+                );
+                Ok(self.finish_node(local_projection, builder))
+            }
             Join {
                 input_left,
                 input_right,
@@ -672,6 +748,26 @@ impl ProjectionPushDown {
                         );
                         // we don't add right column names to local_projection as they are removed
                     }
+                    // the columns of the range predicates are needed to evaluate the join
+                    #[cfg(feature = "range_join")]
+                    for predicate in &options.range_predicates {
+                        let node =
+                            expr_arena.add(AExpr::Column(Arc::from(predicate.left.as_str())));
+                        add_expr_to_accumulated(
+                            node,
+                            &mut pushdown_left,
+                            &mut names_left,
+                            expr_arena,
+                        );
+                        let node =
+                            expr_arena.add(AExpr::Column(Arc::from(predicate.right.as_str())));
+                        add_expr_to_accumulated(
+                            node,
+                            &mut pushdown_right,
+                            &mut names_right,
+                            expr_arena,
+                        );
+                    }
 
                     for proj in acc_projections {
                         let mut add_local = true;
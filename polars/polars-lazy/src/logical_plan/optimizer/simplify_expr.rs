@@ -340,6 +340,7 @@ impl OptimizationRule for SimplifyExprRule {
                     Operator::And => eval_bitwise(left, right, |l, r| l & r),
                     Operator::Or => eval_bitwise(left, right, |l, r| l | r),
                     Operator::Xor => eval_bitwise(left, right, |l, r| l ^ r),
+                    Operator::ShiftLeft | Operator::ShiftRight => None,
                 }
             }
             AExpr::Reverse(expr) => {
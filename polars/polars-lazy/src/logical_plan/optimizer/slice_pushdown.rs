@@ -0,0 +1,307 @@
+use crate::prelude::*;
+use crate::utils::{aexpr_is_elementwise, has_aexpr};
+use polars_core::prelude::*;
+
+#[derive(Clone, Copy)]
+struct State {
+    offset: i64,
+    len: usize,
+}
+
+impl State {
+    /// The slice that selects at least all the rows of this slice.
+    /// Only valid if the offset is not negative.
+    fn head(self) -> State {
+        State {
+            offset: 0,
+            len: (self.offset as usize).saturating_add(self.len),
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct SlicePushDown {}
+
+impl SlicePushDown {
+    fn apply_slice(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+    ) -> ALogicalPlan {
+        match state {
+            Some(state) => {
+                let input = lp_arena.add(lp);
+                ALogicalPlan::Slice {
+                    input,
+                    offset: state.offset,
+                    len: state.len,
+                }
+            }
+            None => lp,
+        }
+    }
+
+    fn pushdown_and_assign(
+        &self,
+        input: Node,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<()> {
+        let alp = lp_arena.take(input);
+        let lp = self.push_down(alp, state, lp_arena, expr_arena)?;
+        lp_arena.replace(input, lp);
+        Ok(())
+    }
+
+    /// The slice cannot be pushed down further. It is applied on top of this node and the
+    /// optimization restarts in the inputs.
+    fn no_pushdown_restart_opt(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        let inputs = lp.get_inputs();
+        let exprs = lp.get_exprs();
+        for node in &inputs {
+            self.pushdown_and_assign(*node, None, lp_arena, expr_arena)?;
+        }
+        let lp = lp.from_exprs_and_input(exprs, inputs);
+        Ok(self.apply_slice(lp, state, lp_arena))
+    }
+
+    /// The slice is pushed down to all inputs of this node.
+    fn pushdown_and_continue(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        let inputs = lp.get_inputs();
+        let exprs = lp.get_exprs();
+        for node in &inputs {
+            self.pushdown_and_assign(*node, state, lp_arena, expr_arena)?;
+        }
+        Ok(lp.from_exprs_and_input(exprs, inputs))
+    }
+
+    /// Slice pushdown optimizer
+    ///
+    /// # Arguments
+    ///
+    /// * `AlogicalPlan` - Arena based logical plan tree representing the query.
+    /// * `state` - The slice that is pushed down. It is applied as soon as it cannot be pushed
+    ///             down any further.
+    /// * `lp_arena` - The local memory arena for the logical plan.
+    /// * `expr_arena` - The local memory arena for the expressions.
+    fn push_down(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        use ALogicalPlan::*;
+
+        match (lp, state) {
+            (Slice { input, offset, len }, None) => {
+                let alp = lp_arena.take(input);
+                self.push_down(alp, Some(State { offset, len }), lp_arena, expr_arena)
+            }
+            // combine two slices
+            (Slice { input, offset, len }, Some(outer)) if offset >= 0 && outer.offset >= 0 => {
+                let state = State {
+                    offset: offset + outer.offset,
+                    len: std::cmp::min(outer.len, len.saturating_sub(outer.offset as usize)),
+                };
+                let alp = lp_arena.take(input);
+                self.push_down(alp, Some(state), lp_arena, expr_arena)
+            }
+            // slices with a negative offset are only applied at the end of their input
+            (lp @ Slice { .. }, Some(outer)) => {
+                let lp = self.push_down(lp, None, lp_arena, expr_arena)?;
+                Ok(self.apply_slice(lp, Some(outer), lp_arena))
+            }
+
+            // the scans only read the rows that are needed
+            #[cfg(feature = "csv-file")]
+            (
+                CsvScan {
+                    path,
+                    schema,
+                    output_schema,
+                    mut options,
+                    predicate: None,
+                    aggregate,
+                },
+                Some(state),
+            ) if state.offset >= 0
+                && aggregate.is_empty()
+                && options.reservoir_sample.is_none() =>
+            {
+                options.n_rows = Some(min_rows(options.n_rows, state));
+                let lp = CsvScan {
+                    path,
+                    schema,
+                    output_schema,
+                    options,
+                    predicate: None,
+                    aggregate,
+                };
+                Ok(self.apply_slice(lp, remaining_slice(state), lp_arena))
+            }
+            #[cfg(feature = "ipc")]
+            (
+                IpcScan {
+                    path,
+                    schema,
+                    output_schema,
+                    mut options,
+                    predicate: None,
+                    aggregate,
+                },
+                Some(state),
+            ) if state.offset >= 0 && aggregate.is_empty() => {
+                options.n_rows = Some(min_rows(options.n_rows, state));
+                let lp = IpcScan {
+                    path,
+                    schema,
+                    output_schema,
+                    options,
+                    predicate: None,
+                    aggregate,
+                };
+                Ok(self.apply_slice(lp, remaining_slice(state), lp_arena))
+            }
+            #[cfg(feature = "parquet")]
+            (
+                ParquetScan {
+                    path,
+                    schema,
+                    output_schema,
+                    predicate: None,
+                    aggregate,
+                    mut options,
+                },
+                Some(state),
+            ) if state.offset >= 0 && aggregate.is_empty() => {
+                options.n_rows = Some(min_rows(options.n_rows, state));
+                let lp = ParquetScan {
+                    path,
+                    schema,
+                    output_schema,
+                    predicate: None,
+                    aggregate,
+                    options,
+                };
+                Ok(self.apply_slice(lp, remaining_slice(state), lp_arena))
+            }
+
+            // elementwise projections don't change the number of rows, so we can slice
+            // their input instead.
+            (
+                lp @ Projection { .. } | lp @ LocalProjection { .. } | lp @ HStack { .. },
+                Some(state),
+            ) => {
+                let exprs = lp.get_exprs();
+                let elementwise = exprs
+                    .iter()
+                    .all(|node| aexpr_is_elementwise(*node, expr_arena));
+                // a projection of only literals has a single row
+                let has_column = matches!(lp, HStack { .. })
+                    || exprs.iter().any(|node| {
+                        has_aexpr(*node, expr_arena, |e| matches!(e, AExpr::Column(_)))
+                    });
+                if elementwise && has_column {
+                    self.pushdown_and_continue(lp, Some(state), lp_arena, expr_arena)
+                } else {
+                    self.no_pushdown_restart_opt(lp, Some(state), lp_arena, expr_arena)
+                }
+            }
+
+            // every row of the left table produces at least one row in the output of a left or
+            // cross join, in the order of the left table. So the first `n` rows of the output
+            // only depend on the first `n` rows of the left table.
+            (
+                Join {
+                    input_left,
+                    input_right,
+                    schema,
+                    left_on,
+                    right_on,
+                    options,
+                },
+                Some(state),
+            ) if state.offset >= 0
+                && preserves_left_rows(&options.how)
+                && options.key_options.validate == JoinValidation::ManyToMany =>
+            {
+                self.pushdown_and_assign(input_left, Some(state.head()), lp_arena, expr_arena)?;
+                self.pushdown_and_assign(input_right, None, lp_arena, expr_arena)?;
+                let lp = Join {
+                    input_left,
+                    input_right,
+                    schema,
+                    left_on,
+                    right_on,
+                    options,
+                };
+                Ok(self.apply_slice(lp, Some(state), lp_arena))
+            }
+
+            // the first `n` rows of a union are in the first `n` rows of its inputs
+            (lp @ Union { .. }, Some(state)) if state.offset >= 0 => {
+                let lp =
+                    self.pushdown_and_continue(lp, Some(state.head()), lp_arena, expr_arena)?;
+                Ok(self.apply_slice(lp, Some(state), lp_arena))
+            }
+
+            // A sort followed by a slice is executed as a partial sort.
+            // Caches may be shared by multiple parts of the plan, so we don't change their input.
+            // All other nodes change the number or the order of the rows.
+            (lp, state) => self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena),
+        }
+    }
+
+    pub fn optimize(
+        &self,
+        logical_plan: ALogicalPlan,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        self.push_down(logical_plan, None, lp_arena, expr_arena)
+    }
+}
+
+/// The number of rows a scan has to read for this slice.
+#[cfg(any(feature = "csv-file", feature = "ipc", feature = "parquet"))]
+fn min_rows(n_rows: Option<usize>, state: State) -> usize {
+    let needed = state.head().len;
+    match n_rows {
+        Some(n_rows) => std::cmp::min(n_rows, needed),
+        None => needed,
+    }
+}
+
+/// The slice that still has to be applied after a scan that read the first rows.
+#[cfg(any(feature = "csv-file", feature = "ipc", feature = "parquet"))]
+fn remaining_slice(state: State) -> Option<State> {
+    if state.offset == 0 {
+        None
+    } else {
+        Some(state)
+    }
+}
+
+fn preserves_left_rows(how: &JoinType) -> bool {
+    match how {
+        JoinType::Left => true,
+        #[cfg(feature = "cross_join")]
+        JoinType::Cross => true,
+        _ => false,
+    }
+}
@@ -12,6 +12,19 @@ impl StackOptimizer {
         expr_arena: &mut Arena<AExpr>,
         lp_arena: &mut Arena<ALogicalPlan>,
         lp_top: Node,
+    ) -> Node {
+        self.optimize_loop_traced(rules, expr_arena, lp_arena, lp_top, &mut |_, _, _| {})
+    }
+
+    /// Like `optimize_loop`, but `on_change` is called with the index of the rule after every
+    /// change a rule makes to the plan.
+    pub(crate) fn optimize_loop_traced(
+        &self,
+        rules: &mut [Box<dyn OptimizationRule>],
+        expr_arena: &mut Arena<AExpr>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        lp_top: Node,
+        on_change: &mut dyn FnMut(usize, &Arena<ALogicalPlan>, &Arena<AExpr>),
     ) -> Node {
         let mut changed = true;
 
@@ -27,11 +40,12 @@ impl StackOptimizer {
             plans.push(lp_top);
             while let Some(current_node) = plans.pop() {
                 // apply rules
-                for rule in rules.iter_mut() {
+                for (i, rule) in rules.iter_mut().enumerate() {
                     // keep iterating over same rule
                     while let Some(x) = rule.optimize_plan(lp_arena, expr_arena, current_node) {
                         lp_arena.replace(current_node, x);
                         changed = true;
+                        on_change(i, lp_arena, expr_arena);
                     }
                 }
 
@@ -43,7 +57,7 @@ impl StackOptimizer {
 
                 // process the expressions on the stack and apply optimizations.
                 while let Some(current_expr_node) = exprs.pop() {
-                    for rule in rules.iter() {
+                    for (i, rule) in rules.iter().enumerate() {
                         // keep iterating over same rule
                         while let Some(x) = rule.optimize_expr(
                             expr_arena,
@@ -53,6 +67,7 @@ impl StackOptimizer {
                         ) {
                             expr_arena.replace(current_expr_node, x);
                             changed = true;
+                            on_change(i, lp_arena, expr_arena);
                         }
                     }
 
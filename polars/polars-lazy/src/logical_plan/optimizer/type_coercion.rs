@@ -137,7 +137,10 @@ impl OptimizationRule for TypeCoercionRule {
                         && type_right == DataType::Utf8)
                         || (type_left == DataType::Utf8 && type_right == DataType::Categorical));
 
-                    if type_left == type_right || compare_cat_to_string {
+                    // the shift amount is cast by the kernel and does not change the output type
+                    let is_shift = matches!(op, Operator::ShiftLeft | Operator::ShiftRight);
+
+                    if type_left == type_right || compare_cat_to_string || is_shift {
                         None
                     } else {
                         let mut st = get_supertype(&type_left, &type_right)
@@ -1,6 +1,9 @@
 use polars_io::csv::NullValues;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct CsvParserOptions {
     pub(crate) delimiter: u8,
     pub(crate) comment_char: Option<u8>,
@@ -13,9 +16,12 @@ pub struct CsvParserOptions {
     pub(crate) ignore_errors: bool,
     pub(crate) cache: bool,
     pub(crate) null_values: Option<NullValues>,
+    /// Number of rows to sample and the seed
+    pub(crate) reservoir_sample: Option<(usize, u64)>,
 }
 #[cfg(feature = "parquet")]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct ParquetOptions {
     pub(crate) n_rows: Option<usize>,
     pub(crate) with_columns: Option<Vec<String>>,
@@ -24,6 +30,7 @@ pub struct ParquetOptions {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct ScanOptions {
     pub n_rows: Option<usize>,
     pub with_columns: Option<Vec<String>>,
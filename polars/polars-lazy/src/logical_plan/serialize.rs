@@ -0,0 +1,52 @@
+//! Closures cannot be serialized. A plan that contains one fails to serialize with an error
+//! that names the operation the closure belongs to.
+use crate::prelude::*;
+use serde::de::Error as DeError;
+use serde::ser::Error as SerError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! impl_udf_serde {
+    ($udf:ident, $operation:expr) => {
+        impl Serialize for dyn $udf {
+            fn serialize<S>(&self, _serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                Err(S::Error::custom(format!(
+                    "cannot serialize the closure of {}; the query can only be serialized if it does not contain user defined functions",
+                    $operation
+                )))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Box<dyn $udf> {
+            fn deserialize<D>(_deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Err(D::Error::custom(format!(
+                    "cannot deserialize the closure of {}",
+                    $operation
+                )))
+            }
+        }
+    };
+}
+
+impl_udf_serde!(SeriesUdf, "a `map`/`apply` expression (`Expr::Function`)");
+impl_udf_serde!(
+    FunctionOutputField,
+    "the output type of a `map`/`apply` expression (`Expr::Function`)"
+);
+impl_udf_serde!(
+    SeriesBinaryUdf,
+    "a `map_binary` expression (`Expr::BinaryFunction`)"
+);
+impl_udf_serde!(
+    BinaryUdfOutputField,
+    "the output field of a `map_binary` expression (`Expr::BinaryFunction`)"
+);
+impl_udf_serde!(
+    DataFrameUdf,
+    "a `LazyFrame::map` or `LazyGroupBy::apply` function"
+);
@@ -11,7 +11,7 @@ pub struct CacheExec {
 impl Executor for CacheExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         if let Some(df) = state.cache_hit(&self.key) {
-            return Ok(df);
+            return state.record("cache_hit", (), |_| Ok(df));
         }
 
         // cache miss
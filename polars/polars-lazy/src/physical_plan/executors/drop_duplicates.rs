@@ -11,9 +11,11 @@ pub(crate) struct DropDuplicatesExec {
 impl Executor for DropDuplicatesExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
-        df.drop_duplicates(
-            self.maintain_order,
-            self.subset.as_ref().map(|v| v.as_ref()),
-        )
+        state.record("drop_duplicates", df, |df| {
+            df.drop_duplicates(
+                self.maintain_order,
+                self.subset.as_ref().map(|v| v.as_ref()),
+            )
+        })
     }
 }
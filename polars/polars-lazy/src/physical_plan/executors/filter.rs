@@ -16,12 +16,14 @@ impl FilterExec {
 impl Executor for FilterExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
-        let s = self.predicate.evaluate(&df, state)?;
-        let mask = s.bool().expect("filter predicate wasn't of type boolean");
-        let df = df.filter(mask)?;
-        if state.verbose {
-            eprintln!("dataframe filtered");
-        }
-        Ok(df)
+        state.record("filter", df, |df| {
+            let s = self.predicate.evaluate(&df, state)?;
+            let mask = s.bool().expect("filter predicate wasn't of type boolean");
+            let df = df.filter(mask)?;
+            if state.verbose {
+                eprintln!("dataframe filtered");
+            }
+            Ok(df)
+        })
     }
 }
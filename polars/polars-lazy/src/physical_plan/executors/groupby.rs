@@ -87,19 +87,21 @@ impl Executor for GroupByExec {
             eprintln!("aggregates are not partitionable: running default HASH AGGREGATION")
         }
         let df = self.input.execute(state)?;
-        let keys = self
-            .keys
-            .iter()
-            .map(|e| e.evaluate(&df, state))
-            .collect::<Result<_>>()?;
-        groupby_helper(
-            df,
-            keys,
-            &self.aggs,
-            self.apply.as_ref(),
-            state,
-            self.maintain_order,
-        )
+        state.record("groupby", df, |df| {
+            let keys = self
+                .keys
+                .iter()
+                .map(|e| e.evaluate(&df, state))
+                .collect::<Result<_>>()?;
+            groupby_helper(
+                df,
+                keys,
+                &self.aggs,
+                self.apply.as_ref(),
+                state,
+                self.maintain_order,
+            )
+        })
     }
 }
 
@@ -195,7 +197,7 @@ fn get_outer_agg_exprs(
         })
         .collect::<Result<Vec<_>>>()?;
 
-    let planner = DefaultPlanner {};
+    let planner = DefaultPlanner::default();
 
     let outer_phys_aggs = aggs_and_names
         .iter()
@@ -215,7 +217,14 @@ fn sample_cardinality(key: &Series, sample_size: usize) -> f32 {
 impl Executor for PartitionGroupByExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let original_df = self.input.execute(state)?;
+        state.record("groupby_partitioned", original_df, |df| {
+            self.execute_impl(state, df)
+        })
+    }
+}
 
+impl PartitionGroupByExec {
+    fn execute_impl(&self, state: &ExecutionState, original_df: DataFrame) -> Result<DataFrame> {
         // already get the keys. This is the very last minute decision which groupby method we choose.
         // If the column is a categorical, we know the number of groups we have and can decide to continue
         // partitioned or go for the standard groupby. The partitioned is likely to be faster on a small number
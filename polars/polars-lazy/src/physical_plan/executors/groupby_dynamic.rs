@@ -18,41 +18,48 @@ impl Executor for GroupByDynamicExec {
         #[cfg(feature = "dynamic_groupby")]
         {
             let df = self.input.execute(state)?;
-            let keys = self
-                .keys
-                .iter()
-                .map(|e| e.evaluate(&df, state))
-                .collect::<Result<Vec<_>>>()?;
+            state.record("groupby_dynamic", df, |df| self.execute_impl(state, df))
+        }
+        #[cfg(not(feature = "dynamic_groupby"))]
+        panic!("activate feature dynamic_groupby")
+    }
+}
 
-            let (time_key, keys, groups) = df.groupby_dynamic(keys, &self.options)?;
+impl GroupByDynamicExec {
+    #[cfg(feature = "dynamic_groupby")]
+    fn execute_impl(&self, state: &ExecutionState, df: DataFrame) -> Result<DataFrame> {
+        let keys = self
+            .keys
+            .iter()
+            .map(|e| e.evaluate(&df, state))
+            .collect::<Result<Vec<_>>>()?;
 
-            let agg_columns = POOL.install(|| {
-                self.aggs
-                    .par_iter()
-                    .map(|expr| {
-                        let opt_agg = as_aggregated(expr.as_ref(), &df, &groups, state)?;
-                        if let Some(agg) = &opt_agg {
-                            if agg.len() != groups.len() {
-                                return Err(PolarsError::ComputeError(
-                                    format!("returned aggregation is a different length: {} than the group lengths: {}",
-                                            agg.len(),
-                                            groups.len()).into()
-                                ))
-                            }
-                        };
-                        Ok(opt_agg)
-                    })
-                    .collect::<Result<Vec<_>>>()
-            })?;
+        let (time_key, keys, groups) = df.groupby_dynamic(keys, &self.options)?;
 
-            let mut columns = Vec::with_capacity(agg_columns.len() + 1 + keys.len());
-            columns.extend(keys);
-            columns.push(time_key);
-            columns.extend(agg_columns.into_iter().flatten());
+        let agg_columns = POOL.install(|| {
+            self.aggs
+                .par_iter()
+                .map(|expr| {
+                    let opt_agg = as_aggregated(expr.as_ref(), &df, &groups, state)?;
+                    if let Some(agg) = &opt_agg {
+                        if agg.len() != groups.len() {
+                            return Err(PolarsError::ComputeError(
+                                format!("returned aggregation is a different length: {} than the group lengths: {}",
+                                        agg.len(),
+                                        groups.len()).into()
+                            ))
+                        }
+                    };
+                    Ok(opt_agg)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
 
-            DataFrame::new(columns)
-        }
-        #[cfg(not(feature = "dynamic_groupby"))]
-        panic!("activate feature dynamic_groupby")
+        let mut columns = Vec::with_capacity(agg_columns.len() + 1 + keys.len());
+        columns.extend(keys);
+        columns.push(time_key);
+        columns.extend(agg_columns.into_iter().flatten());
+
+        DataFrame::new(columns)
     }
 }
@@ -18,6 +18,11 @@ pub struct JoinExec {
     // not used if asof not activated
     #[allow(dead_code)]
     asof_by_right: Vec<String>,
+    key_options: JoinKeyOptions,
+    #[cfg(feature = "asof_join")]
+    asof_options: AsOfOptions,
+    #[cfg(feature = "range_join")]
+    range_predicates: Vec<InequalityPredicate>,
 }
 
 impl JoinExec {
@@ -32,6 +37,9 @@ impl JoinExec {
         suffix: Option<String>,
         asof_by_left: Vec<String>,
         asof_by_right: Vec<String>,
+        key_options: JoinKeyOptions,
+        #[cfg(feature = "asof_join")] asof_options: AsOfOptions,
+        #[cfg(feature = "range_join")] range_predicates: Vec<InequalityPredicate>,
     ) -> Self {
         JoinExec {
             input_left: Some(input_left),
@@ -43,6 +51,11 @@ impl JoinExec {
             suffix,
             asof_by_left,
             asof_by_right,
+            key_options,
+            #[cfg(feature = "asof_join")]
+            asof_options,
+            #[cfg(feature = "range_join")]
+            range_predicates,
         }
     }
 }
@@ -52,7 +65,9 @@ impl Executor for JoinExec {
         let mut input_left = self.input_left.take().unwrap();
         let mut input_right = self.input_right.take().unwrap();
 
-        let (df_left, df_right) = if self.parallel {
+        // only run the sides concurrently if the limit of parallel branches isn't reached
+        let parallel = self.parallel && state.acquire_branches(1) == 1;
+        let (df_left, df_right) = if parallel {
             let state_left = state.clone();
             let state_right = state.clone();
             // propagate the fetch_rows static value to the spawning threads.
@@ -71,10 +86,25 @@ impl Executor for JoinExec {
         } else {
             (input_left.execute(state), input_right.execute(state))
         };
+        if parallel {
+            state.release_branches(1);
+        }
 
         let df_left = df_left?;
         let df_right = df_right?;
+        state.record("join", (df_left, df_right), |(df_left, df_right)| {
+            self.execute_impl(state, df_left, df_right)
+        })
+    }
+}
 
+impl JoinExec {
+    fn execute_impl(
+        &self,
+        state: &ExecutionState,
+        df_left: DataFrame,
+        df_right: DataFrame,
+    ) -> Result<DataFrame> {
         let left_names = self
             .left_on
             .iter()
@@ -87,41 +117,82 @@ impl Executor for JoinExec {
             .map(|e| e.evaluate(&df_right, state).map(|s| s.name().to_string()))
             .collect::<Result<Vec<_>>>()?;
 
+        #[cfg(feature = "range_join")]
+        if !self.range_predicates.is_empty() {
+            let df = df_left.join_where(
+                &df_right,
+                &left_names,
+                &right_names,
+                &self.range_predicates,
+                self.suffix.clone(),
+            );
+            if state.verbose {
+                eprintln!("range join dataframes finished");
+            };
+            return df;
+        }
+
+        // The build side of an inner hash join is chosen on the actual sizes of the inputs,
+        // which are known at this point and more accurate than the estimates of the
+        // optimizer: the smaller table is used to build the hash table and the larger one
+        // probes it. The side is decided in polars-core, so that eager joins benefit too.
+        if state.verbose && self.how == JoinType::Inner {
+            let build_side = if df_left.height() > df_right.height() {
+                "right"
+            } else {
+                "left"
+            };
+            eprintln!(
+                "inner join: building the hash table on the {} table ({} vs {} rows)",
+                build_side,
+                df_left.height(),
+                df_right.height()
+            );
+        }
+
         #[cfg(feature = "asof_join")]
-        let df = if let (JoinType::AsOf, true, true) = (
-            self.how,
-            !self.asof_by_right.is_empty(),
-            !self.asof_by_left.is_empty(),
-        ) {
+        let df = if let JoinType::AsOf = self.how {
             if left_names.len() > 1 || right_names.len() > 1 {
                 return Err(PolarsError::ValueError(
                     "only one column allowed in asof join".into(),
                 ));
             }
-            df_left.join_asof_by(
-                &df_right,
-                &left_names[0],
-                &right_names[0],
-                &self.asof_by_left,
-                &self.asof_by_right,
-            )
+            if !self.asof_by_left.is_empty() && !self.asof_by_right.is_empty() {
+                df_left.join_asof_by(
+                    &df_right,
+                    &left_names[0],
+                    &right_names[0],
+                    &self.asof_by_left,
+                    &self.asof_by_right,
+                    &self.asof_options,
+                )
+            } else {
+                df_left.join_asof(
+                    &df_right,
+                    &left_names[0],
+                    &right_names[0],
+                    &self.asof_options,
+                )
+            }
         } else {
-            df_left.join(
+            df_left.join_with_options(
                 &df_right,
                 &left_names,
                 &right_names,
                 self.how,
                 self.suffix.clone(),
+                &self.key_options,
             )
         };
 
         #[cfg(not(feature = "asof_join"))]
-        let df = df_left.join(
+        let df = df_left.join_with_options(
             &df_right,
             &left_names,
             &right_names,
             self.how,
             self.suffix.clone(),
+            &self.key_options,
         );
 
         if state.verbose {
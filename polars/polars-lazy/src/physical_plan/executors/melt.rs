@@ -4,13 +4,12 @@ use polars_core::prelude::*;
 
 pub struct MeltExec {
     pub input: Box<dyn Executor>,
-    pub id_vars: Arc<Vec<String>>,
-    pub value_vars: Arc<Vec<String>>,
+    pub args: Arc<MeltArgs>,
 }
 
 impl Executor for MeltExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
-        df.melt(&self.id_vars.as_slice(), &self.value_vars.as_slice())
+        state.record("melt", df, |df| df.melt_with_args((*self.args).clone()))
     }
 }
@@ -30,6 +30,52 @@ fn set_n_rows(n_rows: Option<usize>) -> Option<usize> {
     }
 }
 
+/// Execute the inputs of a node. If the inputs are `independent`, they are executed concurrently
+/// on the thread pool, as far as the limit of parallel branches of the `state` allows.
+pub(crate) fn execute_branches(
+    inputs: &mut [Box<dyn Executor>],
+    independent: bool,
+    state: &ExecutionState,
+) -> Result<Vec<DataFrame>> {
+    let extra = if independent && inputs.len() > 1 {
+        state.acquire_branches(inputs.len() - 1)
+    } else {
+        0
+    };
+    if extra == 0 {
+        return inputs
+            .iter_mut()
+            .map(|input| input.execute(state))
+            .collect();
+    }
+
+    // propagate the fetch_rows static value to the spawning threads.
+    let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
+    let mut out = Vec::with_capacity(inputs.len());
+    let mut result = Ok(());
+    // execute the inputs in waves, so that no more than `extra + 1` run at the same time
+    for chunk in inputs.chunks_mut(extra + 1) {
+        let dfs = POOL.install(|| {
+            chunk
+                .par_iter_mut()
+                .map(|input| {
+                    FETCH_ROWS.with(|fr| fr.set(fetch_rows));
+                    input.execute(state)
+                })
+                .collect::<Result<Vec<_>>>()
+        });
+        match dfs {
+            Ok(dfs) => out.extend(dfs),
+            Err(e) => {
+                result = Err(e);
+                break;
+            }
+        }
+    }
+    state.release_branches(extra);
+    result.map(|_| out)
+}
+
 pub(crate) fn evaluate_physical_expressions(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
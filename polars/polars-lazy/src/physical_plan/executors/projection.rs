@@ -16,7 +16,9 @@ impl Executor for ProjectionExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
 
-        let df = evaluate_physical_expressions(&df, &self.expr, state);
+        let df = state.record("projection", df, |df| {
+            evaluate_physical_expressions(&df, &self.expr, state)
+        });
 
         #[cfg(test)]
         {
@@ -15,13 +15,22 @@ use std::path::Path;
 fn cache_hit(
     path: &Path,
     predicate: &Option<Arc<dyn PhysicalExpr>>,
+    n_rows: Option<usize>,
+    reservoir_sample: Option<(usize, u64)>,
     state: &ExecutionState,
 ) -> (String, Option<DataFrame>) {
     let path_str = try_path_to_str(path).unwrap();
-    let cache_key = match predicate {
+    let mut cache_key = match predicate {
         Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
         None => path_str.to_string(),
     };
+    // scans of the same file may read a different number of rows
+    if let Some(n_rows) = n_rows {
+        cache_key.push_str(&format!("n_rows:{}", n_rows));
+    }
+    if let Some((n, seed)) = reservoir_sample {
+        cache_key.push_str(&format!("sample:{}:{}", n, seed));
+    }
     let cached = state.cache_hit(&cache_key);
     (cache_key, cached)
 }
@@ -79,12 +88,8 @@ pub struct IpcExec {
 }
 
 #[cfg(feature = "ipc")]
-impl Executor for IpcExec {
-    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let (cache_key, cached) = cache_hit(&self.path, &self.predicate, state);
-        if let Some(df) = cached {
-            return Ok(df);
-        }
+impl IpcExec {
+    fn read(&mut self) -> Result<DataFrame> {
         let (file, projection, n_rows, aggregate, predicate) = prepare_scan_args(
             &self.path,
             &self.predicate,
@@ -93,14 +98,30 @@ impl Executor for IpcExec {
             self.options.n_rows,
             &self.aggregate,
         );
-        let df = IpcReader::new(file)
+        IpcReader::new(file)
             .with_n_rows(n_rows)
             .finish_with_scan_ops(
                 predicate,
                 aggregate,
                 projection.as_ref().map(|v| v.as_ref()),
-            )?;
+            )
+    }
+}
 
+#[cfg(feature = "ipc")]
+impl Executor for IpcExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let (cache_key, cached) = cache_hit(
+            &self.path,
+            &self.predicate,
+            self.options.n_rows,
+            None,
+            state,
+        );
+        if let Some(df) = cached {
+            return Ok(df);
+        }
+        let df = state.record("ipc_scan", (), |_| self.read())?;
         if self.options.cache {
             state.store_cache(cache_key, df.clone())
         }
@@ -138,15 +159,8 @@ impl ParquetExec {
             options,
         }
     }
-}
 
-#[cfg(feature = "parquet")]
-impl Executor for ParquetExec {
-    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let (cache_key, cached) = cache_hit(&self.path, &self.predicate, state);
-        if let Some(df) = cached {
-            return Ok(df);
-        }
+    fn read(&mut self) -> Result<DataFrame> {
         let (file, projection, n_rows, aggregate, predicate) = prepare_scan_args(
             &self.path,
             &self.predicate,
@@ -156,15 +170,31 @@ impl Executor for ParquetExec {
             &self.aggregate,
         );
 
-        let df = ParquetReader::new(file)
+        ParquetReader::new(file)
             .with_n_rows(n_rows)
             .read_parallel(self.options.parallel)
             .finish_with_scan_ops(
                 predicate,
                 aggregate,
                 projection.as_ref().map(|v| v.as_ref()),
-            )?;
+            )
+    }
+}
 
+#[cfg(feature = "parquet")]
+impl Executor for ParquetExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let (cache_key, cached) = cache_hit(
+            &self.path,
+            &self.predicate,
+            self.options.n_rows,
+            None,
+            state,
+        );
+        if let Some(df) = cached {
+            return Ok(df);
+        }
+        let df = state.record("parquet_scan", (), |_| self.read())?;
         if self.options.cache {
             state.store_cache(cache_key, df.clone())
         }
@@ -186,15 +216,8 @@ pub struct CsvExec {
 }
 
 #[cfg(feature = "csv-file")]
-impl Executor for CsvExec {
-    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let (cache_key, cached) = cache_hit(&self.path, &self.predicate, state);
-        if let Some(df) = cached {
-            return Ok(df);
-        }
-
-        // cache miss
-
+impl CsvExec {
+    fn read(&mut self) -> Result<DataFrame> {
         let mut with_columns = mem::take(&mut self.options.with_columns);
         let mut projected_len = 0;
         with_columns.as_ref().map(|columns| {
@@ -217,7 +240,7 @@ impl Executor for CsvExec {
             Some(self.aggregate.as_slice())
         };
 
-        let df = CsvReader::from_path(&self.path)
+        let reader = CsvReader::from_path(&self.path)
             .unwrap()
             .has_header(self.options.has_header)
             .with_schema(&self.schema)
@@ -232,9 +255,31 @@ impl Executor for CsvExec {
             .with_aggregate(aggregate)
             .with_encoding(CsvEncoding::LossyUtf8)
             .with_comment_char(self.options.comment_char)
-            .with_quote_char(self.options.quote_char)
-            .finish()?;
+            .with_quote_char(self.options.quote_char);
+        #[cfg(feature = "random")]
+        let reader = match self.options.reservoir_sample {
+            Some((n, seed)) => reader.with_reservoir_sample(Some(n), seed),
+            None => reader,
+        };
+        reader.finish()
+    }
+}
+
+#[cfg(feature = "csv-file")]
+impl Executor for CsvExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let (cache_key, cached) = cache_hit(
+            &self.path,
+            &self.predicate,
+            self.options.n_rows,
+            self.options.reservoir_sample,
+            state,
+        );
+        if let Some(df) = cached {
+            return Ok(df);
+        }
 
+        let df = state.record("csv_scan", (), |_| self.read())?;
         if self.options.cache {
             state.store_cache(cache_key, df.clone());
         }
@@ -269,6 +314,12 @@ impl DataFrameExec {
 
 impl Executor for DataFrameExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.record("df_scan", (), |_| self.read(state))
+    }
+}
+
+impl DataFrameExec {
+    fn read(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = mem::take(&mut self.df);
         let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
 
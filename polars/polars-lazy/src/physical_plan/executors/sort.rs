@@ -6,18 +6,32 @@ pub(crate) struct SortExec {
     pub(crate) input: Box<dyn Executor>,
     pub(crate) by_column: Vec<Arc<dyn PhysicalExpr>>,
     pub(crate) reverse: Vec<bool>,
+    /// Only the rows `offset..offset + len` of the sorted output are needed.
+    pub(crate) slice: Option<(usize, usize)>,
 }
 
 impl Executor for SortExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let mut df = self.input.execute(state)?;
+        let df = self.input.execute(state)?;
+        let name = if self.slice.is_some() {
+            "sort_slice"
+        } else {
+            "sort"
+        };
+        state.record(name, df, |df| self.execute_impl(state, df))
+    }
+}
 
+impl SortExec {
+    fn execute_impl(&mut self, state: &ExecutionState, mut df: DataFrame) -> Result<DataFrame> {
         let by_columns = self
             .by_column
             .iter()
             .map(|e| e.evaluate(&df, state))
             .collect::<Result<Vec<_>>>()?;
         let mut column_names = Vec::with_capacity(by_columns.len());
+        // the columns that are only added to sort by
+        let mut added_columns = vec![];
         // replace the columns in the DataFrame with the expressions
         // for col("foo") this is redundant
         // for col("foo").reverse() this is not
@@ -27,10 +41,20 @@ impl Executor for SortExec {
             // if error, expression create a new named column and we must add it to the DataFrame
             // if ok, we have replaced the column with the expression eval
             if df.apply(name, |_| column.clone()).is_err() {
-                df.hstack(&[column])?;
+                added_columns.push(name.to_string());
+                df.hstack_mut(&[column])?;
             }
         }
 
-        df.sort(&column_names, std::mem::take(&mut self.reverse))
+        let reverse = std::mem::take(&mut self.reverse);
+        let mut df = match self.slice {
+            Some((offset, len)) => df.sort_slice(&column_names, reverse, offset, len)?,
+            None => df.sort(&column_names, reverse)?,
+        };
+        // the output has the schema of the input
+        for name in &added_columns {
+            df.drop_in_place(name)?;
+        }
+        Ok(df)
     }
 }
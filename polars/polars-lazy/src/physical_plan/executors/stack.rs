@@ -16,7 +16,13 @@ impl StackExec {
 
 impl Executor for StackExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let mut df = self.input.execute(state)?;
+        let df = self.input.execute(state)?;
+        state.record("with_columns", df, |df| self.execute_impl(state, df))
+    }
+}
+
+impl StackExec {
+    fn execute_impl(&self, state: &ExecutionState, mut df: DataFrame) -> Result<DataFrame> {
         let height = df.height();
 
         let res = POOL.install(|| {
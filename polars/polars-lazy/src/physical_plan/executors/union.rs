@@ -1,3 +1,4 @@
+use super::execute_branches;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::prelude::*;
@@ -5,15 +6,13 @@ use polars_core::utils::concat_df;
 
 pub(crate) struct UnionExec {
     pub(crate) inputs: Vec<Box<dyn Executor>>,
+    /// The inputs don't share sources or caches and can be executed concurrently
+    pub(crate) parallel: bool,
 }
 
 impl Executor for UnionExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let dfs = self
-            .inputs
-            .iter_mut()
-            .map(|input| input.execute(state))
-            .collect::<Result<Vec<_>>>()?;
-        concat_df(&dfs)
+        let dfs = execute_branches(&mut self.inputs, self.parallel, state)?;
+        state.record("union", dfs, |dfs| concat_df(&dfs))
     }
 }
@@ -2,6 +2,8 @@ use crate::physical_plan::state::ExecutionState;
 use crate::physical_plan::PhysicalAggregation;
 use crate::prelude::*;
 use polars_arrow::arrow::{array::*, compute::concatenate::concatenate};
+#[cfg(feature = "approx")]
+use polars_core::frame::groupby::approx;
 use polars_core::frame::groupby::{fmt_groupby_column, GroupByMethod, GroupTuples};
 use polars_core::utils::NoNull;
 use polars_core::{prelude::*, POOL};
@@ -43,7 +45,14 @@ impl PhysicalExpr for AggregationExpr {
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
         let field = self.expr.to_field(input_schema)?;
         let new_name = fmt_groupby_column(field.name(), self.agg_type);
-        Ok(Field::new(&new_name, field.data_type().clone()))
+        let data_type = match self.agg_type {
+            #[cfg(feature = "approx")]
+            GroupByMethod::ApproxNUnique => DataType::UInt32,
+            #[cfg(feature = "approx")]
+            GroupByMethod::ApproxQuantile(_) => DataType::Float64,
+            _ => field.data_type().clone(),
+        };
+        Ok(Field::new(&new_name, data_type))
     }
 
     fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
@@ -142,6 +151,18 @@ impl PhysicalAggregation for AggregationExpr {
                 // implemented explicitly in AggQuantile struct
                 unimplemented!()
             }
+            #[cfg(feature = "approx")]
+            GroupByMethod::ApproxNUnique => {
+                let mut agg = approx::agg_approx_n_unique(&ac.flat(), ac.groups())?;
+                agg.rename(&new_name);
+                Ok(Some(agg.into_series()))
+            }
+            #[cfg(feature = "approx")]
+            GroupByMethod::ApproxQuantile(quantile) => {
+                let mut agg = approx::agg_approx_quantile(&ac.flat(), ac.groups(), quantile)?;
+                agg.rename(&new_name);
+                Ok(Some(agg.into_series()))
+            }
         }
     }
 
@@ -181,6 +202,23 @@ impl PhysicalAggregation for AggregationExpr {
                     vec![s]
                 }))
             }
+            // the partitions produce sketches that are merged in the final aggregation
+            #[cfg(feature = "approx")]
+            GroupByMethod::ApproxNUnique => {
+                let series = self.expr.evaluate(df, state)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut states = approx::agg_approx_n_unique_state(&series, groups)?;
+                states.rename(&new_name);
+                Ok(Some(vec![states.into_series()]))
+            }
+            #[cfg(feature = "approx")]
+            GroupByMethod::ApproxQuantile(_) => {
+                let series = self.expr.evaluate(df, state)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut states = approx::agg_approx_quantile_state(&series, groups)?;
+                states.rename(&new_name);
+                Ok(Some(vec![states.into_series()]))
+            }
             _ => PhysicalAggregation::aggregate(self, df, groups, state)
                 .map(|opt| opt.map(|s| vec![s])),
         }
@@ -249,6 +287,22 @@ impl PhysicalAggregation for AggregationExpr {
                 }
                 Ok(Some(ca.into_series()))
             }
+            #[cfg(feature = "approx")]
+            GroupByMethod::ApproxNUnique => {
+                let series = self.expr.evaluate(final_df, state)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut agg = approx::agg_approx_n_unique_merge(&series, groups)?;
+                agg.rename(&new_name);
+                Ok(Some(agg.into_series()))
+            }
+            #[cfg(feature = "approx")]
+            GroupByMethod::ApproxQuantile(quantile) => {
+                let series = self.expr.evaluate(final_df, state)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut agg = approx::agg_approx_quantile_merge(&series, groups, quantile)?;
+                agg.rename(&new_name);
+                Ok(Some(agg.into_series()))
+            }
             _ => PhysicalAggregation::aggregate(self, final_df, groups, state),
         }
     }
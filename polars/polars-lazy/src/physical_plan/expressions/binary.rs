@@ -53,6 +53,8 @@ pub(crate) fn apply_operator(left: &Series, right: &Series, op: Operator) -> Res
         Operator::And => left.bitand(right),
         Operator::Or => left.bitor(right),
         Operator::Xor => left.bitxor(right),
+        Operator::ShiftLeft => left.shift_left(right),
+        Operator::ShiftRight => left.shift_right(right),
         Operator::Modulus => Ok(left % right),
     }
 }
@@ -12,12 +12,18 @@ impl NotExpr {
     }
 
     fn finish(&self, series: Series) -> Result<Series> {
-        if let Ok(ca) = series.bool() {
-            Ok((!ca).into_series())
-        } else {
-            Err(PolarsError::InvalidOperation(
-                format!("NotExpr expected a boolean type, got: {:?}", series).into(),
-            ))
+        use DataType::*;
+        match series.dtype() {
+            Boolean => Ok((!series.bool().unwrap()).into_series()),
+            // bitwise not
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 => series.bit_not(),
+            _ => Err(PolarsError::InvalidOperation(
+                format!(
+                    "NotExpr expected a boolean or integer type, got: {:?}",
+                    series
+                )
+                .into(),
+            )),
         }
     }
 }
@@ -44,7 +50,8 @@ impl PhysicalExpr for NotExpr {
         Ok(ac)
     }
 
-    fn to_field(&self, _input_schema: &Schema) -> Result<Field> {
-        Ok(Field::new("not", DataType::Boolean))
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        let field = self.0.to_field(input_schema)?;
+        Ok(Field::new("not", field.data_type().clone()))
     }
 }
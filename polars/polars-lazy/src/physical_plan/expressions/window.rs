@@ -16,6 +16,61 @@ pub struct WindowExpr {
     pub(crate) function: Expr,
     pub(crate) phys_function: Arc<dyn PhysicalExpr>,
     pub(crate) options: WindowOptions,
+    /// Input and strategy of a forward/backward fill, these are evaluated per group
+    /// on the original rows.
+    pub(crate) fill: Option<(Arc<dyn PhysicalExpr>, FillNullStrategy)>,
+}
+
+impl WindowExpr {
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_fill(
+        &self,
+        input: &dyn PhysicalExpr,
+        strategy: FillNullStrategy,
+        df: &DataFrame,
+        groups: &GroupTuples,
+        state: &ExecutionState,
+    ) -> Result<Series> {
+        let s = input.evaluate(df, state)?;
+        let (backward, limit) = match strategy {
+            FillNullStrategy::Forward(limit) => (false, limit),
+            FillNullStrategy::Backward(limit) => (true, limit),
+            _ => unreachable!(),
+        };
+        let limit = limit.unwrap_or(usize::MAX);
+        let valid: Vec<bool> = s.is_not_null().into_no_null_iter().collect();
+
+        // for every row the index of the value it is filled with
+        let mut take_idx = vec![None; s.len()];
+        let mut fill_group = |idx: &mut dyn Iterator<Item = usize>| {
+            let mut previous = None;
+            let mut count = 0;
+            for i in idx {
+                if valid[i] {
+                    previous = Some(i);
+                    count = 0;
+                    take_idx[i] = Some(i);
+                } else if count < limit {
+                    count += 1;
+                    take_idx[i] = previous;
+                }
+            }
+        };
+        for (_, idx) in groups {
+            let mut iter = idx.iter().map(|i| *i as usize);
+            if backward {
+                fill_group(&mut iter.rev())
+            } else {
+                fill_group(&mut iter)
+            }
+        }
+
+        let mut out = unsafe { s.take_opt_iter_unchecked(&mut take_idx.into_iter()) };
+        if let Some(name) = &self.out_name {
+            out.rename(name.as_ref());
+        }
+        Ok(out)
+    }
 }
 
 impl PhysicalExpr for WindowExpr {
@@ -40,6 +95,10 @@ impl PhysicalExpr for WindowExpr {
         let mut gb = df.groupby_with_series(groupby_columns.clone(), true)?;
         let mut groups = std::mem::take(gb.get_groups_mut());
 
+        if let Some((input, strategy)) = &self.fill {
+            return self.evaluate_fill(input.as_ref(), *strategy, df, &groups, state);
+        }
+
         // if we flatten this column we need to make sure the groups are sorted.
         if self.options.explode {
             groups.sort_unstable_by_key(|t| t.0);
@@ -1,5 +1,6 @@
 pub mod executors;
 pub mod expressions;
+pub(crate) mod node_timer;
 pub mod planner;
 pub(crate) mod state;
 
@@ -0,0 +1,117 @@
+use polars_core::export::arrow::compute::aggregate::estimated_bytes_size;
+use polars_core::prelude::*;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The output of the inputs of an executor node.
+pub(crate) trait NodeInput {
+    fn frames(&self) -> Vec<&DataFrame>;
+}
+
+/// A node without inputs, e.g. a scan.
+impl NodeInput for () {
+    fn frames(&self) -> Vec<&DataFrame> {
+        vec![]
+    }
+}
+
+impl NodeInput for DataFrame {
+    fn frames(&self) -> Vec<&DataFrame> {
+        vec![self]
+    }
+}
+
+impl NodeInput for (DataFrame, DataFrame) {
+    fn frames(&self) -> Vec<&DataFrame> {
+        vec![&self.0, &self.1]
+    }
+}
+
+impl NodeInput for Vec<DataFrame> {
+    fn frames(&self) -> Vec<&DataFrame> {
+        self.iter().collect()
+    }
+}
+
+fn estimated_size(df: &DataFrame) -> usize {
+    df.get_columns()
+        .iter()
+        .flat_map(|s| s.chunks())
+        .map(|arr| estimated_bytes_size(arr.as_ref()))
+        .sum()
+}
+
+struct NodeRecord {
+    name: String,
+    start: Duration,
+    end: Duration,
+    rows_in: usize,
+    rows_out: usize,
+    peak_bytes: usize,
+}
+
+/// Records the timings and sizes of the executor nodes of a profiled query.
+/// Clones share their records, so that nodes that run on other threads are recorded as well.
+#[derive(Clone)]
+pub(crate) struct NodeTimer {
+    query_start: Instant,
+    records: Arc<Mutex<Vec<NodeRecord>>>,
+}
+
+impl NodeTimer {
+    pub(crate) fn new() -> Self {
+        Self {
+            query_start: Instant::now(),
+            records: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    /// Run `func` on the output of the inputs of a node and record it.
+    pub(crate) fn record<I, F>(&self, name: &str, input: I, func: F) -> Result<DataFrame>
+    where
+        I: NodeInput,
+        F: FnOnce(I) -> Result<DataFrame>,
+    {
+        let frames = input.frames();
+        let rows_in = frames.iter().map(|df| df.height()).sum();
+        let bytes_in: usize = frames.iter().map(|df| estimated_size(df)).sum();
+
+        let start = self.query_start.elapsed();
+        let out = func(input)?;
+        let end = self.query_start.elapsed();
+
+        // the inputs are still alive when the output is created
+        let peak_bytes = bytes_in + estimated_size(&out);
+        let mut records = self.records.lock().unwrap();
+        records.push(NodeRecord {
+            name: name.to_string(),
+            start,
+            end,
+            rows_in,
+            rows_out: out.height(),
+            peak_bytes,
+        });
+        Ok(out)
+    }
+
+    /// Create a `DataFrame` with a row per executed node, ordered by start time.
+    /// The times are in microseconds since the start of the query.
+    pub(crate) fn finish(self) -> Result<DataFrame> {
+        let records = self.records.lock().unwrap();
+        let column = |name: &str, f: fn(&NodeRecord) -> u64| {
+            Series::new(name, records.iter().map(f).collect::<Vec<_>>())
+        };
+        let columns = vec![
+            Series::new(
+                "node",
+                records.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(),
+            ),
+            column("start", |r| r.start.as_micros() as u64),
+            column("end", |r| r.end.as_micros() as u64),
+            column("rows_in", |r| r.rows_in as u64),
+            column("rows_out", |r| r.rows_out as u64),
+            column("peak_bytes", |r| r.peak_bytes as u64),
+        ];
+        DataFrame::new(columns)?.sort("start", false)
+    }
+}
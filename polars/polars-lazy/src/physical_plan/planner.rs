@@ -9,7 +9,7 @@ use crate::prelude::*;
 use crate::utils::expr_to_root_column_name;
 use crate::{
     logical_plan::iterator::ArenaExprIter,
-    utils::{aexpr_to_root_names, aexpr_to_root_nodes, agg_source_paths, has_aexpr},
+    utils::{aexpr_to_root_names, aexpr_to_root_nodes, agg_cache_ids, agg_source_paths, has_aexpr},
 };
 use ahash::RandomState;
 use polars_core::prelude::*;
@@ -19,6 +19,25 @@ use polars_io::ScanAggregation;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+/// Check if the plans below `inputs` read from separate sources and don't share a cache.
+/// Otherwise an input would have to wait for another input to fill the cache, so the inputs
+/// can't be executed concurrently.
+fn independent_inputs(inputs: &[Node], lp_arena: &Arena<ALogicalPlan>) -> bool {
+    let mut sources = HashSet::with_capacity_and_hasher(16, RandomState::default());
+    let mut caches = HashSet::with_capacity_and_hasher(4, RandomState::default());
+    inputs.iter().all(|&input| {
+        let mut input_sources = HashSet::with_capacity_and_hasher(16, RandomState::default());
+        agg_source_paths(input, &mut input_sources, lp_arena);
+        let mut input_caches = HashSet::with_capacity_and_hasher(4, RandomState::default());
+        agg_cache_ids(input, &mut input_caches, lp_arena);
+
+        let independent = sources.is_disjoint(&input_sources) && caches.is_disjoint(&input_caches);
+        sources.extend(input_sources);
+        caches.extend(input_caches);
+        independent
+    })
+}
+
 #[cfg(any(feature = "parquet", feature = "csv-file"))]
 fn aggregate_expr_to_scan_agg(
     aggregate: Vec<Node>,
@@ -54,6 +73,20 @@ fn aggregate_expr_to_scan_agg(
                         column: (*aexpr_to_root_names(*e, expr_arena).pop().unwrap()).to_string(),
                         alias,
                     },
+                    #[cfg(feature = "approx")]
+                    AAggExpr::ApproxNUnique(e) => ScanAggregation::ApproxNUnique {
+                        column: (*aexpr_to_root_names(*e, expr_arena).pop().unwrap()).to_string(),
+                        alias,
+                    },
+                    #[cfg(feature = "approx")]
+                    AAggExpr::ApproxQuantile { expr: e, quantile } => {
+                        ScanAggregation::ApproxQuantile {
+                            column: (*aexpr_to_root_names(*e, expr_arena).pop().unwrap())
+                                .to_string(),
+                            quantile: *quantile,
+                            alias,
+                        }
+                    }
                     _ => todo!(),
                 }
             } else {
@@ -64,7 +97,11 @@ fn aggregate_expr_to_scan_agg(
 }
 
 #[derive(Default)]
-pub struct DefaultPlanner {}
+pub struct DefaultPlanner {
+    /// Execute a sort that is followed by a slice as a top-k sort that only determines the
+    /// rows of the slice. Part of the slice pushdown optimization.
+    pub(crate) top_k_sort: bool,
+}
 
 impl PhysicalPlanner for DefaultPlanner {
     fn create_physical_plan(
@@ -99,24 +136,40 @@ impl DefaultPlanner {
         let logical_plan = lp_arena.take(root);
         match logical_plan {
             Union { inputs } => {
+                let parallel = independent_inputs(&inputs, lp_arena);
                 let inputs = inputs
                     .into_iter()
                     .map(|node| self.create_physical_plan(node, lp_arena, expr_arena))
                     .collect::<Result<Vec<_>>>()?;
-                Ok(Box::new(UnionExec { inputs }))
+                Ok(Box::new(UnionExec { inputs, parallel }))
             }
-            Melt {
-                input,
-                id_vars,
-                value_vars,
-                ..
-            } => {
+            Melt { input, args, .. } => {
+                check_melt_args(&args, lp_arena.get(input).schema(lp_arena))?;
                 let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
-                Ok(Box::new(MeltExec {
+                Ok(Box::new(MeltExec { input, args }))
+            }
+            // a sort followed by a slice only determines the rows of the slice
+            Slice { input, offset, len }
+                if self.top_k_sort && offset >= 0 && matches!(lp_arena.get(input), Sort { .. }) =>
+            {
+                if let Sort {
                     input,
-                    id_vars,
-                    value_vars,
-                }))
+                    by_column,
+                    reverse,
+                } = lp_arena.take(input)
+                {
+                    let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                    let by_column =
+                        self.create_physical_expressions(&by_column, Context::Default, expr_arena)?;
+                    Ok(Box::new(SortExec {
+                        input,
+                        by_column,
+                        reverse,
+                        slice: Some((offset as usize, len)),
+                    }))
+                } else {
+                    unreachable!()
+                }
             }
             Slice { input, offset, len } => {
                 let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
@@ -249,22 +302,27 @@ impl DefaultPlanner {
                     input,
                     by_column,
                     reverse,
+                    slice: None,
                 }))
             }
             Explode { input, columns } => {
                 let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
                 Ok(Box::new(ExplodeExec { input, columns }))
             }
-            Cache { input } => {
+            Cache { input, id } => {
                 let fields = lp_arena.get(input).schema(lp_arena).fields();
                 // todo! fix the unique constraint in the schema. Probably in projection pushdown at joins
                 let mut unique =
                     HashSet::with_capacity_and_hasher(fields.len(), RandomState::default());
                 // assumption of 80 characters per column name
                 let mut key = String::with_capacity(fields.len() * 80);
+                // the id separates caches of different inputs that have the same column names.
+                // The id and names are delimited and the names are prefixed by their length,
+                // so that different (id, columns) pairs can't produce the same key.
+                key.push_str(&format!("{}|", id));
                 for field in fields {
                     if unique.insert(field.name()) {
-                        key.push_str(field.name())
+                        key.push_str(&format!("{}:{}", field.name().len(), field.name()))
                     }
                 }
                 let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
@@ -370,6 +428,9 @@ impl DefaultPlanner {
                             | Expr::Agg(AggExpr::Last(_))
                             | Expr::Agg(AggExpr::List(_))
                             | Expr::Agg(AggExpr::First(_)) => {}
+                            #[cfg(feature = "approx")]
+                            Expr::Agg(AggExpr::ApproxNUnique(_))
+                            | Expr::Agg(AggExpr::ApproxQuantile { .. }) => {}
                             _ => {
                                 partitionable = false;
                                 break
@@ -409,16 +470,7 @@ impl DefaultPlanner {
                 let parallel = if options.force_parallel {
                     true
                 } else if options.allow_parallel {
-                    // check if two DataFrames come from a separate source.
-                    // If they don't we can parallelize,
-                    // Otherwise it is in cache.
-                    let mut sources_left =
-                        HashSet::with_capacity_and_hasher(16, RandomState::default());
-                    agg_source_paths(input_left, &mut sources_left, lp_arena);
-                    let mut sources_right =
-                        HashSet::with_capacity_and_hasher(16, RandomState::default());
-                    agg_source_paths(input_right, &mut sources_right, lp_arena);
-                    sources_left.intersection(&sources_right).next().is_none()
+                    independent_inputs(&[input_left, input_right], lp_arena)
                 } else {
                     false
                 };
@@ -439,6 +491,11 @@ impl DefaultPlanner {
                     options.suffix,
                     options.asof_by_left,
                     options.asof_by_right,
+                    options.key_options,
+                    #[cfg(feature = "asof_join")]
+                    options.asof_options,
+                    #[cfg(feature = "range_join")]
+                    options.range_predicates,
                 )))
             }
             HStack { input, exprs, .. } => {
@@ -502,6 +559,26 @@ impl DefaultPlanner {
                     function = *expr;
                     out_name = Some(name.clone());
                 };
+                // directional fills are computed per group without aggregating to lists
+                let fill = match expr_arena.get(function) {
+                    Function {
+                        input,
+                        options:
+                            FunctionOptions {
+                                fill_strategy: Some(strategy),
+                                ..
+                            },
+                        ..
+                    } if !options.explode => Some((input[0], *strategy)),
+                    _ => None,
+                };
+                let fill = match fill {
+                    Some((input, strategy)) => Some((
+                        self.create_physical_expr(input, Context::Default, expr_arena)?,
+                        strategy,
+                    )),
+                    None => None,
+                };
                 let function = node_to_exp(function, expr_arena);
 
                 Ok(Arc::new(WindowExpr {
@@ -511,6 +588,7 @@ impl DefaultPlanner {
                     function,
                     phys_function,
                     options,
+                    fill,
                 }))
             }
             Literal(value) => Ok(Arc::new(LiteralExpr::new(
@@ -862,6 +940,58 @@ impl DefaultPlanner {
                             }
                         }
                     }
+                    #[cfg(feature = "approx")]
+                    AAggExpr::ApproxNUnique(expr) => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                                input,
+                                GroupByMethod::ApproxNUnique,
+                            ))),
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
+                                    let s = std::mem::take(&mut s[0]);
+                                    s.approx_n_unique().map(|count| {
+                                        UInt32Chunked::new_from_slice(s.name(), &[count as u32])
+                                            .into_series()
+                                    })
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    inputs: vec![input],
+                                    function,
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: ApplyOptions::ApplyFlat,
+                                }))
+                            }
+                        }
+                    }
+                    #[cfg(feature = "approx")]
+                    AAggExpr::ApproxQuantile { expr, quantile } => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                                input,
+                                GroupByMethod::ApproxQuantile(quantile),
+                            ))),
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
+                                    let s = std::mem::take(&mut s[0]);
+                                    s.approx_quantile(quantile).map(|opt_v| {
+                                        Float64Chunked::new_from_opt_slice(s.name(), &[opt_v])
+                                            .into_series()
+                                    })
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    inputs: vec![input],
+                                    function,
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: ApplyOptions::ApplyFlat,
+                                }))
+                            }
+                        }
+                    }
                     AAggExpr::AggGroups(expr) => {
                         if let Context::Default = ctxt {
                             panic!("agg groups expression only supported in aggregation context")
@@ -1,12 +1,53 @@
+use crate::physical_plan::node_timer::{NodeInput, NodeTimer};
 use ahash::RandomState;
 use polars_core::frame::groupby::GroupTuples;
 use polars_core::prelude::*;
+use polars_core::POOL;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 pub type JoinTuplesCache = Arc<Mutex<HashMap<String, Vec<(u32, Option<u32>)>, RandomState>>>;
 pub type GroupTuplesCache = Arc<Mutex<HashMap<String, GroupTuples, RandomState>>>;
 
+/// Number of plan branches that may still be started on the thread pool. The thread that
+/// executes the query is not counted.
+pub(crate) struct BranchSlots {
+    available: AtomicUsize,
+}
+
+impl BranchSlots {
+    fn new(max_parallel_branches: usize) -> Self {
+        Self {
+            available: AtomicUsize::new(max_parallel_branches.saturating_sub(1)),
+        }
+    }
+
+    /// Take up to `n` slots. Returns the number of slots taken.
+    fn acquire(&self, n: usize) -> usize {
+        let mut available = self.available.load(Ordering::Acquire);
+        loop {
+            let taken = std::cmp::min(n, available);
+            if taken == 0 {
+                return 0;
+            }
+            match self.available.compare_exchange_weak(
+                available,
+                available - taken,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return taken,
+                Err(current) => available = current,
+            }
+        }
+    }
+
+    fn release(&self, n: usize) {
+        self.available.fetch_add(n, Ordering::AcqRel);
+    }
+}
+
 /// State/ cache that is maintained during the Execution of the physical plan.
 #[derive(Clone)]
 pub struct ExecutionState {
@@ -16,6 +57,10 @@ pub struct ExecutionState {
     /// Used by Window Expression to prevent redundant joins
     pub(crate) join_tuples: JoinTuplesCache,
     pub(crate) verbose: bool,
+    /// Set if the query is profiled
+    pub(crate) node_timer: Option<NodeTimer>,
+    /// Limits the number of independent branches of the plan that are executed concurrently
+    branch_slots: Arc<BranchSlots>,
 }
 
 impl ExecutionState {
@@ -25,6 +70,38 @@ impl ExecutionState {
             group_tuples: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
             join_tuples: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
             verbose: std::env::var("POLARS_VERBOSE").is_ok(),
+            node_timer: None,
+            branch_slots: Arc::new(BranchSlots::new(POOL.current_num_threads())),
+        }
+    }
+
+    /// Execute at most `max_parallel_branches` independent branches of the plan concurrently.
+    pub(crate) fn with_max_parallel_branches(mut self, max_parallel_branches: usize) -> Self {
+        self.branch_slots = Arc::new(BranchSlots::new(max_parallel_branches));
+        self
+    }
+
+    /// Reserve up to `n` additional branches that may run concurrently.
+    /// Returns the number of branches that were reserved.
+    pub(crate) fn acquire_branches(&self, n: usize) -> usize {
+        self.branch_slots.acquire(n)
+    }
+
+    /// Give back branches reserved with [acquire_branches](ExecutionState::acquire_branches).
+    pub(crate) fn release_branches(&self, n: usize) {
+        self.branch_slots.release(n)
+    }
+
+    /// Compute the output of an executor node from the output of its inputs.
+    /// If the query is profiled, the timing and size of the node are recorded.
+    pub(crate) fn record<I, F>(&self, name: &str, input: I, func: F) -> Result<DataFrame>
+    where
+        I: NodeInput,
+        F: FnOnce(I) -> Result<DataFrame>,
+    {
+        match &self.node_timer {
+            Some(timer) => timer.record(name, input, func),
+            None => func(input),
         }
     }
 
@@ -10,6 +10,9 @@ pub use crate::{
     physical_plan::{expressions::*, planner::DefaultPlanner, Executor, PhysicalPlanner},
 };
 
+#[cfg(feature = "sql")]
+pub use crate::sql::SQLContext;
+
 #[cfg(feature = "csv-file")]
 pub(crate) use crate::physical_plan::executors::scan::CsvExec;
 #[cfg(feature = "parquet")]
@@ -0,0 +1,872 @@
+//! Query [`LazyFrame`]s with SQL.
+//!
+//! Frames are registered as tables in a [`SQLContext`]. A `SELECT` query is translated into a
+//! [`LazyFrame`], so that it is optimized like any other lazy query.
+//!
+//! The supported subset of SQL is:
+//!
+//! * `SELECT [DISTINCT]` with `*`, expressions and aliases
+//! * `FROM` a registered table, followed by `[INNER | LEFT [OUTER] | FULL [OUTER]] JOIN ... ON`
+//!   with equality conditions combined with `AND`, or `CROSS JOIN` (needs the `cross_join` feature)
+//! * `WHERE`, `GROUP BY` on columns, `HAVING`, `ORDER BY ... [ASC | DESC]`, `LIMIT` and `OFFSET`
+//! * arithmetic, comparisons, `AND`, `OR`, `NOT`, `IS [NOT] NULL`, `[NOT] IN (...)`,
+//!   `[NOT] BETWEEN`, `CASE` and `CAST`
+//! * the aggregations `COUNT(*)`, `COUNT([DISTINCT] expr)`, `SUM`, `MIN`, `MAX`, `AVG`, `MEDIAN`,
+//!   `FIRST`, `LAST`, `STDDEV` and `VARIANCE`
+//!
+//! Other constructs, like subqueries, set operations and window functions, return an error.
+mod parser;
+mod tokenizer;
+
+use crate::prelude::*;
+use parser::*;
+use polars_core::datatypes::{PlHashMap, PlHashSet};
+use polars_core::prelude::*;
+
+pub(crate) fn sql_error(msg: String) -> PolarsError {
+    PolarsError::ComputeError(format!("SQL: {}", msg).into())
+}
+
+/// Translate SQL queries over registered tables into [`LazyFrame`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_lazy::prelude::*;
+///
+/// fn total_per_fruit(sales: LazyFrame) -> Result<LazyFrame> {
+///     let mut ctx = SQLContext::new();
+///     ctx.register("sales", sales);
+///     ctx.execute(
+///         "SELECT fruit, SUM(amount) AS total FROM sales \
+///          WHERE amount > 0 GROUP BY fruit ORDER BY total DESC LIMIT 10",
+///     )
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct SQLContext {
+    tables: PlHashMap<String, LazyFrame>,
+}
+
+impl SQLContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a [`LazyFrame`] as a table. An existing table with the same name is replaced.
+    pub fn register(&mut self, name: &str, lf: LazyFrame) {
+        self.tables.insert(name.to_string(), lf);
+    }
+
+    /// Remove a table from the context.
+    pub fn unregister(&mut self, name: &str) -> Option<LazyFrame> {
+        self.tables.remove(name)
+    }
+
+    /// Get the names of the registered tables.
+    pub fn get_tables(&self) -> Vec<String> {
+        let mut tables = self.tables.keys().cloned().collect::<Vec<_>>();
+        tables.sort();
+        tables
+    }
+
+    /// Translate a `SELECT` query into a [`LazyFrame`].
+    pub fn execute(&self, query: &str) -> Result<LazyFrame> {
+        let query = parser::parse(query)?;
+        self.execute_query(&query)
+    }
+
+    fn table(&self, table: &TableRef) -> Result<LazyFrame> {
+        self.tables
+            .get(&table.name)
+            .cloned()
+            .ok_or_else(|| sql_error(format!("table '{}' is not registered", table.name)))
+    }
+
+    fn execute_query(&self, query: &Query) -> Result<LazyFrame> {
+        let mut scope = Scope::default();
+        let mut lf = self.table(&query.from)?;
+        let columns = lf
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| (f.name().clone(), f.name().clone()))
+            .collect();
+        scope.add_table(query.from.reference(), columns)?;
+        for join in &query.joins {
+            lf = self.join(lf, &mut scope, join)?;
+        }
+
+        let count_column = lf
+            .schema()
+            .fields()
+            .first()
+            .map(|f| f.name().clone())
+            .unwrap_or_default();
+
+        if let Some(selection) = &query.selection {
+            if contains_aggregate(selection) {
+                return Err(sql_error(
+                    "aggregate functions are not allowed in WHERE, use HAVING".into(),
+                ));
+            }
+            let predicate =
+                ExprTranslator::new(&scope, &count_column, None).translate(selection)?;
+            lf = lf.filter(predicate);
+        }
+
+        let has_wildcard = query
+            .projection
+            .iter()
+            .any(|item| matches!(item, SelectItem::Wildcard));
+        let is_aggregation = !query.group_by.is_empty()
+            || query.having.is_some()
+            || query.projection.iter().any(
+                |item| matches!(item, SelectItem::Expr { expr, .. } if contains_aggregate(expr)),
+            );
+        if is_aggregation && has_wildcard {
+            return Err(sql_error(
+                "SELECT * cannot be combined with GROUP BY or aggregate functions".into(),
+            ));
+        }
+
+        let aggregation = if is_aggregation {
+            let keys = query
+                .group_by
+                .iter()
+                .map(|expr| match expr {
+                    SqlExpr::Identifier(parts) => scope.resolve(parts),
+                    _ => Err(sql_error("only columns are supported in GROUP BY".into())),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Some(Aggregation { keys, aggs: vec![] })
+        } else {
+            None
+        };
+        let mut translator = ExprTranslator::new(&scope, &count_column, aggregation);
+
+        let mut projection = Vec::with_capacity(query.projection.len());
+        let mut default_names = PlHashSet::with_capacity(query.projection.len());
+        // generated names must not clash with the aliases or the columns of the tables
+        let aliases = query
+            .projection
+            .iter()
+            .filter_map(|item| match item {
+                SelectItem::Expr {
+                    alias: Some(alias), ..
+                } => Some(alias.as_str()),
+                _ => None,
+            })
+            .collect::<PlHashSet<_>>();
+        let input_schema = lf.schema();
+        for (i, item) in query.projection.iter().enumerate() {
+            match item {
+                SelectItem::Wildcard => projection.push(col("*")),
+                SelectItem::Expr { expr, alias } => {
+                    let mut e = translator.translate(expr)?;
+                    if let Some(alias) = alias {
+                        e = e.alias(alias);
+                    } else if is_aggregation {
+                        // otherwise the name of the aggregated column would be used
+                        let mut name = default_name(expr, &scope, i)?;
+                        if aliases.contains(name.as_str()) || default_names.contains(&name) {
+                            let mut suffix = i;
+                            name = loop {
+                                let candidate = format!("{}_{}", name, suffix);
+                                if !aliases.contains(candidate.as_str())
+                                    && !default_names.contains(&candidate)
+                                    && input_schema.index_of(&candidate).is_err()
+                                {
+                                    break candidate;
+                                }
+                                suffix += 1;
+                            };
+                        }
+                        default_names.insert(name.clone());
+                        e = e.alias(&name);
+                    }
+                    projection.push(e);
+                }
+            }
+        }
+
+        let having = match &query.having {
+            Some(having) => Some(translator.translate(having)?),
+            None => None,
+        };
+
+        let mut sort_by = Vec::with_capacity(query.order_by.len());
+        let mut reverse = Vec::with_capacity(query.order_by.len());
+        for (i, order) in query.order_by.iter().enumerate() {
+            let expr = order_by_target(&order.expr, &query.projection)?;
+            let e = translator.translate(expr)?;
+            // sorting on an expression adds it as a column, don't overwrite existing columns
+            let e = match e {
+                Expr::Column(_) => e,
+                e => e.alias(&format!("__sort_{}", i)),
+            };
+            sort_by.push(e);
+            reverse.push(order.descending);
+        }
+
+        if let Some(Aggregation { keys, mut aggs }) = translator.aggregation.take() {
+            if aggs.is_empty() {
+                aggs.push(col(&count_column).count().alias("__agg_count"));
+            }
+            lf = if keys.is_empty() {
+                lf.select(aggs)
+            } else {
+                let keys = keys.iter().map(|name| col(name)).collect::<Vec<_>>();
+                lf.groupby(keys).agg(aggs)
+            };
+            if let Some(having) = having {
+                lf = lf.filter(having);
+            }
+        }
+
+        if !sort_by.is_empty() {
+            lf = lf.sort_by_exprs(sort_by, reverse);
+        }
+        if !(has_wildcard && projection.len() == 1) {
+            lf = lf.select(projection);
+        }
+        if query.distinct {
+            lf = lf.drop_duplicates(true, None);
+        }
+        match (query.limit, query.offset) {
+            (Some(limit), offset) => lf = lf.slice(offset.unwrap_or(0) as i64, limit),
+            (None, Some(offset)) => lf = lf.slice(offset as i64, usize::MAX),
+            (None, None) => {}
+        }
+        Ok(lf)
+    }
+
+    fn join(&self, lf: LazyFrame, scope: &mut Scope, join: &Join) -> Result<LazyFrame> {
+        let right = self.table(&join.table)?;
+        let right_schema = right.schema();
+        let reference = join.table.reference();
+        let left_names = lf
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<PlHashSet<_>>();
+        let in_right = |name: &str| right_schema.fields().iter().any(|f| f.name() == name);
+
+        // the right join keys are removed, they are found in the left key columns
+        let mut key_names = PlHashMap::default();
+
+        let lf = match join.how {
+            SqlJoinType::Cross => {
+                #[cfg(feature = "cross_join")]
+                {
+                    lf.cross_join(right)
+                }
+                #[cfg(not(feature = "cross_join"))]
+                {
+                    return Err(sql_error(
+                        "CROSS JOIN needs the `cross_join` feature".into(),
+                    ));
+                }
+            }
+            how => {
+                let mut conditions = vec![];
+                flatten_and(join.on.as_ref().unwrap(), &mut conditions);
+
+                let belongs_to_right = |parts: &[String]| match parts {
+                    [table, _] => table == reference,
+                    [name] => {
+                        in_right(name.as_str()) && !matches!(scope.lookup(parts), Ok(Some(_)))
+                    }
+                    _ => false,
+                };
+
+                let mut left_on = Vec::with_capacity(conditions.len());
+                let mut right_on = Vec::with_capacity(conditions.len());
+                for condition in conditions {
+                    let (a, b) = match condition {
+                        SqlExpr::BinaryOp {
+                            left,
+                            op: SqlBinaryOp::Eq,
+                            right,
+                        } => match (&**left, &**right) {
+                            (SqlExpr::Identifier(a), SqlExpr::Identifier(b)) => (a, b),
+                            _ => return Err(unsupported_join_condition()),
+                        },
+                        _ => return Err(unsupported_join_condition()),
+                    };
+                    let (left_key, right_key) = match (belongs_to_right(a), belongs_to_right(b)) {
+                        (false, true) => (a, b),
+                        (true, false) => (b, a),
+                        _ => {
+                            return Err(sql_error(format!(
+                                "could not determine the tables of the join condition '{} = {}'",
+                                a.join("."),
+                                b.join(".")
+                            )))
+                        }
+                    };
+                    let left_name = scope.resolve(left_key)?;
+                    let right_name = right_key.last().unwrap();
+                    if !in_right(right_name.as_str()) {
+                        return Err(sql_error(format!(
+                            "column '{}' not found",
+                            right_key.join(".")
+                        )));
+                    }
+                    left_on.push(col(&left_name));
+                    right_on.push(col(right_name));
+                    key_names.insert(right_name.clone(), left_name);
+                }
+
+                let how = match how {
+                    SqlJoinType::Inner => JoinType::Inner,
+                    SqlJoinType::Left => JoinType::Left,
+                    _ => JoinType::Outer,
+                };
+                lf.join_builder()
+                    .with(right)
+                    .left_on(left_on)
+                    .right_on(right_on)
+                    .how(how)
+                    .finish()
+            }
+        };
+
+        let columns = right_schema
+            .fields()
+            .iter()
+            .map(|f| {
+                let name = f.name();
+                let output_name = match key_names.get(name) {
+                    Some(left_name) => left_name.clone(),
+                    None if left_names.contains(name) => format!("{}_right", name),
+                    None => name.clone(),
+                };
+                (name.clone(), output_name)
+            })
+            .collect();
+        scope.add_table(reference, columns)?;
+        Ok(lf)
+    }
+}
+
+fn unsupported_join_condition() -> PolarsError {
+    sql_error(
+        "only equality conditions between columns combined with AND are supported in JOIN ON"
+            .into(),
+    )
+}
+
+fn flatten_and<'a>(expr: &'a SqlExpr, out: &mut Vec<&'a SqlExpr>) {
+    match expr {
+        SqlExpr::BinaryOp {
+            left,
+            op: SqlBinaryOp::And,
+            right,
+        } => {
+            flatten_and(left, out);
+            flatten_and(right, out);
+        }
+        expr => out.push(expr),
+    }
+}
+
+/// Resolve the references to select aliases and positions in an ORDER BY expression.
+fn order_by_target<'a>(expr: &'a SqlExpr, projection: &'a [SelectItem]) -> Result<&'a SqlExpr> {
+    match expr {
+        SqlExpr::Identifier(parts) if parts.len() == 1 => Ok(projection
+            .iter()
+            .find_map(|item| match item {
+                SelectItem::Expr {
+                    expr,
+                    alias: Some(alias),
+                } if *alias == parts[0] => Some(expr),
+                _ => None,
+            })
+            .unwrap_or(expr)),
+        SqlExpr::Literal(SqlLiteral::Integer(position)) => {
+            match projection.get((*position as usize).wrapping_sub(1)) {
+                Some(SelectItem::Expr { expr, .. }) => Ok(expr),
+                _ => Err(sql_error(format!(
+                    "ORDER BY position {} does not refer to a selected expression",
+                    position
+                ))),
+            }
+        }
+        _ => Ok(expr),
+    }
+}
+
+/// The columns of the tables in the FROM clause, mapped to their name in the joined frame.
+#[derive(Default)]
+struct Scope {
+    tables: Vec<(String, PlHashMap<String, String>)>,
+}
+
+impl Scope {
+    fn add_table(&mut self, reference: &str, columns: PlHashMap<String, String>) -> Result<()> {
+        if self.tables.iter().any(|(name, _)| name == reference) {
+            return Err(sql_error(format!(
+                "table name '{}' is specified more than once, use an alias",
+                reference
+            )));
+        }
+        self.tables.push((reference.to_string(), columns));
+        Ok(())
+    }
+
+    fn lookup(&self, parts: &[String]) -> Result<Option<String>> {
+        match parts {
+            [name] => {
+                let mut found: Option<&String> = None;
+                for (_, columns) in &self.tables {
+                    if let Some(output_name) = columns.get(name) {
+                        match found {
+                            Some(prev) if prev != output_name => {
+                                return Err(sql_error(format!(
+                                    "column reference '{}' is ambiguous",
+                                    name
+                                )))
+                            }
+                            _ => found = Some(output_name),
+                        }
+                    }
+                }
+                Ok(found.cloned())
+            }
+            [table, name] => {
+                let columns = self
+                    .tables
+                    .iter()
+                    .find(|(reference, _)| reference == table)
+                    .map(|(_, columns)| columns)
+                    .ok_or_else(|| {
+                        sql_error(format!("table '{}' is not in the FROM clause", table))
+                    })?;
+                Ok(columns.get(name).cloned())
+            }
+            _ => Err(sql_error(format!(
+                "invalid column reference '{}'",
+                parts.join(".")
+            ))),
+        }
+    }
+
+    fn resolve(&self, parts: &[String]) -> Result<String> {
+        self.lookup(parts)?
+            .ok_or_else(|| sql_error(format!("column '{}' not found", parts.join("."))))
+    }
+}
+
+/// The state of an aggregation query.
+struct Aggregation {
+    /// Output names of the GROUP BY columns.
+    keys: Vec<String>,
+    /// The aggregations, each aliased to a temporary column.
+    aggs: Vec<Expr>,
+}
+
+struct ExprTranslator<'a> {
+    scope: &'a Scope,
+    /// A column of the input, used for `COUNT(*)`.
+    count_column: &'a str,
+    /// Set in an aggregation query. Aggregations are then replaced by their output column.
+    aggregation: Option<Aggregation>,
+}
+
+impl<'a> ExprTranslator<'a> {
+    fn new(scope: &'a Scope, count_column: &'a str, aggregation: Option<Aggregation>) -> Self {
+        ExprTranslator {
+            scope,
+            count_column,
+            aggregation,
+        }
+    }
+
+    fn translate(&mut self, expr: &SqlExpr) -> Result<Expr> {
+        match expr {
+            SqlExpr::Identifier(parts) => {
+                let name = self.scope.resolve(parts)?;
+                if let Some(aggregation) = &self.aggregation {
+                    if !aggregation.keys.contains(&name) {
+                        return Err(sql_error(format!(
+                            "column '{}' must appear in the GROUP BY clause or be used in an aggregate function",
+                            parts.join(".")
+                        )));
+                    }
+                }
+                Ok(col(&name))
+            }
+            SqlExpr::Literal(literal) => Ok(match literal {
+                SqlLiteral::Integer(v) => lit(*v),
+                SqlLiteral::Float(v) => lit(*v),
+                SqlLiteral::String(v) => lit(v.as_str()),
+                SqlLiteral::Boolean(v) => lit(*v),
+                SqlLiteral::Null => lit(Null {}),
+            }),
+            SqlExpr::BinaryOp { left, op, right } => {
+                let left = self.translate(left)?;
+                let right = self.translate(right)?;
+                Ok(match op {
+                    SqlBinaryOp::Eq => left.eq(right),
+                    SqlBinaryOp::NotEq => left.neq(right),
+                    SqlBinaryOp::Lt => left.lt(right),
+                    SqlBinaryOp::LtEq => left.lt_eq(right),
+                    SqlBinaryOp::Gt => left.gt(right),
+                    SqlBinaryOp::GtEq => left.gt_eq(right),
+                    SqlBinaryOp::Plus => binary_expr(left, Operator::Plus, right),
+                    SqlBinaryOp::Minus => binary_expr(left, Operator::Minus, right),
+                    SqlBinaryOp::Multiply => binary_expr(left, Operator::Multiply, right),
+                    SqlBinaryOp::Divide => binary_expr(left, Operator::Divide, right),
+                    SqlBinaryOp::Modulus => binary_expr(left, Operator::Modulus, right),
+                    SqlBinaryOp::And => left.and(right),
+                    SqlBinaryOp::Or => left.or(right),
+                })
+            }
+            SqlExpr::Negative(expr) => Ok(match &**expr {
+                SqlExpr::Literal(SqlLiteral::Integer(v)) => lit(-*v),
+                SqlExpr::Literal(SqlLiteral::Float(v)) => lit(-*v),
+                expr => binary_expr(lit(0), Operator::Minus, self.translate(expr)?),
+            }),
+            SqlExpr::Not(expr) => Ok(self.translate(expr)?.not()),
+            SqlExpr::IsNull { expr, negated } => {
+                let expr = self.translate(expr)?;
+                Ok(if *negated {
+                    expr.is_not_null()
+                } else {
+                    expr.is_null()
+                })
+            }
+            SqlExpr::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let expr = self.translate(expr)?;
+                let mut predicate: Option<Expr> = None;
+                for item in list {
+                    let eq = expr.clone().eq(self.translate(item)?);
+                    predicate = Some(match predicate {
+                        Some(predicate) => predicate.or(eq),
+                        None => eq,
+                    });
+                }
+                // the parser guarantees a non empty list
+                let predicate = predicate.unwrap();
+                Ok(if *negated { predicate.not() } else { predicate })
+            }
+            SqlExpr::Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => {
+                let expr = self.translate(expr)?;
+                let predicate = expr
+                    .clone()
+                    .gt_eq(self.translate(low)?)
+                    .and(expr.lt_eq(self.translate(high)?));
+                Ok(if *negated { predicate.not() } else { predicate })
+            }
+            SqlExpr::Function {
+                name,
+                args,
+                distinct,
+                wildcard,
+            } => self.translate_aggregate(name, args, *distinct, *wildcard),
+            SqlExpr::Cast { expr, data_type } => Ok(self.translate(expr)?.cast(data_type.clone())),
+            SqlExpr::Case {
+                operand,
+                conditions,
+                else_result,
+            } => {
+                let operand = match operand {
+                    Some(operand) => Some(self.translate(operand)?),
+                    None => None,
+                };
+                let mut out = match else_result {
+                    Some(else_result) => self.translate(else_result)?,
+                    None => lit(Null {}),
+                };
+                for (condition, result) in conditions.iter().rev() {
+                    let mut predicate = self.translate(condition)?;
+                    if let Some(operand) = &operand {
+                        predicate = operand.clone().eq(predicate);
+                    }
+                    out = when(predicate).then(self.translate(result)?).otherwise(out);
+                }
+                Ok(out)
+            }
+        }
+    }
+
+    fn translate_aggregate(
+        &mut self,
+        name: &str,
+        args: &[SqlExpr],
+        distinct: bool,
+        wildcard: bool,
+    ) -> Result<Expr> {
+        if !is_aggregate(name) {
+            return Err(sql_error(format!(
+                "function {} is not supported",
+                name.to_uppercase()
+            )));
+        }
+        if self.aggregation.is_none() {
+            return Err(sql_error(format!(
+                "aggregate function {} is not allowed here",
+                name.to_uppercase()
+            )));
+        }
+        // the arguments are evaluated on the input of the aggregation
+        let mut input = ExprTranslator::new(self.scope, self.count_column, None);
+        let agg = match (name, args) {
+            ("count", []) if wildcard => col(self.count_column).count(),
+            ("count", [arg]) if distinct => input.translate(arg)?.n_unique(),
+            (_, _) if distinct => {
+                return Err(sql_error(format!(
+                    "DISTINCT is not supported in {}",
+                    name.to_uppercase()
+                )))
+            }
+            ("count", [arg]) => input
+                .translate(arg)?
+                .is_not_null()
+                .cast(DataType::UInt32)
+                .sum(),
+            ("sum", [arg]) => input.translate(arg)?.sum(),
+            ("min", [arg]) => input.translate(arg)?.min(),
+            ("max", [arg]) => input.translate(arg)?.max(),
+            ("avg", [arg]) | ("mean", [arg]) => input.translate(arg)?.mean(),
+            ("median", [arg]) => input.translate(arg)?.median(),
+            ("first", [arg]) => input.translate(arg)?.first(),
+            ("last", [arg]) => input.translate(arg)?.last(),
+            ("stddev", [arg]) | ("stddev_samp", [arg]) | ("std", [arg]) => {
+                input.translate(arg)?.std()
+            }
+            ("variance", [arg]) | ("var_samp", [arg]) | ("var", [arg]) => {
+                input.translate(arg)?.var()
+            }
+            _ => {
+                return Err(sql_error(format!(
+                    "wrong number of arguments for {}",
+                    name.to_uppercase()
+                )))
+            }
+        };
+
+        let aggregation = self.aggregation.as_mut().unwrap();
+        let output_name = format!("__agg_{}", aggregation.aggs.len());
+        aggregation.aggs.push(agg.alias(&output_name));
+        Ok(col(&output_name))
+    }
+}
+
+fn is_aggregate(name: &str) -> bool {
+    matches!(
+        name,
+        "count"
+            | "sum"
+            | "min"
+            | "max"
+            | "avg"
+            | "mean"
+            | "median"
+            | "first"
+            | "last"
+            | "stddev"
+            | "stddev_samp"
+            | "std"
+            | "variance"
+            | "var_samp"
+            | "var"
+    )
+}
+
+fn contains_aggregate(expr: &SqlExpr) -> bool {
+    match expr {
+        SqlExpr::Function { name, .. } if is_aggregate(name) => true,
+        SqlExpr::Function { args, .. } => args.iter().any(contains_aggregate),
+        SqlExpr::Identifier(_) | SqlExpr::Literal(_) => false,
+        SqlExpr::BinaryOp { left, right, .. } => {
+            contains_aggregate(left) || contains_aggregate(right)
+        }
+        SqlExpr::Negative(expr)
+        | SqlExpr::Not(expr)
+        | SqlExpr::IsNull { expr, .. }
+        | SqlExpr::Cast { expr, .. } => contains_aggregate(expr),
+        SqlExpr::InList { expr, list, .. } => {
+            contains_aggregate(expr) || list.iter().any(contains_aggregate)
+        }
+        SqlExpr::Between {
+            expr, low, high, ..
+        } => contains_aggregate(expr) || contains_aggregate(low) || contains_aggregate(high),
+        SqlExpr::Case {
+            operand,
+            conditions,
+            else_result,
+        } => {
+            operand.as_deref().map_or(false, contains_aggregate)
+                || conditions
+                    .iter()
+                    .any(|(c, r)| contains_aggregate(c) || contains_aggregate(r))
+                || else_result.as_deref().map_or(false, contains_aggregate)
+        }
+    }
+}
+
+fn first_identifier(expr: &SqlExpr) -> Option<&[String]> {
+    match expr {
+        SqlExpr::Identifier(parts) => Some(parts.as_slice()),
+        SqlExpr::Literal(_) => None,
+        SqlExpr::Function { args, .. } => args.iter().find_map(first_identifier),
+        SqlExpr::BinaryOp { left, right, .. } => {
+            first_identifier(left).or_else(|| first_identifier(right))
+        }
+        SqlExpr::Negative(expr)
+        | SqlExpr::Not(expr)
+        | SqlExpr::IsNull { expr, .. }
+        | SqlExpr::InList { expr, .. }
+        | SqlExpr::Between { expr, .. }
+        | SqlExpr::Cast { expr, .. } => first_identifier(expr),
+        SqlExpr::Case {
+            operand,
+            conditions,
+            else_result,
+        } => operand
+            .as_deref()
+            .and_then(first_identifier)
+            .or_else(|| {
+                conditions
+                    .iter()
+                    .find_map(|(c, r)| first_identifier(c).or_else(|| first_identifier(r)))
+            })
+            .or_else(|| else_result.as_deref().and_then(first_identifier)),
+    }
+}
+
+/// The output name of an unaliased expression in an aggregation. Aggregate functions are
+/// named `<function>_<column>`, e.g. `sum_a`, so that they don't clash with the grouping keys.
+fn default_name(expr: &SqlExpr, scope: &Scope, position: usize) -> Result<String> {
+    match (expr, first_identifier(expr)) {
+        (SqlExpr::Function { name, .. }, Some(parts)) => {
+            Ok(format!("{}_{}", name, scope.resolve(parts)?))
+        }
+        (SqlExpr::Function { name, .. }, None) => Ok(name.clone()),
+        (_, Some(parts)) => scope.resolve(parts),
+        (_, None) => Ok(format!("column_{}", position)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+
+    #[test]
+    fn test_sql_errors() -> Result<()> {
+        let mut ctx = SQLContext::new();
+        ctx.register(
+            "t",
+            df![
+                "a" => [1, 2, 3],
+                "b" => ["x", "y", "x"]
+            ]?
+            .lazy(),
+        );
+
+        let err = |query| match ctx.execute(query) {
+            Err(e) => format!("{:?}", e),
+            Ok(_) => panic!("expected an error for {}", query),
+        };
+        assert!(err("SELECT a FROM u").contains("table 'u' is not registered"));
+        assert!(err("SELECT c FROM t").contains("column 'c' not found"));
+        assert!(err("SELECT a, SUM(a) FROM t GROUP BY b").contains("GROUP BY"));
+        assert!(err("SELECT a FROM t WHERE SUM(a) > 1").contains("HAVING"));
+        assert!(err("SELECT UPPER(b) FROM t").contains("not supported"));
+        assert!(err("SELECT a FROM t UNION SELECT a FROM t").contains("UNION"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sql_aggregation_names() -> Result<()> {
+        let mut ctx = SQLContext::new();
+        ctx.register(
+            "t",
+            df![
+                "a" => [1, 2, 3],
+                "b" => ["x", "y", "x"]
+            ]?
+            .lazy(),
+        );
+
+        let out = ctx
+            .execute("SELECT a, SUM(a), MAX(a), COUNT(*) FROM t GROUP BY a")?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["a", "sum_a", "max_a", "count"]);
+
+        let out = ctx
+            .execute("SELECT b, SUM(a), SUM(a) FROM t GROUP BY b ORDER BY b")?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["b", "sum_a", "sum_a_2"]);
+        assert_eq!(
+            Vec::from(out.column("sum_a_2")?.i32()?),
+            &[Some(4), Some(2)]
+        );
+
+        // the generated name doesn't clash with an alias or a column of the table
+        ctx.register(
+            "u",
+            df![
+                "a" => [1, 2, 3],
+                "b" => ["x", "y", "x"],
+                "sum_a_2" => [0, 0, 0]
+            ]?
+            .lazy(),
+        );
+        let out = ctx
+            .execute("SELECT SUM(a), SUM(a), MAX(a) AS sum_a_1 FROM u")?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["sum_a", "sum_a_3", "sum_a_1"]);
+        let out = ctx
+            .execute("SELECT b, SUM(a), SUM(a) FROM u GROUP BY b ORDER BY b")?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["b", "sum_a", "sum_a_3"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sql_order_by_expression() -> Result<()> {
+        let mut ctx = SQLContext::new();
+        ctx.register(
+            "t",
+            df![
+                "a" => [1, 5, 3],
+                "b" => [4, -3, 1]
+            ]?
+            .lazy(),
+        );
+
+        let out = ctx
+            .execute("SELECT a, b FROM t ORDER BY a + b")?
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(5), Some(3), Some(1)]
+        );
+
+        // the sort key is not added to the output
+        let out = ctx
+            .execute("SELECT * FROM t ORDER BY a * 2 DESC")?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(5), Some(3), Some(1)]
+        );
+        Ok(())
+    }
+}
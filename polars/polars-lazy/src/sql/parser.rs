@@ -0,0 +1,782 @@
+use super::sql_error;
+use super::tokenizer::{tokenize, Token};
+use polars_core::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Query {
+    pub(crate) distinct: bool,
+    pub(crate) projection: Vec<SelectItem>,
+    pub(crate) from: TableRef,
+    pub(crate) joins: Vec<Join>,
+    pub(crate) selection: Option<SqlExpr>,
+    pub(crate) group_by: Vec<SqlExpr>,
+    pub(crate) having: Option<SqlExpr>,
+    pub(crate) order_by: Vec<OrderByExpr>,
+    pub(crate) limit: Option<usize>,
+    pub(crate) offset: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum SelectItem {
+    Wildcard,
+    Expr {
+        expr: SqlExpr,
+        alias: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct TableRef {
+    pub(crate) name: String,
+    pub(crate) alias: Option<String>,
+}
+
+impl TableRef {
+    /// The name the columns of this table are qualified with.
+    pub(crate) fn reference(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum SqlJoinType {
+    Inner,
+    Left,
+    Full,
+    Cross,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Join {
+    pub(crate) how: SqlJoinType,
+    pub(crate) table: TableRef,
+    pub(crate) on: Option<SqlExpr>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct OrderByExpr {
+    pub(crate) expr: SqlExpr,
+    pub(crate) descending: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum SqlBinaryOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+    Modulus,
+    And,
+    Or,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum SqlLiteral {
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    Null,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum SqlExpr {
+    /// A column, optionally qualified with a table name: `a` or `t.a`.
+    Identifier(Vec<String>),
+    Literal(SqlLiteral),
+    BinaryOp {
+        left: Box<SqlExpr>,
+        op: SqlBinaryOp,
+        right: Box<SqlExpr>,
+    },
+    Negative(Box<SqlExpr>),
+    Not(Box<SqlExpr>),
+    IsNull {
+        expr: Box<SqlExpr>,
+        negated: bool,
+    },
+    InList {
+        expr: Box<SqlExpr>,
+        list: Vec<SqlExpr>,
+        negated: bool,
+    },
+    Between {
+        expr: Box<SqlExpr>,
+        low: Box<SqlExpr>,
+        high: Box<SqlExpr>,
+        negated: bool,
+    },
+    /// A function call. The name is lower case. `wildcard` is set for `COUNT(*)`.
+    Function {
+        name: String,
+        args: Vec<SqlExpr>,
+        distinct: bool,
+        wildcard: bool,
+    },
+    Cast {
+        expr: Box<SqlExpr>,
+        data_type: DataType,
+    },
+    Case {
+        operand: Option<Box<SqlExpr>>,
+        conditions: Vec<(SqlExpr, SqlExpr)>,
+        else_result: Option<Box<SqlExpr>>,
+    },
+}
+
+/// Keywords that end an expression and can therefore not be used as an implicit alias.
+const RESERVED: [&str; 29] = [
+    "select",
+    "from",
+    "where",
+    "group",
+    "by",
+    "having",
+    "order",
+    "limit",
+    "offset",
+    "join",
+    "inner",
+    "left",
+    "right",
+    "full",
+    "outer",
+    "cross",
+    "on",
+    "as",
+    "and",
+    "or",
+    "not",
+    "union",
+    "intersect",
+    "except",
+    "asc",
+    "desc",
+    "when",
+    "then",
+    "end",
+];
+
+/// Parse a `SELECT` query.
+pub(crate) fn parse(sql: &str) -> Result<Query> {
+    let mut parser = Parser {
+        tokens: tokenize(sql)?,
+        pos: 0,
+    };
+    let query = parser.parse_query()?;
+    parser.parse_symbol(";");
+    match parser.peek() {
+        Token::Eof => Ok(query),
+        token => Err(sql_error(format!(
+            "unexpected '{}' after the end of the query",
+            token
+        ))),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn peek_nth(&self, n: usize) -> &Token {
+        self.tokens
+            .get(self.pos + n)
+            .unwrap_or_else(|| self.tokens.last().unwrap())
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if self.pos < self.tokens.len() - 1 {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn parse_keyword(&mut self, keyword: &str) -> bool {
+        if self.peek().is_keyword(keyword) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_keywords(&mut self, keywords: &[&str]) -> bool {
+        let matches = keywords
+            .iter()
+            .enumerate()
+            .all(|(i, kw)| self.peek_nth(i).is_keyword(kw));
+        if matches {
+            self.pos += keywords.len();
+        }
+        matches
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        if self.parse_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(sql_error(format!(
+                "expected {}, found '{}'",
+                keyword.to_uppercase(),
+                self.peek()
+            )))
+        }
+    }
+
+    fn parse_symbol(&mut self, symbol: &str) -> bool {
+        if matches!(self.peek(), Token::Symbol(s) if *s == symbol) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<()> {
+        if self.parse_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(sql_error(format!(
+                "expected '{}', found '{}'",
+                symbol,
+                self.peek()
+            )))
+        }
+    }
+
+    fn parse_identifier(&mut self) -> Result<String> {
+        match self.next() {
+            Token::Word { value, .. } => Ok(value),
+            token => Err(sql_error(format!(
+                "expected an identifier, found '{}'",
+                token
+            ))),
+        }
+    }
+
+    /// Parse `[AS] alias`, where the `AS` keyword is optional.
+    fn parse_optional_alias(&mut self) -> Result<Option<String>> {
+        if self.parse_keyword("as") {
+            return self.parse_identifier().map(Some);
+        }
+        let is_alias = matches!(self.peek(), Token::Word { .. }) && !is_reserved(self.peek());
+        if is_alias {
+            self.parse_identifier().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn parse_usize(&mut self, clause: &str) -> Result<usize> {
+        match self.next() {
+            Token::Number(n) => n.parse().map_err(|_| {
+                sql_error(format!(
+                    "expected a positive integer in {}, found {}",
+                    clause, n
+                ))
+            }),
+            token => Err(sql_error(format!(
+                "expected a positive integer in {}, found '{}'",
+                clause, token
+            ))),
+        }
+    }
+
+    fn parse_query(&mut self) -> Result<Query> {
+        if self.peek().is_keyword("with") {
+            return Err(sql_error("WITH clauses are not supported".into()));
+        }
+        self.expect_keyword("select")?;
+        let distinct = self.parse_keyword("distinct");
+
+        let mut projection = vec![];
+        loop {
+            if self.parse_symbol("*") {
+                projection.push(SelectItem::Wildcard);
+            } else {
+                // `t.*`
+                if matches!(self.peek(), Token::Word { .. })
+                    && matches!(self.peek_nth(1), Token::Symbol("."))
+                    && matches!(self.peek_nth(2), Token::Symbol("*"))
+                {
+                    return Err(sql_error(
+                        "qualified wildcards like 't.*' are not supported".into(),
+                    ));
+                }
+                let expr = self.parse_expr()?;
+                let alias = self.parse_optional_alias()?;
+                projection.push(SelectItem::Expr { expr, alias });
+            }
+            if !self.parse_symbol(",") {
+                break;
+            }
+        }
+
+        self.expect_keyword("from")?;
+        let from = self.parse_table_ref()?;
+        if matches!(self.peek(), Token::Symbol(",")) {
+            return Err(sql_error(
+                "selecting from multiple tables separated by a comma is not supported, use a JOIN"
+                    .into(),
+            ));
+        }
+
+        let mut joins = vec![];
+        loop {
+            let how = if self.parse_keyword("join") || self.parse_keywords(&["inner", "join"]) {
+                SqlJoinType::Inner
+            } else if self.parse_keywords(&["left", "join"])
+                || self.parse_keywords(&["left", "outer", "join"])
+            {
+                SqlJoinType::Left
+            } else if self.parse_keywords(&["full", "join"])
+                || self.parse_keywords(&["full", "outer", "join"])
+            {
+                SqlJoinType::Full
+            } else if self.parse_keywords(&["cross", "join"]) {
+                SqlJoinType::Cross
+            } else if self.peek().is_keyword("right") {
+                return Err(sql_error(
+                    "RIGHT JOIN is not supported, swap the tables and use a LEFT JOIN".into(),
+                ));
+            } else {
+                break;
+            };
+            let table = self.parse_table_ref()?;
+            let on = if how != SqlJoinType::Cross {
+                self.expect_keyword("on")?;
+                Some(self.parse_expr()?)
+            } else {
+                None
+            };
+            joins.push(Join { how, table, on });
+        }
+
+        let selection = if self.parse_keyword("where") {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+
+        let mut group_by = vec![];
+        if self.parse_keywords(&["group", "by"]) {
+            loop {
+                group_by.push(self.parse_expr()?);
+                if !self.parse_symbol(",") {
+                    break;
+                }
+            }
+        }
+
+        let having = if self.parse_keyword("having") {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+
+        let mut order_by = vec![];
+        if self.parse_keywords(&["order", "by"]) {
+            loop {
+                let expr = self.parse_expr()?;
+                let descending = if self.parse_keyword("desc") {
+                    true
+                } else {
+                    self.parse_keyword("asc");
+                    false
+                };
+                if self.peek().is_keyword("nulls") {
+                    return Err(sql_error("NULLS FIRST/LAST is not supported".into()));
+                }
+                order_by.push(OrderByExpr { expr, descending });
+                if !self.parse_symbol(",") {
+                    break;
+                }
+            }
+        }
+
+        let limit = if self.parse_keyword("limit") {
+            Some(self.parse_usize("LIMIT")?)
+        } else {
+            None
+        };
+        let offset = if self.parse_keyword("offset") {
+            Some(self.parse_usize("OFFSET")?)
+        } else {
+            None
+        };
+
+        for set_operation in ["union", "intersect", "except"] {
+            if self.peek().is_keyword(set_operation) {
+                return Err(sql_error(format!(
+                    "{} is not supported",
+                    set_operation.to_uppercase()
+                )));
+            }
+        }
+
+        Ok(Query {
+            distinct,
+            projection,
+            from,
+            joins,
+            selection,
+            group_by,
+            having,
+            order_by,
+            limit,
+            offset,
+        })
+    }
+
+    fn parse_table_ref(&mut self) -> Result<TableRef> {
+        if matches!(self.peek(), Token::Symbol("(")) {
+            return Err(sql_error("subqueries are not supported".into()));
+        }
+        let name = self.parse_identifier()?;
+        let alias = self.parse_optional_alias()?;
+        Ok(TableRef { name, alias })
+    }
+
+    fn parse_expr(&mut self) -> Result<SqlExpr> {
+        self.parse_or()
+    }
+
+    fn parse_or(&mut self) -> Result<SqlExpr> {
+        let mut expr = self.parse_and()?;
+        while self.parse_keyword("or") {
+            expr = binary(expr, SqlBinaryOp::Or, self.parse_and()?);
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<SqlExpr> {
+        let mut expr = self.parse_not()?;
+        while self.parse_keyword("and") {
+            expr = binary(expr, SqlBinaryOp::And, self.parse_not()?);
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<SqlExpr> {
+        if self.parse_keyword("not") {
+            Ok(SqlExpr::Not(Box::new(self.parse_not()?)))
+        } else {
+            self.parse_comparison()
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<SqlExpr> {
+        let expr = self.parse_additive()?;
+
+        if self.parse_keyword("is") {
+            let negated = self.parse_keyword("not");
+            self.expect_keyword("null")?;
+            return Ok(SqlExpr::IsNull {
+                expr: Box::new(expr),
+                negated,
+            });
+        }
+
+        let negated = self.parse_keyword("not");
+        if self.parse_keyword("in") {
+            self.expect_symbol("(")?;
+            if self.peek().is_keyword("select") {
+                return Err(sql_error("subqueries are not supported".into()));
+            }
+            let mut list = vec![];
+            loop {
+                list.push(self.parse_expr()?);
+                if !self.parse_symbol(",") {
+                    break;
+                }
+            }
+            self.expect_symbol(")")?;
+            return Ok(SqlExpr::InList {
+                expr: Box::new(expr),
+                list,
+                negated,
+            });
+        }
+        if self.parse_keyword("between") {
+            let low = self.parse_additive()?;
+            self.expect_keyword("and")?;
+            let high = self.parse_additive()?;
+            return Ok(SqlExpr::Between {
+                expr: Box::new(expr),
+                low: Box::new(low),
+                high: Box::new(high),
+                negated,
+            });
+        }
+        if self.peek().is_keyword("like") || self.peek().is_keyword("ilike") {
+            return Err(sql_error("LIKE is not supported".into()));
+        }
+        if negated {
+            return Err(sql_error(format!(
+                "expected IN or BETWEEN after NOT, found '{}'",
+                self.peek()
+            )));
+        }
+
+        let op = match self.peek() {
+            Token::Symbol("=") => SqlBinaryOp::Eq,
+            Token::Symbol("!=") | Token::Symbol("<>") => SqlBinaryOp::NotEq,
+            Token::Symbol("<") => SqlBinaryOp::Lt,
+            Token::Symbol("<=") => SqlBinaryOp::LtEq,
+            Token::Symbol(">") => SqlBinaryOp::Gt,
+            Token::Symbol(">=") => SqlBinaryOp::GtEq,
+            _ => return Ok(expr),
+        };
+        self.next();
+        Ok(binary(expr, op, self.parse_additive()?))
+    }
+
+    fn parse_additive(&mut self) -> Result<SqlExpr> {
+        let mut expr = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Token::Symbol("+") => SqlBinaryOp::Plus,
+                Token::Symbol("-") => SqlBinaryOp::Minus,
+                _ => return Ok(expr),
+            };
+            self.next();
+            expr = binary(expr, op, self.parse_multiplicative()?);
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<SqlExpr> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Token::Symbol("*") => SqlBinaryOp::Multiply,
+                Token::Symbol("/") => SqlBinaryOp::Divide,
+                Token::Symbol("%") => SqlBinaryOp::Modulus,
+                _ => return Ok(expr),
+            };
+            self.next();
+            expr = binary(expr, op, self.parse_unary()?);
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<SqlExpr> {
+        if self.parse_symbol("-") {
+            Ok(SqlExpr::Negative(Box::new(self.parse_unary()?)))
+        } else if self.parse_symbol("+") {
+            self.parse_unary()
+        } else {
+            self.parse_primary()
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<SqlExpr> {
+        match self.next() {
+            Token::Number(n) => {
+                let literal = if n.contains('.') {
+                    n.parse()
+                        .map(SqlLiteral::Float)
+                        .map_err(|_| sql_error(format!("invalid number {}", n)))?
+                } else {
+                    n.parse()
+                        .map(SqlLiteral::Integer)
+                        .map_err(|_| sql_error(format!("invalid integer {}", n)))?
+                };
+                Ok(SqlExpr::Literal(literal))
+            }
+            Token::String(s) => Ok(SqlExpr::Literal(SqlLiteral::String(s))),
+            Token::Symbol("(") => {
+                if self.peek().is_keyword("select") {
+                    return Err(sql_error("subqueries are not supported".into()));
+                }
+                let expr = self.parse_expr()?;
+                self.expect_symbol(")")?;
+                Ok(expr)
+            }
+            token @ Token::Word { quoted: false, .. } if is_reserved(&token) => Err(sql_error(
+                format!("expected an expression, found '{}'", token),
+            )),
+            token if token.is_keyword("true") => Ok(SqlExpr::Literal(SqlLiteral::Boolean(true))),
+            token if token.is_keyword("false") => Ok(SqlExpr::Literal(SqlLiteral::Boolean(false))),
+            token if token.is_keyword("null") => Ok(SqlExpr::Literal(SqlLiteral::Null)),
+            token if token.is_keyword("case") => self.parse_case(),
+            token if token.is_keyword("cast") => {
+                self.expect_symbol("(")?;
+                let expr = self.parse_expr()?;
+                self.expect_keyword("as")?;
+                let data_type = self.parse_data_type()?;
+                self.expect_symbol(")")?;
+                Ok(SqlExpr::Cast {
+                    expr: Box::new(expr),
+                    data_type,
+                })
+            }
+            Token::Word { value, quoted } => {
+                if !quoted && self.parse_symbol("(") {
+                    return self.parse_function(value.to_lowercase());
+                }
+                let mut parts = vec![value];
+                if self.parse_symbol(".") {
+                    parts.push(self.parse_identifier()?);
+                }
+                if matches!(self.peek(), Token::Symbol(".")) {
+                    return Err(sql_error(format!(
+                        "identifiers with more than two parts are not supported: '{}.{}'",
+                        parts.join("."),
+                        self.peek_nth(1)
+                    )));
+                }
+                Ok(SqlExpr::Identifier(parts))
+            }
+            token => Err(sql_error(format!(
+                "expected an expression, found '{}'",
+                token
+            ))),
+        }
+    }
+
+    fn parse_function(&mut self, name: String) -> Result<SqlExpr> {
+        let distinct = self.parse_keyword("distinct");
+        let mut args = vec![];
+        let mut wildcard = false;
+        if self.parse_symbol("*") {
+            wildcard = true;
+        } else if !matches!(self.peek(), Token::Symbol(")")) {
+            loop {
+                args.push(self.parse_expr()?);
+                if !self.parse_symbol(",") {
+                    break;
+                }
+            }
+        }
+        self.expect_symbol(")")?;
+        if self.peek().is_keyword("over") {
+            return Err(sql_error("window functions are not supported".into()));
+        }
+        Ok(SqlExpr::Function {
+            name,
+            args,
+            distinct,
+            wildcard,
+        })
+    }
+
+    fn parse_case(&mut self) -> Result<SqlExpr> {
+        let operand = if self.peek().is_keyword("when") {
+            None
+        } else {
+            Some(Box::new(self.parse_expr()?))
+        };
+        let mut conditions = vec![];
+        while self.parse_keyword("when") {
+            let condition = self.parse_expr()?;
+            self.expect_keyword("then")?;
+            let result = self.parse_expr()?;
+            conditions.push((condition, result));
+        }
+        if conditions.is_empty() {
+            return Err(sql_error("expected WHEN in CASE expression".into()));
+        }
+        let else_result = if self.parse_keyword("else") {
+            Some(Box::new(self.parse_expr()?))
+        } else {
+            None
+        };
+        self.expect_keyword("end")?;
+        Ok(SqlExpr::Case {
+            operand,
+            conditions,
+            else_result,
+        })
+    }
+
+    fn parse_data_type(&mut self) -> Result<DataType> {
+        let name = self.parse_identifier()?.to_lowercase();
+        let data_type = match name.as_str() {
+            "smallint" | "int" | "integer" => DataType::Int32,
+            "bigint" => DataType::Int64,
+            "float" | "real" => DataType::Float32,
+            "double" => {
+                self.parse_keyword("precision");
+                DataType::Float64
+            }
+            "varchar" | "char" | "text" | "string" => DataType::Utf8,
+            "boolean" | "bool" => DataType::Boolean,
+            #[cfg(feature = "dtype-date")]
+            "date" => DataType::Date,
+            _ => {
+                return Err(sql_error(format!(
+                    "casting to {} is not supported",
+                    name.to_uppercase()
+                )))
+            }
+        };
+        // ignore the length of e.g. `VARCHAR(10)`
+        if self.parse_symbol("(") {
+            self.parse_usize("type length")?;
+            self.expect_symbol(")")?;
+        }
+        Ok(data_type)
+    }
+}
+
+fn binary(left: SqlExpr, op: SqlBinaryOp, right: SqlExpr) -> SqlExpr {
+    SqlExpr::BinaryOp {
+        left: Box::new(left),
+        op,
+        right: Box::new(right),
+    }
+}
+
+fn is_reserved(token: &Token) -> bool {
+    RESERVED.iter().any(|kw| token.is_keyword(kw))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_query() -> Result<()> {
+        let query = parse(
+            "SELECT a, SUM(b) AS total FROM t x LEFT JOIN u ON x.a = u.a \
+             WHERE b BETWEEN 1 AND 10 GROUP BY a HAVING COUNT(*) > 1 ORDER BY total DESC LIMIT 5",
+        )?;
+        assert_eq!(query.projection.len(), 2);
+        assert_eq!(query.from.reference(), "x");
+        assert_eq!(query.joins[0].how, SqlJoinType::Left);
+        assert_eq!(query.group_by, vec![SqlExpr::Identifier(vec!["a".into()])]);
+        assert!(query.order_by[0].descending);
+        assert_eq!(query.limit, Some(5));
+
+        // precedence: AND binds stronger than OR, * stronger than +
+        let query = parse("select a + b * 2 from t where a = 1 or b = 2 and c = 3")?;
+        match &query.selection {
+            Some(SqlExpr::BinaryOp { op, .. }) => assert_eq!(*op, SqlBinaryOp::Or),
+            _ => panic!(),
+        }
+        match &query.projection[0] {
+            SelectItem::Expr {
+                expr: SqlExpr::BinaryOp { op, .. },
+                ..
+            } => assert_eq!(*op, SqlBinaryOp::Plus),
+            _ => panic!(),
+        }
+
+        assert!(parse("SELECT a FROM t RIGHT JOIN u ON t.a = u.a").is_err());
+        assert!(parse("SELECT a FROM (SELECT a FROM t)").is_err());
+        assert!(parse("SELECT a FROM t WHERE").is_err());
+        Ok(())
+    }
+}
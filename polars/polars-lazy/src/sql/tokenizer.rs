@@ -0,0 +1,141 @@
+use super::sql_error;
+use polars_core::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Token {
+    /// A keyword or identifier. Quoted identifiers are never keywords.
+    Word {
+        value: String,
+        quoted: bool,
+    },
+    Number(String),
+    /// A single quoted string literal.
+    String(String),
+    /// Punctuation and operators, e.g. `(`, `,` or `<=`.
+    Symbol(&'static str),
+    Eof,
+}
+
+impl Token {
+    /// Check if this token is the given (case insensitive) keyword.
+    pub(crate) fn is_keyword(&self, keyword: &str) -> bool {
+        match self {
+            Token::Word {
+                value,
+                quoted: false,
+            } => value.eq_ignore_ascii_case(keyword),
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Word {
+                value,
+                quoted: false,
+            } => write!(f, "{}", value),
+            Token::Word {
+                value,
+                quoted: true,
+            } => write!(f, "\"{}\"", value),
+            Token::Number(n) => write!(f, "{}", n),
+            Token::String(s) => write!(f, "'{}'", s),
+            Token::Symbol(s) => write!(f, "{}", s),
+            Token::Eof => write!(f, "end of query"),
+        }
+    }
+}
+
+const SYMBOLS: [&str; 17] = [
+    "<=", ">=", "<>", "!=", "(", ")", ",", ".", ";", "*", "+", "-", "/", "%", "=", "<", ">",
+];
+
+pub(crate) fn tokenize(sql: &str) -> Result<Vec<Token>> {
+    let chars = sql.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '-' && chars.get(i + 1) == Some(&'-') {
+            // skip line comments
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Word {
+                value: chars[start..i].iter().collect(),
+                quoted: false,
+            });
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c == '\'' || c == '"' {
+            // a doubled quote escapes the quote character
+            let mut value = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => {
+                        return Err(sql_error(format!(
+                            "unterminated {} in SQL query",
+                            if c == '\'' { "string" } else { "identifier" }
+                        )))
+                    }
+                    Some(&ch) if ch == c => {
+                        if chars.get(i + 1) == Some(&c) {
+                            value.push(c);
+                            i += 2;
+                        } else {
+                            i += 1;
+                            break;
+                        }
+                    }
+                    Some(&ch) => {
+                        value.push(ch);
+                        i += 1;
+                    }
+                }
+            }
+            if c == '\'' {
+                tokens.push(Token::String(value))
+            } else {
+                tokens.push(Token::Word {
+                    value,
+                    quoted: true,
+                })
+            }
+        } else {
+            let symbol = SYMBOLS.iter().find(|s| {
+                s.chars()
+                    .enumerate()
+                    .all(|(offset, ch)| chars.get(i + offset) == Some(&ch))
+            });
+            match symbol {
+                Some(s) => {
+                    tokens.push(Token::Symbol(*s));
+                    i += s.len();
+                }
+                None => {
+                    return Err(sql_error(format!(
+                        "unexpected character '{}' in SQL query",
+                        c
+                    )))
+                }
+            }
+        }
+    }
+    tokens.push(Token::Eof);
+    Ok(tokens)
+}
@@ -1251,16 +1251,40 @@ fn test_fill_forward() -> Result<()> {
 
     let out = df
         .lazy()
-        .select([col("b").forward_fill().over([col("a")])])
+        .select([col("b").forward_fill(None).over([col("a")])])
         .collect()?;
-    let agg = out.column("b")?.list()?;
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(1), None, Some(1)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fill_directional_over() -> Result<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "b", "a", "a"],
+        "v" => [Some("x"), Some("y"), None, None, None, Some("z")]
+    ]?;
 
-    let a: Series = agg.get(0).unwrap();
-    assert!(a.series_equal(&Series::new("b", &[1, 1])));
-    let a: Series = agg.get(2).unwrap();
-    assert!(a.series_equal(&Series::new("b", &[1, 1])));
-    let a: Series = agg.get(1).unwrap();
-    assert_eq!(a.null_count(), 1);
+    let out = df
+        .lazy()
+        .select([
+            col("v")
+                .forward_fill(Some(1))
+                .over([col("g")])
+                .alias("ffill"),
+            col("v").backward_fill(None).over([col("g")]).alias("bfill"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("ffill")?.utf8()?),
+        &[Some("x"), Some("y"), Some("x"), Some("y"), None, Some("z")]
+    );
+    assert_eq!(
+        Vec::from(out.column("bfill")?.utf8()?),
+        &[Some("x"), Some("y"), Some("z"), None, Some("z"), Some("z")]
+    );
     Ok(())
 }
 
@@ -1282,6 +1306,222 @@ fn test_cross_join() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "asof_join")]
+#[test]
+fn test_lazy_asof_join_options() -> Result<()> {
+    let trades = df![
+        "time" => [1, 5, 10],
+        "ticker" => ["MSFT", "MSFT", "MSFT"],
+    ]?;
+    let quotes = df![
+        "time" => [1, 2, 3, 6, 7],
+        "ticker" => ["MSFT", "MSFT", "MSFT", "MSFT", "MSFT"],
+        "bid" => [1, 2, 3, 6, 7]
+    ]?;
+
+    let out = trades
+        .clone()
+        .lazy()
+        .join_builder()
+        .with(quotes.clone().lazy())
+        .left_on(vec![col("time")])
+        .right_on(vec![col("time")])
+        .how(JoinType::AsOf)
+        .asof_strategy(AsofStrategy::Nearest)
+        .asof_tolerance(AnyValue::Int32(1))
+        .finish()
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("bid")?.i32()?),
+        &[Some(1), Some(6), None]
+    );
+
+    let out = trades
+        .lazy()
+        .join_builder()
+        .with(quotes.lazy())
+        .left_on(vec![col("time")])
+        .right_on(vec![col("time")])
+        .how(JoinType::AsOf)
+        .asof_by(vec!["ticker".into()], vec!["ticker".into()])
+        .asof_strategy(AsofStrategy::Forward)
+        .asof_allow_exact_matches(false)
+        .finish()
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("bid")?.i32()?),
+        &[Some(2), Some(6), None]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "semi_anti_join")]
+#[test]
+fn test_lazy_semi_anti_join() -> Result<()> {
+    let df_a = fruits_cars();
+    let df_b = df![
+        "fruits" => ["banana", "pear"],
+        "price" => [1, 2],
+    ]?;
+
+    let q = df_a
+        .clone()
+        .lazy()
+        .semi_join(df_b.clone().lazy(), col("fruits"), col("fruits"))
+        .select([col("A")]);
+
+    // the right table should only provide the join key
+    let mut expr_arena = Arena::with_capacity(16);
+    let mut lp_arena = Arena::with_capacity(8);
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).all(|(_, lp)| {
+        if let ALogicalPlan::DataFrameScan { projection, .. } = lp {
+            matches!(projection, Some(projection) if projection.len() <= 2)
+        } else {
+            true
+        }
+    }));
+
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), &["A"]);
+    assert_eq!(
+        Vec::from(out.column("A")?.i32()?),
+        &[Some(1), Some(2), Some(5)]
+    );
+
+    let out = df_a
+        .lazy()
+        .anti_join(df_b.lazy(), col("fruits"), col("fruits"))
+        .filter(col("B").gt(lit(2)))
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["A", "fruits", "B", "cars"]);
+    assert_eq!(Vec::from(out.column("A")?.i32()?), &[Some(3)]);
+    Ok(())
+}
+
+#[test]
+fn test_lazy_join_key_options() -> Result<()> {
+    let df_a = df![
+        "a" => [Some(1), Some(2), None],
+        "b" => ["x", "y", "z"],
+    ]?;
+    let df_b = df![
+        "a" => [Some(1), Some(1), None, Some(3)],
+        "c" => [1, 2, 3, 4],
+    ]?;
+
+    let out = df_a
+        .clone()
+        .lazy()
+        .join_builder()
+        .with(df_b.clone().lazy())
+        .left_on(vec![col("a")])
+        .right_on(vec![col("a")])
+        .how(JoinType::Left)
+        .validate(JoinValidation::OneToOne)
+        .finish()
+        .collect();
+    assert!(out.is_err());
+
+    let out = df_a
+        .clone()
+        .lazy()
+        .join_builder()
+        .with(df_b.clone().lazy())
+        .left_on(vec![col("a")])
+        .right_on(vec![col("a")])
+        .how(JoinType::Left)
+        .join_nulls(false)
+        .finish()
+        .collect()?;
+    assert_eq!(out.column("c")?.null_count(), 2);
+
+    let q = df_a
+        .lazy()
+        .join_builder()
+        .with(df_b.lazy())
+        .left_on(vec![col("a")])
+        .right_on(vec![col("a")])
+        .how(JoinType::Outer)
+        .coalesce(false)
+        .finish();
+    let schema = q.schema();
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b", "a_right", "c"]);
+    assert_eq!(out.schema(), *schema);
+    Ok(())
+}
+
+#[cfg(feature = "range_join")]
+#[test]
+fn test_lazy_join_where() -> Result<()> {
+    let events = df![
+        "ts" => [1, 4, 5, 9, 12],
+        "key" => ["a", "a", "b", "b", "b"],
+    ]?;
+    let intervals = df![
+        "start" => [0, 4, 8],
+        "end" => [4, 9, 10],
+        "key" => ["a", "a", "b"],
+        "name" => ["x", "y", "z"],
+    ]?;
+
+    let out = events
+        .clone()
+        .lazy()
+        .join_where(
+            intervals.clone().lazy(),
+            vec![col("ts").gt_eq(col("start")), col("ts").lt(col("end"))],
+        )?
+        .select([col("ts"), col("name")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("ts")?.i32()?),
+        &[Some(1), Some(4), Some(5), Some(9)]
+    );
+    assert_eq!(
+        Vec::from(out.column("name")?.utf8()?),
+        &[Some("x"), Some("y"), Some("y"), Some("z")]
+    );
+
+    // equality predicates are used as join keys
+    let out = events
+        .clone()
+        .lazy()
+        .join_where(
+            intervals.clone().lazy(),
+            vec![
+                col("key").eq(col("key")),
+                col("ts").gt_eq(col("start")),
+                col("ts").lt(col("end")),
+            ],
+        )?
+        .collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["ts", "key", "start", "end", "name"]
+    );
+    assert_eq!(
+        Vec::from(out.column("ts")?.i32()?),
+        &[Some(1), Some(4), Some(9)]
+    );
+
+    // only comparisons between two columns are supported
+    let rejected = [
+        col("ts").gt_eq(lit(1)),
+        (col("ts") + col("start")).lt(col("end")),
+        col("ts").neq(col("start")),
+    ];
+    for predicate in rejected.iter() {
+        assert!(events
+            .clone()
+            .lazy()
+            .join_where(intervals.clone().lazy(), vec![predicate.clone()])
+            .is_err());
+    }
+    Ok(())
+}
+
 #[test]
 fn test_fold_wildcard() -> Result<()> {
     let df1 = df![
@@ -2468,3 +2708,944 @@ fn test_agg_unique_first() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "sql")]
+fn test_sql_context() -> Result<()> {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", fruits_cars().lazy());
+    ctx.register(
+        "prices",
+        df![
+            "fruits" => ["banana", "apple"],
+            "price" => [1, 2],
+        ]?
+        .lazy(),
+    );
+
+    let out = ctx
+        .execute(
+            "SELECT fruits, SUM(A) AS total, COUNT(*) AS n FROM df WHERE B > 1 \
+             GROUP BY fruits HAVING COUNT(*) > 1 ORDER BY total DESC",
+        )?
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["fruits", "total", "n"]);
+    assert_eq!(
+        Vec::from(out.column("fruits")?.utf8()?),
+        &[Some("apple"), Some("banana")]
+    );
+    assert_eq!(Vec::from(out.column("total")?.i32()?), &[Some(7), Some(3)]);
+
+    let out = ctx
+        .execute(
+            "SELECT d.A, p.price FROM df AS d JOIN prices p ON d.fruits = p.fruits \
+             WHERE p.price > 1 ORDER BY d.A LIMIT 1",
+        )?
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["A", "price"]);
+    assert_eq!(Vec::from(out.column("A")?.i32()?), &[Some(3)]);
+
+    let out = ctx
+        .execute("SELECT DISTINCT cars FROM df ORDER BY cars")?
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("cars")?.utf8()?),
+        &[Some("audi"), Some("beetle")]
+    );
+
+    let out = ctx
+        .execute("SELECT A, CASE WHEN A IN (1, 2) THEN 'low' ELSE 'high' END AS size FROM df")?
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("size")?.utf8()?),
+        &[
+            Some("low"),
+            Some("low"),
+            Some("high"),
+            Some("high"),
+            Some("high")
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_common_subplan_elimination() -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [1, 1, 2]
+    ]?;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_udf = calls.clone();
+    let lf = df.lazy().map(
+        move |df: DataFrame| {
+            calls_udf.fetch_add(1, Ordering::Relaxed);
+            Ok(df)
+        },
+        Some(AllowedOptimizations {
+            predicate_pushdown: false,
+            ..Default::default()
+        }),
+        None,
+    );
+
+    // self join
+    let q = lf
+        .clone()
+        .join(lf.clone(), [col("a")], [col("a")], JoinType::Inner);
+    let out = q.clone().collect()?;
+    assert_eq!(out.shape(), (3, 3));
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    calls.store(0, Ordering::Relaxed);
+    let out = q.with_common_subplan_elimination(false).collect()?;
+    assert_eq!(out.shape(), (3, 3));
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+    // union of two filters on the same input
+    calls.store(0, Ordering::Relaxed);
+    let out = crate::functions::concat(
+        [
+            lf.clone().filter(col("b").eq(lit(1))),
+            lf.filter(col("b").eq(lit(2))),
+        ],
+        false,
+    )?
+    .collect()?;
+    assert_eq!(out.height(), 3);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    Ok(())
+}
+
+#[test]
+fn test_common_subexpr_elimination() -> Result<()> {
+    let df = df![
+        "g" => ["a", "a", "b"],
+        "a" => [1, 2, 3],
+        "b" => [2, 2, 1]
+    ]?;
+    let ab = || col("a") * col("b");
+
+    let q = df
+        .clone()
+        .lazy()
+        .select([ab().sum().alias("sum"), ab().max().alias("max")]);
+    assert!(q.describe_optimized_plan()?.contains("__POLARS_CSE_0"));
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), &["sum", "max"]);
+    assert_eq!(out.column("sum")?.i32()?.get(0), Some(9));
+    assert_eq!(out.column("max")?.i32()?.get(0), Some(4));
+
+    // the temporary columns are removed from the output
+    let q = df
+        .clone()
+        .lazy()
+        .with_columns(vec![ab().alias("c"), (ab() + lit(1)).alias("d")]);
+    let out = q.clone().collect()?;
+    let expected = q.with_common_subexpr_elimination(false).collect()?;
+    assert_eq!(out.get_column_names(), &["g", "a", "b", "c", "d"]);
+    assert!(out.frame_equal(&expected));
+
+    let q = df
+        .clone()
+        .lazy()
+        .groupby([col("g")])
+        .agg([ab().sum(), ab().mean().alias("mean")])
+        .sort("g", false);
+    let out = q.clone().collect()?;
+    let expected = q.with_common_subexpr_elimination(false).collect()?;
+    assert_eq!(out.get_column_names(), expected.get_column_names());
+    assert!(out.frame_equal(&expected));
+
+    // a `Series` literal has a length of its own, so it is not extracted
+    let s = Series::new("", &[1, 2, 3]);
+    let q = df.lazy().select([
+        (col("a") + lit(s.clone())).sum().alias("sum"),
+        (col("a") + lit(s)).max().alias("max"),
+    ]);
+    assert!(!q.describe_optimized_plan()?.contains("__POLARS_CSE_"));
+    let out = q.clone().collect()?;
+    let expected = q.with_common_subexpr_elimination(false).collect()?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_slice_pushdown() -> Result<()> {
+    let df = df![
+        "a" => [3, 1, 4, 1, 5, 9, 2, 6],
+        "b" => ["x", "y", "x", "z", "y", "x", "z", "y"]
+    ]?;
+
+    // a sort followed by a slice is a top-k sort, which gives the same rows as a full sort
+    let q = df
+        .clone()
+        .lazy()
+        .sort_by_exprs(vec![col("b"), col("a")], vec![false, true])
+        .slice(1, 3);
+    let out = q.clone().collect()?;
+    let expected = q.with_slice_pushdown(false).collect()?;
+    assert!(out.frame_equal(&expected));
+    assert!(out.frame_equal(&df.sort(["b", "a"], vec![false, true])?.slice(1, 3)));
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(4), Some(3), Some(6)]
+    );
+    let sorted = df.sort(["a", "b"], vec![true, false])?;
+    for (offset, len) in [(0, 1), (0, 8), (3, 2), (6, 10)].iter() {
+        let q = df
+            .clone()
+            .lazy()
+            .sort_by_exprs(vec![col("a"), col("b")], vec![true, false])
+            .slice(*offset, *len);
+        let out = q.clone().collect()?;
+        let expected = q.with_slice_pushdown(false).collect()?;
+        assert!(out.frame_equal(&expected));
+        assert!(out.frame_equal(&sorted.slice(*offset, *len)));
+    }
+
+    // the limit is pushed down through elementwise projections
+    let q = df
+        .lazy()
+        .with_column((col("a") * lit(2)).alias("c"))
+        .limit(2);
+    assert!(!q.describe_optimized_plan()?.starts_with("SLICE"));
+    let out = q.collect()?;
+    assert_eq!(Vec::from(out.column("c")?.i32()?), &[Some(6), Some(2)]);
+
+    // the scan only reads the rows that are needed
+    let q = scan_foods_csv().slice(2, 3);
+    let out = q.clone().collect()?;
+    let expected = q.with_slice_pushdown(false).collect()?;
+    assert_eq!(out.height(), 3);
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_profile() -> Result<()> {
+    let df = fruits_cars();
+    let (out, profile) = df
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .groupby([col("fruits")])
+        .agg([col("B").sum()])
+        .sort("fruits", false)
+        .profile()?;
+    assert_eq!(out.height(), 2);
+    assert_eq!(
+        profile.get_column_names(),
+        &["node", "start", "end", "rows_in", "rows_out", "peak_bytes"]
+    );
+
+    let nodes = profile.column("node")?.utf8()?;
+    let node_idx = |name: &str| {
+        nodes
+            .into_iter()
+            .position(|n| n.unwrap().starts_with(name))
+            .unwrap()
+    };
+    // the filter is pushed down into the scan
+    let scan = node_idx("df_scan");
+    let groupby = node_idx("groupby");
+    let sort = node_idx("sort");
+    assert!(scan < groupby && groupby < sort);
+
+    let rows_in = profile.column("rows_in")?.u64()?;
+    let rows_out = profile.column("rows_out")?.u64()?;
+    assert_eq!(rows_out.get(scan), Some(4));
+    assert_eq!(rows_in.get(groupby), Some(4));
+    assert_eq!(rows_out.get(groupby), Some(2));
+    assert_eq!(rows_out.get(sort), Some(2));
+
+    let start = profile.column("start")?.u64()?;
+    let end = profile.column("end")?.u64()?;
+    assert!(start.into_iter().zip(end.into_iter()).all(|(s, e)| s <= e));
+    Ok(())
+}
+
+#[test]
+fn test_explain() -> Result<()> {
+    let df = fruits_cars();
+    let q = df
+        .lazy()
+        .select([
+            col("A"),
+            (lit(1.0f32) + lit(1.0f32) + col("B").cast(DataType::Float32)).alias("C"),
+        ])
+        .filter(col("A").gt(lit(2)));
+    let explain = q.explain()?;
+
+    assert_eq!(explain.steps[0].pass, "unoptimized");
+    let passes = explain.passes();
+    assert!(passes.contains(&"predicate_pushdown"));
+    assert!(passes.contains(&"simplify_expr"));
+
+    // the predicate is pushed down into the scan and every node shows its schema
+    let plan = explain.optimized_plan();
+    assert!(plan.starts_with("SELECT"));
+    assert!(plan.contains("SELECTION"));
+    assert!(plan.contains("C: Float32"));
+    assert!(!plan.contains("FILTER"));
+
+    let diff = explain.diff();
+    assert!(diff.contains("=== predicate_pushdown ==="));
+    assert!(diff.lines().any(|l| l.starts_with("- FILTER")));
+    Ok(())
+}
+
+#[test]
+fn test_join_reorder() -> Result<()> {
+    let a = df![
+        "k1" => (0..200).map(|i| i % 10).collect::<Vec<i32>>(),
+        "a" => (0..200).collect::<Vec<i32>>()
+    ]?;
+    let b = df![
+        "k1" => (0..200).map(|i| i % 10).collect::<Vec<i32>>(),
+        "k2" => (0..200).collect::<Vec<i32>>()
+    ]?;
+    let c = df![
+        "k2" => [0, 1],
+        "c" => ["x", "y"]
+    ]?;
+
+    // joining `a` and `b` first creates 4000 rows, joining `b` and `c` first only 2
+    let q = a
+        .clone()
+        .lazy()
+        .join(b.clone().lazy(), [col("k1")], [col("k1")], JoinType::Inner)
+        .join(c.clone().lazy(), [col("k2")], [col("k2")], JoinType::Inner);
+
+    // reordering is opt-in, by default the joins keep the order of the rows
+    assert!(!q.explain()?.passes().contains(&"join_reorder"));
+    let expected = q.clone().collect()?;
+    assert!(expected.frame_equal(&a.inner_join(&b, "k1", "k1")?.inner_join(&c, "k2", "k2")?));
+    let expected_unsorted = expected.clone();
+
+    let q = q.with_join_reorder(true);
+    assert!(q.explain()?.passes().contains(&"join_reorder"));
+    // the reordered joins produce the same rows in a different order
+    let out = q.clone().collect()?.sort(&["a", "k2"], false)?;
+    let expected = expected.sort(&["a", "k2"], false)?;
+    assert_eq!(out.get_column_names(), &["k1", "a", "k2", "c"]);
+    assert_eq!(out.height(), 40);
+    assert!(out.frame_equal(&expected));
+
+    let reordered = |q: &LazyFrame| -> Result<bool> {
+        let diff = q.explain()?.diff();
+        let step = diff
+            .split("=== ")
+            .find(|step| step.starts_with("join_reorder ==="))
+            .unwrap();
+        Ok(step.lines().any(|l| l.starts_with('+')))
+    };
+    assert!(reordered(&q)?);
+
+    // joins with different options are not reordered
+    let q = a
+        .lazy()
+        .join(b.lazy(), [col("k1")], [col("k1")], JoinType::Inner)
+        .join_builder()
+        .with(c.lazy())
+        .left_on(vec![col("k2")])
+        .right_on(vec![col("k2")])
+        .join_nulls(false)
+        .finish()
+        .with_join_reorder(true);
+    assert!(!reordered(&q)?);
+    assert!(q.collect()?.frame_equal(&expected_unsorted));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde-lazy")]
+fn test_serde_plan() -> Result<()> {
+    let df = df![
+        "groups" => ["a", "b", "a", "c", "b"],
+        "values" => [1, 2, 3, 4, 5],
+        "floats" => [1.0, 2.0, 3.0, 4.0, 5.0]
+    ]?;
+    let q = df
+        .lazy()
+        .filter(col("values").gt(lit(1)))
+        .groupby([col("groups")])
+        .agg([
+            col("values").sum(),
+            col("floats").quantile(0.5, QuantileInterpolOptions::Linear),
+        ])
+        .sort("groups", false);
+    let expected = q.clone().collect()?;
+
+    let json = q.to_json()?;
+    let out = LazyFrame::from_json(&json)?.collect()?;
+    assert!(out.frame_equal(&expected));
+
+    let bytes = q.to_binary()?;
+    let out = LazyFrame::from_binary(&bytes)?.collect()?;
+    assert!(out.frame_equal(&expected));
+
+    // closures cannot be serialized
+    let q = q.with_column(col("values").map(Ok, GetOutput::same_type()));
+    let err = q.to_json().unwrap_err();
+    assert!(err.to_string().contains("`map`/`apply` expression"));
+    Ok(())
+}
+
+#[test]
+fn test_parallel_branches() -> Result<()> {
+    let frames = (0..8)
+        .map(|i| {
+            df![
+                "key" => [i, i + 1, i + 2],
+                "value" => [i * 10, i * 20, i * 30]
+            ]
+            .unwrap()
+            .lazy()
+            .filter(col("value").gt(lit(0)))
+        })
+        .collect::<Vec<_>>();
+    let right = df![
+        "key" => (0..10).collect::<Vec<i32>>(),
+        "label" => (0..10).map(|i| format!("k{}", i)).collect::<Vec<_>>()
+    ]?;
+    // the union is the left side of a join, so branches are nested
+    let q = crate::functions::concat(&frames, false)?.join(
+        right.lazy(),
+        [col("key")],
+        [col("key")],
+        JoinType::Inner,
+    );
+
+    let expected = q.clone().with_max_parallel_branches(1).collect()?;
+    assert_eq!(expected.height(), 21);
+    for max_parallel_branches in [2, 3, 16] {
+        let out = q
+            .clone()
+            .with_max_parallel_branches(max_parallel_branches)
+            .collect()?;
+        assert!(out.frame_equal(&expected));
+    }
+
+    // inputs that scan the same file are executed sequentially
+    let q = crate::functions::concat(&[scan_foods_csv(), scan_foods_csv().slice(0, 2)], false)?;
+    assert_eq!(q.collect()?.height(), 29);
+    Ok(())
+}
+
+#[test]
+fn test_lazy_pivot() -> Result<()> {
+    let df = df![
+        "foo" => ["A", "A", "B", "B", "C"],
+        "N" => [1, 2, 2, 4, 2],
+        "M" => [10, 20, 20, 40, 20],
+        "bar" => ["k", "l", "k", "l", "k"]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .pivot(
+            PivotOptions {
+                index: vec!["foo".into()],
+                columns: "bar".into(),
+                values: vec!["N".into()],
+                maintain_order: true,
+                ..Default::default()
+            },
+            |values| values.first(),
+        )?
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["foo", "k", "l"]);
+    assert_eq!(
+        Vec::from(out.column("l")?.i32()?),
+        &[Some(2), Some(4), None]
+    );
+
+    // multiple value columns with known column values
+    let out = df
+        .lazy()
+        .pivot(
+            PivotOptions {
+                index: vec!["foo".into()],
+                columns: "bar".into(),
+                column_values: Some(vec!["k".into()]),
+                maintain_order: true,
+                ..Default::default()
+            },
+            |values| values.sum(),
+        )?
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["foo", "N_bar_k", "M_bar_k"]);
+    assert_eq!(
+        Vec::from(out.column("M_bar_k")?.i32()?),
+        &[Some(10), Some(20), Some(20)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_lazy_melt_args() -> Result<()> {
+    let df = df![
+        "id" => ["a", "b"],
+        "x" => [1, 2],
+        "y" => [3, 4],
+        "label" => ["u", "v"]
+    ]?;
+    let out = df
+        .lazy()
+        .melt_with_args(MeltArgs {
+            id_vars: vec!["id".into()],
+            value_dtypes: vec![DataType::Int32],
+            variable_name: Some("column".into()),
+            value_name: Some("number".into()),
+            ..Default::default()
+        })
+        .filter(col("number").gt(lit(1)))
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["id", "column", "number"]);
+    assert_eq!(
+        Vec::from(out.column("number")?.i32()?),
+        &[Some(2), Some(3), Some(4)]
+    );
+
+    // a data type selection that matches no column
+    let out = df
+        .clone()
+        .lazy()
+        .melt_with_args(MeltArgs {
+            id_vars: vec!["id".into()],
+            value_dtypes: vec![DataType::Float64],
+            ..Default::default()
+        })
+        .collect();
+    assert!(matches!(out, Err(PolarsError::NotFound(_))));
+    // an unknown identifier column
+    let out = df
+        .lazy()
+        .melt(vec!["foo".into()], vec!["x".into(), "y".into()])
+        .select([col("value")])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::NotFound(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "interpolate")]
+fn test_interpolate_by() -> Result<()> {
+    let df = df![
+        "t" => [0i64, 1, 4, 5, 6],
+        "v" => [Some(0.0), None, Some(8.0), None, Some(10.0)]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("v")
+                .interpolate_by(col("t"), Default::default())
+                .alias("linear"),
+            col("v")
+                .interpolate_with(InterpolateOptions {
+                    method: InterpolationMethod::Next,
+                    limit: None,
+                })
+                .alias("next"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("linear")?.f64()?),
+        &[Some(0.0), Some(2.0), Some(8.0), Some(9.0), Some(10.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("next")?.f64()?),
+        &[Some(0.0), Some(8.0), Some(8.0), Some(10.0), Some(10.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ewma", feature = "dtype-date", feature = "dtype-datetime"))]
+fn test_ewm_by_time_and_corr() -> Result<()> {
+    let df = df![
+        "t" => [0i32, 1, 3],
+        "x" => [1.0, 5.0, 2.0],
+        "y" => [2.0, 10.0, 4.0]
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_column(col("t").cast(DataType::Date))
+        .select([
+            col("x")
+                .ewm_mean_by(col("t"), Duration::parse("1d"), Default::default())
+                .alias("mean"),
+            col("x")
+                .ewm_corr(col("y"), Default::default())
+                .alias("corr"),
+        ])
+        .collect()?;
+    let mean = out.column("mean")?.f64()?;
+    assert!((mean.get(2).unwrap() - 3.375 / 1.375).abs() < 1e-12);
+    let corr = out.column("corr")?.f64()?;
+    assert_eq!(corr.get(0), None);
+    assert!((corr.get(2).unwrap() - 1.0).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "math")]
+fn test_math_exprs() -> Result<()> {
+    let df = df![
+        "a" => [1i32, 4, 9],
+        "b" => [0.5f32, -2.0, 3.0]
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a").sqrt(),
+            col("b").sign().alias("sign"),
+            col("b").exp().alias("exp"),
+            col("a").clip(2.0, 5.0).alias("clip"),
+            col("a").pow_expr(lit(2)).alias("pow"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.f64()?),
+        &[Some(1.0), Some(2.0), Some(3.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("sign")?.f32()?),
+        &[Some(1.0), Some(-1.0), Some(1.0)]
+    );
+    assert_eq!(out.column("exp")?.dtype(), &DataType::Float32);
+    assert_eq!(
+        Vec::from(out.column("clip")?.i32()?),
+        &[Some(2), Some(4), Some(5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("pow")?.f64()?),
+        &[Some(1.0), Some(16.0), Some(81.0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_bitwise_exprs() -> Result<()> {
+    let df = df![
+        "flags" => [0b0101u32, 0b0011, 0b1000],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("flags").and(lit(0b0110u32)).alias("and"),
+            col("flags").or(lit(0b0001u32)).alias("or"),
+            col("flags").not().alias("not"),
+            col("flags").shift_left(lit(2)).alias("shl"),
+            col("flags").shift_right(lit(1)).alias("shr"),
+            col("flags").count_ones().alias("ones"),
+            col("flags").is_bit_set(0).alias("bit_0"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("and")?.u32()?),
+        &[Some(0b0100), Some(0b0010), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("or")?.u32()?),
+        &[Some(0b0101), Some(0b0011), Some(0b1001)]
+    );
+    assert_eq!(
+        Vec::from(out.column("not")?.u32()?),
+        &[Some(!0b0101), Some(!0b0011), Some(!0b1000)]
+    );
+    assert_eq!(
+        Vec::from(out.column("shl")?.u32()?),
+        &[Some(0b010100), Some(0b1100), Some(0b100000)]
+    );
+    assert_eq!(
+        Vec::from(out.column("shr")?.u32()?),
+        &[Some(0b0010), Some(0b0001), Some(0b0100)]
+    );
+    assert_eq!(
+        Vec::from(out.column("ones")?.u32()?),
+        &[Some(2), Some(2), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("bit_0")?.bool()?),
+        &[Some(true), Some(true), Some(false)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "binning")]
+fn test_value_counts_and_binning_exprs() -> Result<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "v" => [1i32, 2, 2, 7, 9]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby([col("g")])
+        .agg([
+            col("v").value_counts(true, false).alias("counts"),
+            col("v").value_counts_keys(true).alias("values"),
+            col("v").value_counts(false, false).alias("unsorted_counts"),
+            col("v").value_counts_keys(false).alias("unsorted_values"),
+            col("v").hist(Some(vec![5.0]), None).alias("hist"),
+        ])
+        .sort("g", false)
+        .collect()?;
+
+    let counts = out.column("counts")?.list()?.get(0).unwrap();
+    assert_eq!(Vec::from(counts.u32()?), &[Some(2), Some(1)]);
+    let values = out.column("values")?.list()?.get(0).unwrap();
+    assert_eq!(Vec::from(values.i32()?), &[Some(2), Some(1)]);
+    let counts = out.column("unsorted_counts")?.list()?.get(0).unwrap();
+    assert_eq!(Vec::from(counts.u32()?), &[Some(1), Some(2)]);
+    let values = out.column("unsorted_values")?.list()?.get(0).unwrap();
+    assert_eq!(Vec::from(values.i32()?), &[Some(1), Some(2)]);
+    let hist = out.column("hist")?.list()?.get(1).unwrap();
+    assert_eq!(Vec::from(hist.u32()?), &[Some(0), Some(2)]);
+
+    let out = df
+        .lazy()
+        .select([col("v").cut(vec![5.0], Some(vec!["low".into(), "high".into()]))])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.utf8()?),
+        &[
+            Some("low"),
+            Some("low"),
+            Some("low"),
+            Some("high"),
+            Some("high")
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "binning")]
+fn test_binning_exprs_groupby() -> Result<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b"],
+        "v" => [1i32, 2, 3, 10, 20, 30]
+    ]?;
+    let labels = || Some(vec!["low".to_string(), "high".to_string()]);
+
+    let out = df
+        .lazy()
+        .groupby([col("g")])
+        .agg([
+            col("v").cut(vec![5.0], labels()).alias("cut"),
+            col("v").qcut(vec![0.5], labels()).alias("qcut"),
+            col("v").qcut(vec![0.5], None).alias("qcut_intervals"),
+        ])
+        .sort("g", false)
+        .collect()?;
+
+    let get = |name: &str, idx: usize| -> Result<Vec<Option<String>>> {
+        let s = out.column(name)?.list()?.get(idx).unwrap();
+        Ok(s.utf8()?.into_iter().map(|v| v.map(String::from)).collect())
+    };
+    let strings = |values: &[&str]| -> Vec<Option<String>> {
+        values.iter().map(|v| Some(v.to_string())).collect()
+    };
+    assert_eq!(get("cut", 0)?, strings(&["low", "low", "low"]));
+    assert_eq!(get("cut", 1)?, strings(&["high", "high", "high"]));
+    // the medians of the groups are 2 and 20
+    assert_eq!(get("qcut", 0)?, strings(&["low", "low", "high"]));
+    assert_eq!(get("qcut", 1)?, strings(&["low", "low", "high"]));
+    assert_eq!(
+        get("qcut_intervals", 0)?,
+        strings(&["(-inf, 2]", "(-inf, 2]", "(2, inf]"])
+    );
+    assert_eq!(
+        get("qcut_intervals", 1)?,
+        strings(&["(-inf, 20]", "(-inf, 20]", "(20, inf]"])
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn test_approx_aggregations() -> Result<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b"],
+        "v" => [1i32, 2, 3, 2, 4, 6]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby([col("g")])
+        .agg([
+            col("v").approx_n_unique().alias("n_unique"),
+            col("v").approx_quantile(0.5).alias("median"),
+        ])
+        .sort("g", false)
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("n_unique")?.u32()?),
+        &[Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("median")?.f64()?),
+        &[Some(2.0), Some(4.0)]
+    );
+
+    let out = df.lazy().select([col("v").approx_n_unique()]).collect()?;
+    assert_eq!(out.column("v")?.u32()?.get(0), Some(5));
+
+    // the sketches are computed per batch and merged at the scan level
+    let out = scan_foods_csv()
+        .with_aggregate_pushdown(true)
+        .select([col("calories").approx_n_unique().alias("n_unique")])
+        .collect()?;
+    let expected = scan_foods_csv().collect()?.column("calories")?.n_unique()?;
+    assert_eq!(out.column("n_unique")?.u32()?.get(0), Some(expected as u32));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rolling_window")]
+fn test_rolling_cov_corr_exprs() -> Result<()> {
+    use crate::functions::{rolling_corr, rolling_cov};
+
+    let df = df![
+        "x" => [1.0, 2.0, 3.0, 5.0],
+        "y" => [3i32, 2, 1, -3]
+    ]?;
+    let options = RollingOptions {
+        window_size: 2,
+        ..Default::default()
+    };
+    let out = df
+        .lazy()
+        .select([
+            rolling_cov(col("x"), col("y"), options.clone()),
+            rolling_corr(col("x"), col("y"), options),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("rolling_cov")?.f64()?),
+        &[None, Some(-0.5), Some(-0.5), Some(-4.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("rolling_corr")?.f64()?),
+        &[None, Some(-1.0), Some(-1.0), Some(-1.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_sampling() -> Result<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b", "b"],
+        "v" => [1i32, 2, 3, 4, 5, 6, 7],
+        "w" => [10i32, 20, 30, 40, 50, 60, 70]
+    ]?;
+
+    // stratified sample, the columns keep their rows together
+    let out = df
+        .clone()
+        .lazy()
+        .groupby([col("g")])
+        .agg([
+            col("v").sample_n(2, false, 0),
+            col("w").sample_n(2, false, 0),
+        ])
+        .explode(vec![col("v"), col("w")])
+        .collect()?;
+    assert_eq!(out.height(), 4);
+    let v = out.column("v")?.i32()?;
+    let w = out.column("w")?.i32()?;
+    assert!(v
+        .into_no_null_iter()
+        .zip(w.into_no_null_iter())
+        .all(|(v, w)| v * 10 == w));
+
+    // the filter is applied on the sample
+    let out = df
+        .clone()
+        .lazy()
+        .sample_n(3, false, 0)
+        .filter(col("v").gt(lit(3)))
+        .collect()?;
+    let expected = df.sample_n(3, false, 0)?;
+    let expected = expected.filter(&expected.column("v")?.gt(3))?;
+    assert!(out.frame_equal(&expected));
+
+    let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
+    let scan = |seed| {
+        LazyCsvReader::new(path.to_string())
+            .with_reservoir_sample(Some(5), seed)
+            .finish()
+    };
+    let out = scan(0)?.collect()?;
+    assert_eq!(out.height(), 5);
+    // the same seed gives the same sample
+    assert!(out.frame_equal(&scan(0)?.collect()?));
+    // the sample is not just the first rows of the file
+    let head = LazyCsvReader::new(path.to_string())
+        .finish()?
+        .collect()?
+        .head(Some(5));
+    let mut not_head = false;
+    for seed in 0..10 {
+        not_head |= !scan(seed)?.collect()?.frame_equal(&head);
+    }
+    assert!(not_head);
+
+    // the filter is applied on the sample
+    let out = scan(0)?.filter(col("calories").gt(lit(100))).collect()?;
+    let expected = scan(0)?.collect()?;
+    let expected = expected.filter(&expected.column("calories")?.gt(100))?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "describe")]
+fn test_lazy_describe() -> Result<()> {
+    let df = df![
+        "a" => [1i32, 2, 3, 4, 100],
+        "b" => ["x", "y", "y", "z", "y"]
+    ]?;
+    let out = df
+        .lazy()
+        .filter(col("a").lt(lit(100)))
+        .describe(Some(&[0.5, 0.9]))?;
+    assert_eq!(
+        Vec::from(out.column("describe")?.utf8()?),
+        &[
+            Some("count"),
+            Some("null_count"),
+            Some("mean"),
+            Some("std"),
+            Some("min"),
+            Some("50%"),
+            Some("90%"),
+            Some("max"),
+            Some("unique"),
+            Some("top")
+        ]
+    );
+    let a = out.column("a")?.f64()?;
+    assert_eq!(a.get(0), Some(4.0));
+    assert_eq!(a.get(4), Some(1.0));
+    assert_eq!(a.get(5), Some(2.5));
+    assert!((a.get(6).unwrap() - 3.7).abs() < 1e-12);
+    assert_eq!(a.get(7), Some(4.0));
+    let b = out.column("b")?.utf8()?;
+    assert_eq!(b.get(8), Some("3"));
+    assert_eq!(b.get(9), Some("y"));
+    Ok(())
+}
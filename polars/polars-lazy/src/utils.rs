@@ -226,6 +226,42 @@ pub(crate) fn expressions_to_schema(expr: &[Expr], schema: &Schema, ctxt: Contex
     Schema::new(fields)
 }
 
+/// Check if the expression only consists of elementwise operations, i.e. every output value
+/// only depends on the input values in the same row.
+pub(crate) fn aexpr_is_elementwise(node: Node, arena: &Arena<AExpr>) -> bool {
+    arena.iter(node).all(|(_, e)| {
+        use AExpr::*;
+        match e {
+            // `Series` and `Range` literals have a length of their own
+            Literal(lv) => !matches!(lv, LiteralValue::Series(_) | LiteralValue::Range { .. }),
+            _ => matches!(
+                e,
+                Column(_)
+                    | Alias(_, _)
+                    | BinaryExpr { .. }
+                    | Cast { .. }
+                    | Not(_)
+                    | IsNull(_)
+                    | IsNotNull(_)
+                    | Ternary { .. }
+            ),
+        }
+    })
+}
+
+/// Get a set of the ids of the caches in this LogicalPlan
+pub(crate) fn agg_cache_ids(
+    root_lp: Node,
+    ids: &mut HashSet<usize, RandomState>,
+    lp_arena: &Arena<ALogicalPlan>,
+) {
+    lp_arena.iter(root_lp).for_each(|(_, lp)| {
+        if let ALogicalPlan::Cache { id, .. } = lp {
+            ids.insert(*id);
+        }
+    })
+}
+
 /// Get a set of the data source paths in this LogicalPlan
 pub(crate) fn agg_source_paths(
     root_lp: Node,
@@ -4,9 +4,12 @@ use crate::calendar::{
 };
 use crate::unit::TimeNanoseconds;
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::ops::{Add, Mul, Sub};
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Duration {
     // the number of months for the duration
     months: i64,
@@ -173,6 +176,10 @@ impl Duration {
         self.nsecs
     }
 
+    pub fn negative(&self) -> bool {
+        self.negative
+    }
+
     /// Estimated duration of the window duration. Not a very good one if months != 0.
     #[inline]
     pub const fn duration(&self) -> TimeNanoseconds {
@@ -1,10 +1,13 @@
 use crate::bounds::Bounds;
 use crate::unit::TimeNanoseconds;
 use crate::window::Window;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 pub type GroupTuples = Vec<(u32, Vec<u32>)>;
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ClosedWindow {
     Left,
     Right,
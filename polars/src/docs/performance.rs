@@ -51,16 +51,17 @@
 //! ### Example: Eager join multiple DataFrames on a Categorical
 //! When the strings of one column need to be joined with the string data from another `DataFrame`.
 //! The `Categorical` data needs to be synchronized (Categories in df A need to point to the same
-//! underlying string data as Categories in df B). You can do that by turning the global string cache
-//! on.
+//! underlying string data as Categories in df B). You can do that by keeping the global string cache
+//! in use with a `StringCacheGuard`. Categoricals that are not created under the same string cache
+//! can still be joined, but their categories need to be re-encoded first, which is more expensive.
 //!
 //! ```rust
 //! use polars::prelude::*;
-//! use polars::toggle_string_cache;
+//! use polars::StringCacheGuard;
 //!
 //! fn example(mut df_a: DataFrame, mut df_b: DataFrame) -> Result<DataFrame> {
-//!     // Set a global string cache
-//!     toggle_string_cache(true);
+//!     // Use the global string cache until the guard is dropped
+//!     let _guard = StringCacheGuard::new();
 //!
 //!     df_a.may_apply("a", |s| s.cast::<CategoricalType>())?;
 //!     df_b.may_apply("b", |s| s.cast::<CategoricalType>())?;
@@ -93,7 +93,8 @@
 //! * `lazy` - Lazy API
 //!     - `lazy_regex` - Use regexes in [column selection](crate::lazy::dsl::col)
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
-//! * `random` - Generate arrays with randomly sampled values
+//!     - `sql` - Query DataFrames and LazyFrames with SQL through a `SQLContext`.
+//! * `random` - Generate arrays with randomly sampled values, sample in lazy queries and csv scans
 //! * `ndarray`- Convert from `DataFrame` to `ndarray`
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `strings` - Extra string utilities for `Utf8Chunked`
@@ -105,6 +106,7 @@
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
+//!     - `serde-lazy` - Serialize lazy query plans to JSON or bincode with `LazyFrame::to_json` and `LazyFrame::to_binary`.
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
@@ -120,6 +122,8 @@
 //!     - `rows` - Create `DataFrame` from rows and extract rows from `DataFrames`.
 //!     - `asof_join` - Join as of, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the cartesian product of two DataFrames.
+//!     - `semi_anti_join` - Filter a DataFrame by the existence of its keys in another DataFrame.
+//!     - `range_join` - Join on inequality predicates, optionally combined with equality keys.
 //!     - `groupby_list` - Allow groupby operation on keys of type List.
 //!     - `row_hash` - Utility to hash DataFrame rows to UInt64Chunked
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
@@ -139,13 +143,18 @@
 //!     - `mode` - [Return the most occurring value(s)](crate::chunked_array::ops::ChunkUnique::mode)
 //!     - `cum_agg` - [cumsum, cummin, cummax aggregation](crate::chunked_array::ops::CumAgg)
 //!     - `rolling_window` [rolling window functions, like rolling_mean](crate::chunked_array::ops::ChunkWindow)
-//!     - `interpolate` [interpolate None values](crate::chunked_array::ops::Interpolate)
+//!     - `interpolate` [interpolate None values](crate::chunked_array::ops::Interpolate), also nearest, previous/next, cubic spline and interpolation weighted by another column
 //!     - `extract_jsonpath` - [Run jsonpath queries on Utf8Chunked](https://goessner.net/articles/JsonPath/)
 //!     - `list` - [List utils](crate::chunked_array::list::namespace)
 //!     - `rank` - Ranking algorithms.
 //!     - `moment` - kurtosis and skew statistics
-//!     - `ewma` - Exponential moving average windows
+//!     - `ewma` - Exponentially weighted moving mean, std, var, cov and corr, also weighted by time
 //!     - `abs` - Get absolute values of Series
+//!     - `math` - log, exp, sqrt, cbrt, trigonometric and hyperbolic functions, sign and clip
+//!     - `binning` - Bin values with `cut` and `qcut` and count them in histograms with `hist`
+//!     - `approx` - Approximate n_unique (HyperLogLog) and quantile (t-digest) aggregations
+//!     - `describe` - Summary statistics of a `DataFrame` or `LazyFrame`
+//!     - `cov` - Covariance and correlation matrices of a `DataFrame`
 //!     - `arange` - Range operation on Series
 //! * `DataFrame` pretty printing (Choose one or none, but not both):
 //!     - `plain_fmt` - no overflowing (less compilation times)
@@ -234,9 +243,9 @@
 pub mod docs;
 pub mod prelude;
 
-#[cfg(feature = "dtype-categorical")]
-pub use polars_core::toggle_string_cache;
 pub use polars_core::{chunked_array, datatypes, doc, error, frame, functions, series, testing};
+#[cfg(feature = "dtype-categorical")]
+pub use polars_core::{toggle_string_cache, StringCacheGuard};
 
 pub use polars_core::apply_method_all_arrow_series;
 pub use polars_core::df;
@@ -423,8 +423,8 @@ impl PyDataFrame {
 
     pub fn fill_null(&self, strategy: &str) -> PyResult<Self> {
         let strat = match strategy {
-            "backward" => FillNullStrategy::Backward,
-            "forward" => FillNullStrategy::Forward,
+            "backward" => FillNullStrategy::Backward(None),
+            "forward" => FillNullStrategy::Forward(None),
             "min" => FillNullStrategy::Min,
             "max" => FillNullStrategy::Max,
             "mean" => FillNullStrategy::Mean,
@@ -205,11 +205,11 @@ impl PyExpr {
     }
 
     pub fn backward_fill(&self) -> PyExpr {
-        self.clone().inner.backward_fill().into()
+        self.clone().inner.backward_fill(None).into()
     }
 
     pub fn forward_fill(&self) -> PyExpr {
-        self.clone().inner.forward_fill().into()
+        self.clone().inner.forward_fill(None).into()
     }
 
     pub fn shift(&self, periods: i64) -> PyExpr {
@@ -483,7 +483,10 @@ impl PySeries {
     }
 
     pub fn value_counts(&self) -> PyResult<PyDataFrame> {
-        let df = self.series.value_counts().map_err(PyPolarsEr::from)?;
+        let df = self
+            .series
+            .value_counts(true, false)
+            .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
 